@@ -26,6 +26,10 @@ fn main() {
         .collect::<Vec<_>>();
 
     // The [q_i + i | 0 <= i < n] variables
+    #[allow(
+        unused_variables,
+        reason = "used once the all-different constraints are posted"
+    )]
     let diag1 = variables
         .iter()
         .cloned()
@@ -34,6 +38,10 @@ fn main() {
         .collect::<Vec<_>>();
 
     // The [q_i - i | 0 <= i < n] variables
+    #[allow(
+        unused_variables,
+        reason = "used once the all-different constraints are posted"
+    )]
     let diag2 = variables
         .iter()
         .cloned()
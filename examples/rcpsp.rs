@@ -4,7 +4,10 @@ use clap::ValueEnum;
 use dzn_rs::DataFile;
 use dzn_rs::ShapedArray;
 use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use munchkin::branching::branchers::last_conflict::LastConflict;
+use munchkin::branching::branchers::sequential_brancher::SequentialBrancher;
 use munchkin::branching::Brancher;
+use munchkin::branching::FirstFail;
 use munchkin::branching::InDomainMin;
 use munchkin::branching::InputOrder;
 use munchkin::model::Constraint;
@@ -22,6 +25,12 @@ munchkin::entry_point!(problem = Rcpsp, search_strategies = SearchStrategies);
 enum SearchStrategies {
     #[default]
     Default,
+    /// VSIDS with phase saving over all variables.
+    Vsids,
+    /// First fail over the start times, followed by VSIDS with phase saving over all variables.
+    FirstFailThenVsids,
+    /// The default strategy with last-conflict reasoning over the two most recent conflicts.
+    LastConflict,
 }
 
 struct Rcpsp {
@@ -135,21 +144,44 @@ impl Problem<SearchStrategies> for Rcpsp {
     fn get_search(
         &self,
         strategy: SearchStrategies,
-        _: &Solver,
+        solver: &Solver,
         solver_variables: &VariableMap,
     ) -> impl Brancher + 'static {
-        match strategy {
-            SearchStrategies::Default => Box::new(IndependentVariableValueBrancher::new(
-                InputOrder::new(
-                    solver_variables
-                        .get_array(self.start_times)
-                        .into_iter()
-                        .chain([solver_variables.to_solver_variable(self.makespan)])
-                        .collect(),
-                ),
-                InDomainMin,
-            )) as Box<dyn Brancher>,
-        }
+        let input_order = IndependentVariableValueBrancher::new(
+            InputOrder::new(
+                solver_variables
+                    .get_array(self.start_times)
+                    .into_iter()
+                    .chain([solver_variables.to_solver_variable(self.makespan)])
+                    .collect(),
+            ),
+            InDomainMin,
+        );
+
+        let brancher: Box<dyn Brancher> = match strategy {
+            SearchStrategies::Default => Box::new(input_order),
+            SearchStrategies::Vsids => {
+                Box::new(solver.default_brancher_over_all_propositional_variables())
+            }
+            SearchStrategies::FirstFailThenVsids => {
+                let start_times = solver_variables.get_array(self.start_times);
+                let first_fail: Box<dyn Brancher> =
+                    Box::new(IndependentVariableValueBrancher::new(
+                        FirstFail::new(&start_times),
+                        InDomainMin,
+                    ));
+
+                Box::new(SequentialBrancher::new(vec![
+                    first_fail,
+                    Box::new(solver.default_brancher_over_all_propositional_variables()),
+                ]))
+            }
+            SearchStrategies::LastConflict => {
+                Box::new(LastConflict::with_num_conflict_variables(input_order, 2))
+            }
+        };
+
+        brancher
     }
 
     fn get_output_variables(&self) -> impl Iterator<Item = Output> + '_ {
@@ -22,7 +22,10 @@ use clap::ValueEnum;
 use dzn_rs::DataFile;
 use dzn_rs::ShapedArray;
 use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use munchkin::branching::ActivityBasedSearch;
 use munchkin::branching::Brancher;
+use munchkin::branching::DomWDeg;
+use munchkin::branching::ImpactBasedSearch;
 use munchkin::branching::InDomainMin;
 use munchkin::branching::InputOrder;
 use munchkin::model::Constraint;
@@ -43,6 +46,14 @@ munchkin::entry_point!(
 enum SearchStrategies {
     #[default]
     Default,
+    /// VSIDS with phase saving over all variables.
+    Vsids,
+    /// dom/wdeg over the successor variables.
+    DomWDeg,
+    /// Activity-based search over the successor variables.
+    Abs,
+    /// Impact-based search over the successor variables.
+    Ibs,
 }
 
 struct TravellingSalesperson {
@@ -121,15 +132,32 @@ impl Problem<SearchStrategies> for TravellingSalesperson {
     fn get_search(
         &self,
         strategy: SearchStrategies,
-        _: &Solver,
+        solver: &Solver,
         variables: &VariableMap,
     ) -> impl Brancher + 'static {
-        match strategy {
-            SearchStrategies::Default => IndependentVariableValueBrancher::new(
+        let brancher: Box<dyn Brancher> = match strategy {
+            SearchStrategies::Default => Box::new(IndependentVariableValueBrancher::new(
                 InputOrder::new(variables.get_array(self.successors)),
                 InDomainMin,
-            ),
-        }
+            )),
+            SearchStrategies::Vsids => {
+                Box::new(solver.default_brancher_over_all_propositional_variables())
+            }
+            SearchStrategies::DomWDeg => Box::new(IndependentVariableValueBrancher::new(
+                DomWDeg::new(&variables.get_array(self.successors)),
+                InDomainMin,
+            )),
+            SearchStrategies::Abs => Box::new(IndependentVariableValueBrancher::new(
+                ActivityBasedSearch::new(&variables.get_array(self.successors)),
+                InDomainMin,
+            )),
+            SearchStrategies::Ibs => Box::new(IndependentVariableValueBrancher::new(
+                ImpactBasedSearch::new(&variables.get_array(self.successors)),
+                InDomainMin,
+            )),
+        };
+
+        brancher
     }
 
     fn get_output_variables(&self) -> impl Iterator<Item = Output> + '_ {
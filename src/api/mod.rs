@@ -46,9 +46,15 @@ pub mod variables {
     //!   used when interacting with the [`Solver`]. A [`Literal`] is used when a
     //!   [`PropositionalVariable`] is given a polarity (i.e. it is the positive [`Literal`] or its
     //!   negated version). A [`Literal`] can be created using [`Solver::new_literal`].
+    //!
+    //! For scheduling, an [`IntervalVariable`] groups the start time, duration and end time of a
+    //! (possibly optional) task together with a [`Literal`] indicating whether the task is
+    //! present. These are created using [`Solver::new_interval_variable`] and
+    //! [`Solver::new_optional_interval_variable`].
     pub use crate::engine::variables::AffineView;
     pub use crate::engine::variables::DomainId;
     pub use crate::engine::variables::IntegerVariable;
+    pub use crate::engine::variables::IntervalVariable;
     pub use crate::engine::variables::Literal;
     pub use crate::engine::variables::PropositionalVariable;
     pub use crate::engine::variables::TransformableVariable;
@@ -79,8 +85,11 @@ pub mod termination {
     //! The most common example would be [`TimeBudget`], which terminates the [`Solver`] whenever
     //! the time budget is exceeded.
     pub use crate::engine::termination::combinator::*;
+    pub use crate::engine::termination::conflict_budget::*;
+    pub use crate::engine::termination::decision_budget::*;
     pub use crate::engine::termination::indefinite::*;
     pub use crate::engine::termination::os_signal::*;
+    pub use crate::engine::termination::propagation_budget::*;
     pub use crate::engine::termination::time_budget::*;
     pub use crate::engine::termination::TerminationCondition;
     #[cfg(doc)]
@@ -38,7 +38,7 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
 
     /// Find a new solution by blocking the previous solution from being found. Also calls the
     /// [`Brancher::on_solution`] method from the [`Brancher`] used to run the initial solve.
-    pub fn next_solution(&mut self) -> IteratedSolution {
+    pub fn next_solution(&mut self) -> IteratedSolution<'_> {
         if let Some(blocking_clause) = self.next_blocking_clause.take() {
             self.solver.restore_state_at_root(self.brancher);
             if self.solver.add_clause(blocking_clause).is_err() {
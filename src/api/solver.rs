@@ -5,26 +5,106 @@ use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::HashSet;
 use crate::basic_types::Solution;
+use crate::basic_types::SolutionReference;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::value_selection::PhaseSaving;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
+use crate::branching::variable_selection::Vsids;
 use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::constraints;
 use crate::constraints::ConstraintPoster;
 use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::termination::TerminationCondition;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::IntervalVariable;
 use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+use crate::engine::variables::TransformableVariable;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::munchkin_assert_simple;
 use crate::options::SolverOptions;
+use crate::predicate;
 use crate::results::solution_iterator::SolutionIterator;
 use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
 use crate::statistics::log_statistic;
 use crate::statistics::log_statistic_postfix;
 
+/// A [`Brancher`] which is suitable for any problem without knowledge of its structure; see
+/// [`Solver::default_brancher_over_all_propositional_variables`]. It uses [`Vsids`] to select a
+/// variable and [`PhaseSaving`] to select its value.
+///
+/// This is the only [`Brancher`] which makes use of the hints which are added to the solver (see
+/// [`Solver::add_hint`]).
+#[derive(Debug)]
+pub struct DefaultBrancher(
+    IndependentVariableValueBrancher<
+        PropositionalVariable,
+        Vsids<PropositionalVariable>,
+        PhaseSaving<PropositionalVariable, bool>,
+    >,
+);
+
+impl Brancher for DefaultBrancher {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.0.next_decision(context)
+    }
+
+    fn on_conflict(&mut self) {
+        self.0.on_conflict();
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.0.on_unassign_literal(literal);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.0.on_unassign_integer(variable, value);
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.0.on_appearance_in_conflict_literal(literal);
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.0.on_appearance_in_conflict_integer(variable);
+    }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        self.0.uses_conflict_appearances()
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.0.on_propagator_conflict(propagator);
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.0.on_decision_propagated(context, is_conflicting);
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.0.on_solution(solution);
+    }
+
+    fn on_restart(&mut self) {
+        self.0.on_restart();
+    }
+
+    fn uses_hints(&self) -> bool {
+        true
+    }
+}
+
+/// The initial [`Vsids`] activity of the literals of variables which are hinted using
+/// [`Solver::add_hint`].
+const HINTED_VARIABLE_INITIAL_ACTIVITY: f64 = 1.0;
+
 /// The main interaction point which allows the creation of variables, the addition of constraints,
 /// and solving problems.
 ///
@@ -80,6 +160,8 @@ pub struct Solver {
     /// The function is called whenever an optimisation function finds a solution; see
     /// [`Solver::with_solution_callback`].
     solution_callback: Box<dyn Fn(&Solution)>,
+    /// The values of the [`PropositionalVariable`]s which are hinted using [`Solver::add_hint`].
+    hints: Vec<(PropositionalVariable, bool)>,
 }
 
 impl Default for Solver {
@@ -87,6 +169,7 @@ impl Default for Solver {
         Self {
             satisfaction_solver: Default::default(),
             solution_callback: create_empty_function(),
+            hints: vec![],
         }
     }
 }
@@ -110,6 +193,7 @@ impl Solver {
         Solver {
             satisfaction_solver: ConstraintSatisfactionSolver::new(solver_options),
             solution_callback: create_empty_function(),
+            hints: vec![],
         }
     }
 }
@@ -119,6 +203,7 @@ impl Solver {
         Solver {
             satisfaction_solver: ConstraintSatisfactionSolver::new(solver_options),
             solution_callback: create_empty_function(),
+            hints: vec![],
         }
     }
 
@@ -330,6 +415,108 @@ impl Solver {
     }
 }
 
+/// Functions for creating interval variables, which are used by the scheduling constraints.
+impl Solver {
+    /// Create a new mandatory [`IntervalVariable`] which starts no earlier than `earliest_start`,
+    /// ends no later than `latest_end` and has a duration in the range
+    /// `[min_duration, max_duration]`.
+    ///
+    /// If the solver is already in an infeasible state, linking the start, duration and end of
+    /// the interval fails and a [`ConstraintOperationError`] will be returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// // A task which takes between 2 and 4 time units and is executed within [0, 10]
+    /// let task = solver.new_interval_variable(0, 10, 2, 4)?;
+    /// # Ok::<(), munchkin::ConstraintOperationError>(())
+    /// ```
+    pub fn new_interval_variable(
+        &mut self,
+        earliest_start: i32,
+        latest_end: i32,
+        min_duration: u32,
+        max_duration: u32,
+    ) -> Result<IntervalVariable, ConstraintOperationError> {
+        let presence = self.get_true_literal();
+        self.create_interval_variable(
+            earliest_start,
+            latest_end,
+            min_duration,
+            max_duration,
+            presence,
+        )
+    }
+
+    /// Create a new optional [`IntervalVariable`]; the interval is only executed (within the given
+    /// bounds) if its presence literal is true. An absent interval is ignored by the scheduling
+    /// constraints.
+    ///
+    /// If the solver is already in an infeasible state, a [`ConstraintOperationError`] will be
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let task = solver.new_optional_interval_variable(0, 10, 3, 3)?;
+    /// let is_scheduled = task.presence();
+    /// # Ok::<(), munchkin::ConstraintOperationError>(())
+    /// ```
+    pub fn new_optional_interval_variable(
+        &mut self,
+        earliest_start: i32,
+        latest_end: i32,
+        min_duration: u32,
+        max_duration: u32,
+    ) -> Result<IntervalVariable, ConstraintOperationError> {
+        let presence = self.new_literal();
+        self.create_interval_variable(
+            earliest_start,
+            latest_end,
+            min_duration,
+            max_duration,
+            presence,
+        )
+    }
+
+    fn create_interval_variable(
+        &mut self,
+        earliest_start: i32,
+        latest_end: i32,
+        min_duration: u32,
+        max_duration: u32,
+        presence: Literal,
+    ) -> Result<IntervalVariable, ConstraintOperationError> {
+        munchkin_assert_simple!(
+            min_duration <= max_duration,
+            "The minimum duration of an interval cannot exceed its maximum duration"
+        );
+        munchkin_assert_simple!(
+            earliest_start + min_duration as i32 <= latest_end,
+            "An interval should fit between its earliest start and latest end"
+        );
+
+        let start = self.new_bounded_integer(earliest_start, latest_end - min_duration as i32);
+        let duration = self.new_bounded_integer(
+            min_duration as i32,
+            max_duration.min((latest_end - earliest_start) as u32) as i32,
+        );
+        let end = self.new_bounded_integer(earliest_start + min_duration as i32, latest_end);
+
+        self.add_constraint(constraints::equals(
+            [start.scaled(1), duration.scaled(1), end.scaled(-1)],
+            0,
+        ))
+        .implied_by(presence)?;
+
+        Ok(IntervalVariable::new(start, duration, end, presence))
+    }
+}
+
 /// Functions for solving with the constraints that have been added to the [`Solver`].
 impl Solver {
     /// Solves the current model in the [`Solver`] until it finds a solution (or is indicated to
@@ -610,6 +797,87 @@ impl Solver {
     }
 }
 
+/// Functions for creating and managing [`Brancher`]s.
+impl Solver {
+    /// Creates an instance of the [`DefaultBrancher`], which uses [`Vsids`] to select a variable
+    /// and [`PhaseSaving`] to select its value.
+    ///
+    /// The brancher is created over all [`PropositionalVariable`]s which are currently defined.
+    /// Since every integer variable is encoded with literals, this also covers the integer
+    /// variables.
+    pub fn default_brancher_over_all_propositional_variables(&self) -> DefaultBrancher {
+        let variables = self
+            .satisfaction_solver
+            .get_propositional_assignments()
+            .get_propositional_variables()
+            .collect::<Vec<_>>();
+
+        // Hinted variables are given an initial activity such that they are branched on first.
+        let hinted_variables = self
+            .hints
+            .iter()
+            .map(|&(variable, _)| variable)
+            .collect::<HashSet<_>>();
+        let initial_activities = variables
+            .iter()
+            .map(|variable| {
+                if hinted_variables.contains(variable) {
+                    HINTED_VARIABLE_INITIAL_ACTIVITY
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        DefaultBrancher(IndependentVariableValueBrancher::new(
+            Vsids::with_initial_values(&variables, &initial_activities),
+            PhaseSaving::with_initial_values(&variables, self.hints.clone(), false),
+        ))
+    }
+
+    /// Provides a hint that `variable` should take the value `value`; this can be used to start the
+    /// search near a known (partial) assignment, e.g. a solution found by a heuristic.
+    ///
+    /// The hint concerns the literals `[variable >= value]`, `[variable <= value]` and
+    /// `[variable == value]`. It is used by the [`DefaultBrancher`] (see
+    /// [`Solver::default_brancher_over_all_propositional_variables`]) by freezing the values of
+    /// these literals in [`PhaseSaving`] and by branching on them before the other literals; it
+    /// thus only affects [`DefaultBrancher`]s which are created after the hint is added (see
+    /// [`Brancher::uses_hints`]). Note that the hint does not have to be feasible.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// solver.add_hint(x, 5);
+    /// let brancher = solver.default_brancher_over_all_propositional_variables();
+    /// ```
+    pub fn add_hint(&mut self, variable: impl IntegerVariable, value: i32) {
+        for predicate in [
+            predicate!(variable >= value),
+            predicate!(variable <= value),
+            predicate!(variable == value),
+        ] {
+            let literal = self.get_literal(predicate);
+            self.add_literal_hint(literal, true);
+        }
+    }
+
+    fn add_literal_hint(&mut self, literal: Literal, value: bool) {
+        if self.get_literal_value(literal).is_some() {
+            // The literal is fixed at the root, so there is no decision to guide.
+            return;
+        }
+
+        self.hints.push((
+            literal.get_propositional_variable(),
+            literal.is_positive() == value,
+        ));
+    }
+}
+
 /// Functions for adding new constraints to the solver.
 impl Solver {
     /// Add a constraint to the solver. This returns a [`ConstraintPoster`] which enables control
@@ -0,0 +1,250 @@
+//! A heap where the keys range from [0, ..., n - 1] and the values are nonnegative floating points.
+//!
+//! The heap can be queried to return the key with the maximum value, and certain keys can be
+//! (temporarily) removed from the heap. A removed key keeps its value, and can be restored later.
+
+use std::ops::AddAssign;
+use std::ops::DivAssign;
+
+use super::KeyedVec;
+use super::StorageKey;
+use crate::munchkin_assert_moderate;
+
+#[derive(Debug, Clone)]
+pub(crate) struct KeyValueHeap<Key: StorageKey, Value> {
+    /// Contains the values stored as a heap; the value of key `i` is at index
+    /// [`KeyValueHeap::map_key_to_position`]\[i\]
+    values: Vec<Value>,
+    /// `map_key_to_position[i]` is the index in [`KeyValueHeap::values`] at which the value of key
+    /// `i` is stored
+    map_key_to_position: KeyedVec<Key, usize>,
+    /// `map_position_to_key[i]` is the key whose value is stored at index `i` in
+    /// [`KeyValueHeap::values`]
+    map_position_to_key: Vec<Key>,
+    /// The keys which are in the heap are stored at the positions before `end_position`, the
+    /// removed keys are stored at the positions after it
+    end_position: usize,
+}
+
+impl<Key: StorageKey, Value> Default for KeyValueHeap<Key, Value> {
+    fn default() -> Self {
+        Self {
+            values: Vec::default(),
+            map_key_to_position: KeyedVec::default(),
+            map_position_to_key: Vec::default(),
+            end_position: 0,
+        }
+    }
+}
+
+impl<Key, Value> KeyValueHeap<Key, Value>
+where
+    Key: StorageKey + Copy,
+    Value: AddAssign<Value> + DivAssign<Value> + PartialOrd + Copy,
+{
+    /// Get the key with the maximum value, together with that value, without removing it from
+    /// the heap. Returns [`None`] if the heap is empty.
+    pub(crate) fn peek_max(&self) -> Option<(&Key, &Value)> {
+        if self.end_position > 0 {
+            Some((&self.map_position_to_key[0], &self.values[0]))
+        } else {
+            None
+        }
+    }
+
+    /// Get the value of the key; the key does not need to be present in the heap.
+    pub(crate) fn get_value(&self, key: Key) -> Value {
+        munchkin_assert_moderate!(key.index() < self.map_key_to_position.len());
+        self.values[self.map_key_to_position[key]]
+    }
+
+    /// Removes the key with the maximum value from the heap and returns it. Its value is kept, and
+    /// the key can be reinserted using [`KeyValueHeap::restore_key`].
+    pub(crate) fn pop_max(&mut self) -> Option<Key> {
+        if self.end_position > 0 {
+            let best_key = self.map_position_to_key[0];
+            self.delete_key(best_key);
+            Some(best_key)
+        } else {
+            None
+        }
+    }
+
+    /// Increments the value of the key by `increment`; the key does not need to be present in the
+    /// heap.
+    pub(crate) fn increment(&mut self, key: Key, increment: Value) {
+        let position = self.map_key_to_position[key];
+        self.values[position] += increment;
+
+        if self.is_key_present(key) {
+            self.sift_up(position);
+        }
+    }
+
+    /// Restores a key which was removed from the heap; if the key is already present, nothing
+    /// happens.
+    pub(crate) fn restore_key(&mut self, key: Key) {
+        if !self.is_key_present(key) {
+            let position = self.map_key_to_position[key];
+            munchkin_assert_moderate!(position >= self.end_position);
+
+            self.swap_positions(position, self.end_position);
+            self.end_position += 1;
+            self.sift_up(self.end_position - 1);
+        }
+    }
+
+    /// Removes the key from the heap while keeping its value; if the key is not present, nothing
+    /// happens.
+    pub(crate) fn delete_key(&mut self, key: Key) {
+        if self.is_key_present(key) {
+            let position = self.map_key_to_position[key];
+            self.swap_positions(position, self.end_position - 1);
+            self.end_position -= 1;
+
+            if position < self.end_position {
+                self.sift_down(position);
+            }
+        }
+    }
+
+    /// The number of keys which are present in the heap.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.end_position
+    }
+
+    pub(crate) fn is_key_present(&self, key: Key) -> bool {
+        key.index() < self.map_key_to_position.len()
+            && self.map_key_to_position[key] < self.end_position
+    }
+
+    /// Adds the next key to the heap with the provided value. The key is expected to be the
+    /// smallest key which is not yet known to the heap.
+    pub(crate) fn grow(&mut self, key: Key, value: Value) {
+        let last_index = self.values.len();
+        munchkin_assert_moderate!(key.index() == last_index);
+
+        self.values.push(value);
+        self.map_key_to_position.push(last_index);
+        self.map_position_to_key.push(key);
+
+        // The new key is placed at the end, which is in the part of removed keys, and is then
+        // restored into the heap
+        self.swap_positions(self.end_position, last_index);
+        self.end_position += 1;
+        self.sift_up(self.end_position - 1);
+    }
+
+    /// Ensures the heap knows about all keys up to and including `key`, the new keys are added
+    /// with the provided value.
+    pub(crate) fn accomodate(&mut self, key: Key, value: Value) {
+        while self.map_key_to_position.len() <= key.index() {
+            self.grow(
+                Key::create_from_index(self.map_key_to_position.len()),
+                value,
+            );
+        }
+    }
+
+    /// Divides all the values by the divisor; this does not change the order of the heap.
+    pub(crate) fn divide_values(&mut self, divisor: Value) {
+        for value in self.values.iter_mut() {
+            *value /= divisor;
+        }
+    }
+
+    fn swap_positions(&mut self, a: usize, b: usize) {
+        let key_a = self.map_position_to_key[a];
+        let key_b = self.map_position_to_key[b];
+
+        self.values.swap(a, b);
+        self.map_position_to_key.swap(a, b);
+        self.map_key_to_position[key_a] = b;
+        self.map_key_to_position[key_b] = a;
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+
+            if self.values[parent] >= self.values[position] {
+                break;
+            }
+
+            self.swap_positions(parent, position);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut largest = position;
+
+            if left < self.end_position && self.values[left] > self.values[largest] {
+                largest = left;
+            }
+            if right < self.end_position && self.values[right] > self.values[largest] {
+                largest = right;
+            }
+
+            if largest == position {
+                break;
+            }
+
+            self.swap_positions(position, largest);
+            position = largest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyValueHeap;
+
+    #[test]
+    fn max_is_returned_after_increments() {
+        let mut heap: KeyValueHeap<usize, f64> = KeyValueHeap::default();
+        heap.accomodate(4, 0.0);
+
+        heap.increment(2, 3.0);
+        heap.increment(4, 1.0);
+        heap.increment(1, 2.0);
+
+        assert_eq!(Some(2), heap.pop_max());
+        assert_eq!(Some(1), heap.pop_max());
+        assert_eq!(Some(4), heap.pop_max());
+        assert_eq!(2, heap.len());
+    }
+
+    #[test]
+    fn restored_key_keeps_its_value() {
+        let mut heap: KeyValueHeap<usize, f64> = KeyValueHeap::default();
+        heap.accomodate(2, 0.0);
+        heap.increment(1, 5.0);
+
+        assert_eq!(Some(1), heap.pop_max());
+        assert!(!heap.is_key_present(1));
+
+        heap.increment(1, 1.0);
+        heap.restore_key(1);
+
+        assert_eq!(Some((&1, &6.0)), heap.peek_max());
+    }
+
+    #[test]
+    fn dividing_values_keeps_the_order() {
+        let mut heap: KeyValueHeap<usize, f64> = KeyValueHeap::default();
+        heap.accomodate(2, 0.0);
+        heap.increment(0, 4.0);
+        heap.increment(2, 8.0);
+
+        heap.divide_values(4.0);
+
+        assert_eq!(2.0, heap.get_value(2));
+        assert_eq!(Some(2), heap.pop_max());
+        assert_eq!(Some(0), heap.pop_max());
+    }
+}
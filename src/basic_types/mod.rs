@@ -4,6 +4,7 @@ mod constraint_operation_error;
 mod constraint_reference;
 mod csp_solver_execution_flag;
 mod hash_structures;
+mod key_value_heap;
 mod keyed_vec;
 mod propagation_status_cp;
 mod propagation_status_cp_one_step;
@@ -19,6 +20,7 @@ pub use constraint_operation_error::ConstraintOperationError;
 pub(crate) use constraint_reference::ConstraintReference;
 pub(crate) use csp_solver_execution_flag::CSPSolverExecutionFlag;
 pub(crate) use hash_structures::*;
+pub(crate) use key_value_heap::KeyValueHeap;
 pub(crate) use keyed_vec::*;
 pub(crate) use propagation_status_cp::Inconsistency;
 pub(crate) use propagation_status_cp::PropagationStatusCP;
@@ -1,8 +1,16 @@
 use super::conflict_info::StoredConflictInfo;
+use crate::engine::cp::propagation::PropagatorId;
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub(crate) enum PropagationStatusOneStepCP {
-    ConflictDetected { conflict_info: StoredConflictInfo },
+    ConflictDetected {
+        conflict_info: StoredConflictInfo,
+        propagator: PropagatorId,
+    },
+    /// The propagator emptied a domain, the conflict itself is found by the clausal propagator.
+    EmptyDomain {
+        propagator: PropagatorId,
+    },
     PropagationHappened,
     FixedPoint,
 }
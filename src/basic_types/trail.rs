@@ -32,7 +32,7 @@ impl<T> Trail<T> {
         self.current_decision_level
     }
 
-    pub(crate) fn synchronise(&mut self, new_decision_level: usize) -> Rev<Drain<T>> {
+    pub(crate) fn synchronise(&mut self, new_decision_level: usize) -> Rev<Drain<'_, T>> {
         munchkin_assert_simple!(new_decision_level < self.current_decision_level);
 
         let new_trail_len = self.trail_delimiter[new_decision_level];
@@ -42,6 +42,12 @@ impl<T> Trail<T> {
         self.trail.drain(new_trail_len..).rev()
     }
 
+    /// Returns the elements which were added at the current decision level.
+    pub(crate) fn get_current_decision_level_entries(&self) -> &[T] {
+        let start = self.trail_delimiter.last().copied().unwrap_or(0);
+        &self.trail[start..]
+    }
+
     pub(crate) fn push(&mut self, elem: T) {
         self.trail.push(elem)
     }
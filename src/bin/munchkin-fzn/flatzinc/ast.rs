@@ -0,0 +1,117 @@
+//! The abstract syntax tree of a FlatZinc model.
+//!
+//! The tree stays close to the grammar of the [FlatZinc
+//! specification](https://docs.minizinc.dev/en/stable/fzn-spec.html); the interpretation of the
+//! items happens in the compiler.
+
+/// A FlatZinc expression. Annotations are expressions as well.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expr {
+    Bool(bool),
+    Int(i32),
+    Float(f64),
+    String(String),
+    Set(IntSet),
+    Identifier(String),
+    /// An access of the 1-indexed array with the given name.
+    ArrayAccess(String, i32),
+    Array(Vec<Expr>),
+    /// An annotation with arguments, such as `int_search(x, input_order, indomain_min)`.
+    Call(String, Vec<Expr>),
+}
+
+/// A set of integers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum IntSet {
+    /// The values `lower_bound..=upper_bound`.
+    Range(i32, i32),
+    /// The values in the set, in the order in which they appear.
+    Values(Vec<i32>),
+}
+
+impl IntSet {
+    /// The values in the set, in increasing order.
+    pub(crate) fn values(&self) -> Vec<i32> {
+        match self {
+            IntSet::Range(lower_bound, upper_bound) => (*lower_bound..=*upper_bound).collect(),
+            IntSet::Values(values) => {
+                let mut values = values.clone();
+                values.sort_unstable();
+                values.dedup();
+                values
+            }
+        }
+    }
+}
+
+/// The type of a declared parameter or variable, without the `var` or `array` qualifiers.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Type {
+    Bool,
+    /// An integer without restrictions on its domain.
+    Int,
+    /// An integer restricted to the given set.
+    IntIn(IntSet),
+    Float,
+    /// A set of integers.
+    Set,
+}
+
+/// A parameter declaration, such as `array [1..3] of int: xs = [1, 2, 3];`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Parameter {
+    pub(crate) name: String,
+    pub(crate) value: Expr,
+}
+
+/// A variable declaration, such as `var 1..5: x :: output_var;`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Variable {
+    pub(crate) name: String,
+    pub(crate) domain: Type,
+    pub(crate) annotations: Vec<Expr>,
+    /// The value the variable is assigned in its declaration; either a constant or another
+    /// variable.
+    pub(crate) value: Option<Expr>,
+}
+
+/// A declaration of an array of variables, such as `array [1..2] of var int: xs = [x, y];`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VariableArray {
+    pub(crate) name: String,
+    pub(crate) element_type: Type,
+    pub(crate) annotations: Vec<Expr>,
+    pub(crate) elements: Vec<Expr>,
+}
+
+/// A constraint item, such as `constraint int_le(x, y);`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ConstraintItem {
+    pub(crate) name: String,
+    pub(crate) arguments: Vec<Expr>,
+    pub(crate) annotations: Vec<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Goal {
+    Satisfy,
+    Minimize(Expr),
+    Maximize(Expr),
+}
+
+/// The solve item, such as `solve :: int_search(xs, input_order, indomain_min) satisfy;`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SolveItem {
+    pub(crate) annotations: Vec<Expr>,
+    pub(crate) goal: Goal,
+}
+
+/// A FlatZinc model. The items of every kind are kept in the order in which they are declared.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FlatZincModel {
+    pub(crate) parameters: Vec<Parameter>,
+    pub(crate) variables: Vec<Variable>,
+    pub(crate) variable_arrays: Vec<VariableArray>,
+    pub(crate) constraints: Vec<ConstraintItem>,
+    pub(crate) solve: SolveItem,
+}
@@ -0,0 +1,304 @@
+//! Inference of the bounds of integer variables which are declared without a domain.
+//!
+//! As the solver creates the literals of a domain eagerly, every integer variable needs bounds.
+//! MiniZinc omits the domains of variables which are defined by a constraint when it cannot
+//! compute them, so they are derived here from the defining constraints (those annotated with
+//! `defines_var`).
+
+use std::collections::HashMap;
+
+use super::ast::Expr;
+use super::ast::FlatZincModel;
+use super::ast::Type;
+
+type Interval = (i64, i64);
+
+/// Computes the bounds of all integer and Boolean variables for which they can be determined.
+pub(crate) fn infer_bounds(model: &FlatZincModel) -> HashMap<String, Interval> {
+    let mut inference = BoundsInference {
+        parameters: model
+            .parameters
+            .iter()
+            .map(|parameter| (parameter.name.as_str(), &parameter.value))
+            .collect(),
+        arrays: model
+            .variable_arrays
+            .iter()
+            .map(|array| (array.name.as_str(), array.elements.as_slice()))
+            .collect(),
+        bounds: HashMap::new(),
+    };
+
+    for variable in &model.variables {
+        let bounds = match &variable.domain {
+            Type::Bool => Some((0, 1)),
+            Type::IntIn(set) => {
+                let values = set.values();
+                values
+                    .first()
+                    .zip(values.last())
+                    .map(|(&min, &max)| (min as i64, max as i64))
+            }
+            _ => None,
+        };
+
+        if let Some(bounds) = bounds {
+            let _ = inference.bounds.insert(variable.name.clone(), bounds);
+        }
+    }
+
+    loop {
+        let mut changed = false;
+
+        for variable in &model.variables {
+            if inference.bounds.contains_key(&variable.name) {
+                continue;
+            }
+
+            if let Some(bounds) = variable
+                .value
+                .as_ref()
+                .and_then(|value| inference.bounds_of(value))
+            {
+                let _ = inference.bounds.insert(variable.name.clone(), bounds);
+                changed = true;
+            }
+        }
+
+        for constraint in &model.constraints {
+            let Some(target) =
+                constraint
+                    .annotations
+                    .iter()
+                    .find_map(|annotation| match annotation {
+                        Expr::Call(name, arguments) if name == "defines_var" => {
+                            match arguments.as_slice() {
+                                [Expr::Identifier(target)] => Some(target),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    })
+            else {
+                continue;
+            };
+
+            if inference.bounds.contains_key(target) {
+                continue;
+            }
+
+            if let Some(bounds) =
+                inference.infer_defined(&constraint.name, &constraint.arguments, target)
+            {
+                let _ = inference.bounds.insert(target.clone(), bounds);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return inference.bounds;
+        }
+    }
+}
+
+struct BoundsInference<'model> {
+    parameters: HashMap<&'model str, &'model Expr>,
+    arrays: HashMap<&'model str, &'model [Expr]>,
+    bounds: HashMap<String, Interval>,
+}
+
+impl BoundsInference<'_> {
+    fn bounds_of(&self, expr: &Expr) -> Option<Interval> {
+        match expr {
+            Expr::Int(value) => Some((*value as i64, *value as i64)),
+            Expr::Bool(value) => Some((*value as i64, *value as i64)),
+            Expr::Identifier(name) => self.bounds.get(name).copied().or_else(|| {
+                self.parameters
+                    .get(name.as_str())
+                    .and_then(|value| self.bounds_of(value))
+            }),
+            Expr::ArrayAccess(name, index) => {
+                let elements = self.named_elements(name)?;
+                let element = elements.get(usize::try_from(*index).ok()?.checked_sub(1)?)?;
+                self.bounds_of(element)
+            }
+            _ => None,
+        }
+    }
+
+    fn elements<'a>(&'a self, expr: &'a Expr) -> Option<&'a [Expr]> {
+        match expr {
+            Expr::Array(elements) => Some(elements),
+            Expr::Identifier(name) => self.named_elements(name),
+            _ => None,
+        }
+    }
+
+    fn named_elements(&self, name: &str) -> Option<&[Expr]> {
+        self.arrays.get(name).copied().or_else(|| {
+            self.parameters
+                .get(name)
+                .and_then(|value| self.elements(value))
+        })
+    }
+
+    /// The bounds of `target` which follow from the constraint which defines it.
+    fn infer_defined(&self, name: &str, arguments: &[Expr], target: &str) -> Option<Interval> {
+        let is_target = |expr: &Expr| matches!(expr, Expr::Identifier(name) if name == target);
+
+        match (name, arguments) {
+            ("int_lin_eq", [weights, terms, rhs]) => {
+                let weights = self.elements(weights)?;
+                let terms = self.elements(terms)?;
+                let rhs = self.bounds_of(rhs)?;
+
+                let target_index = terms.iter().position(is_target)?;
+                let target_weight = self.bounds_of(&weights[target_index])?.0;
+
+                let rest = weights
+                    .iter()
+                    .zip(terms)
+                    .enumerate()
+                    .filter(|&(index, _)| index != target_index)
+                    .try_fold((0, 0), |sum, (_, (weight, term))| {
+                        let weight = self.bounds_of(weight)?.0;
+                        Some(add(sum, scale(self.bounds_of(term)?, weight)))
+                    })?;
+
+                divide(subtract(rhs, rest), target_weight)
+            }
+            ("int_plus", [a, b, c]) => {
+                if is_target(c) {
+                    Some(add(self.bounds_of(a)?, self.bounds_of(b)?))
+                } else if is_target(a) {
+                    Some(subtract(self.bounds_of(c)?, self.bounds_of(b)?))
+                } else {
+                    Some(subtract(self.bounds_of(c)?, self.bounds_of(a)?))
+                }
+            }
+            ("int_times", [a, b, c]) if is_target(c) => {
+                let (a_min, a_max) = self.bounds_of(a)?;
+                let (b_min, b_max) = self.bounds_of(b)?;
+                let corners = [a_min * b_min, a_min * b_max, a_max * b_min, a_max * b_max];
+
+                Some((*corners.iter().min()?, *corners.iter().max()?))
+            }
+            ("int_abs", [a, b]) if is_target(b) => {
+                let (min, max) = self.bounds_of(a)?;
+                let lower_bound = if min <= 0 && max >= 0 {
+                    0
+                } else {
+                    min.abs().min(max.abs())
+                };
+
+                Some((lower_bound, min.abs().max(max.abs())))
+            }
+            ("int_max", [a, b, c]) if is_target(c) => {
+                let (a_min, a_max) = self.bounds_of(a)?;
+                let (b_min, b_max) = self.bounds_of(b)?;
+
+                Some((a_min.max(b_min), a_max.max(b_max)))
+            }
+            ("int_min", [a, b, c]) if is_target(c) => {
+                let (a_min, a_max) = self.bounds_of(a)?;
+                let (b_min, b_max) = self.bounds_of(b)?;
+
+                Some((a_min.min(b_min), a_max.min(b_max)))
+            }
+            (
+                "array_int_element"
+                | "array_var_int_element"
+                | "array_bool_element"
+                | "array_var_bool_element",
+                [_, array, rhs],
+            ) if is_target(rhs) => self
+                .elements(array)?
+                .iter()
+                .map(|element| self.bounds_of(element))
+                .try_fold((i64::MAX, i64::MIN), |(min, max), bounds| {
+                    let (element_min, element_max) = bounds?;
+                    Some((min.min(element_min), max.max(element_max)))
+                })
+                .filter(|(min, max)| min <= max),
+            ("bool2int", [_, integer]) if is_target(integer) => Some((0, 1)),
+            _ => None,
+        }
+    }
+}
+
+fn add((a_min, a_max): Interval, (b_min, b_max): Interval) -> Interval {
+    (a_min + b_min, a_max + b_max)
+}
+
+fn subtract(a: Interval, (b_min, b_max): Interval) -> Interval {
+    add(a, (-b_max, -b_min))
+}
+
+fn scale((min, max): Interval, factor: i64) -> Interval {
+    if factor >= 0 {
+        (min * factor, max * factor)
+    } else {
+        (max * factor, min * factor)
+    }
+}
+
+/// The bounds of `x` such that `x * divisor` lies within `interval`.
+fn divide(interval: Interval, divisor: i64) -> Option<Interval> {
+    if divisor == 0 {
+        return None;
+    }
+
+    let (min, max) = if divisor > 0 {
+        interval
+    } else {
+        scale(interval, -1)
+    };
+    let divisor = divisor.abs();
+
+    Some((-(-min).div_euclid(divisor), max.div_euclid(divisor)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flatzinc::parse;
+
+    #[test]
+    fn bounds_are_inferred_through_defining_constraints() {
+        let model = parse(
+            "var 1..3: x;
+             var -2..4: y;
+             var int: sum :: is_defined_var;
+             var int: product :: is_defined_var;
+             var int: cost :: is_defined_var;
+             constraint int_lin_eq([2, 3, -1], [x, y, sum], 0) :: defines_var(sum);
+             constraint int_times(sum, x, product) :: defines_var(product);
+             constraint int_lin_eq([-2, 1], [cost, product], 1) :: defines_var(cost);
+             solve satisfy;",
+        )
+        .expect("valid model");
+
+        let bounds = infer_bounds(&model);
+
+        assert_eq!(bounds["sum"], (-4, 18));
+        assert_eq!(bounds["product"], (-12, 54));
+        // -2 * cost = 1 - product, so cost lies within [-53 / -2, 13 / -2].
+        assert_eq!(bounds["cost"], (-6, 26));
+    }
+
+    #[test]
+    fn variables_without_defining_constraint_stay_unbounded() {
+        let model = parse(
+            "var int: x;
+             var 0..4: y;
+             constraint int_le(x, y);
+             solve satisfy;",
+        )
+        .expect("valid model");
+
+        let bounds = infer_bounds(&model);
+
+        assert!(!bounds.contains_key("x"));
+        assert_eq!(bounds["y"], (0, 4));
+    }
+}
@@ -0,0 +1,1242 @@
+//! Compiles a [`FlatZincModel`] into a [`Solver`].
+//!
+//! Every FlatZinc variable, including the Boolean ones, is represented by a [`DomainId`]; a
+//! Boolean variable is a 0-1 integer whose literal is `[x >= 1]`. This allows the Boolean
+//! variables to be used wherever an integer is expected (e.g. in `bool2int` or in element
+//! constraints over arrays of Booleans) and to branch over all variables with a single brancher.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use log::warn;
+use munchkin::constraints;
+use munchkin::constraints::Automaton;
+use munchkin::constraints::NegatableConstraint;
+use munchkin::model::cumulative_impl;
+use munchkin::model::is_disjunctive;
+use munchkin::model::sub_circuit_elimination;
+use munchkin::model::Globals;
+use munchkin::predicate;
+use munchkin::variables::AffineView;
+use munchkin::variables::DomainId;
+use munchkin::variables::Literal;
+use munchkin::variables::TransformableVariable;
+use munchkin::Solver;
+
+use super::ast::ConstraintItem;
+use super::ast::Expr;
+use super::ast::FlatZincModel;
+use super::ast::Goal;
+use super::ast::IntSet;
+use super::ast::Type;
+use super::ast::Variable;
+use super::bounds::infer_bounds;
+use super::output::Output;
+use super::FlatZincError;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Objective {
+    Satisfy,
+    Minimise(DomainId),
+    Maximise(DomainId),
+}
+
+/// The result of compiling a FlatZinc model.
+#[derive(Debug)]
+pub(crate) struct CompiledModel {
+    pub(crate) solver: Solver,
+    pub(crate) objective: Objective,
+    /// The variables in the order in which they should be branched on. The variables in the
+    /// search annotations come first, followed by all other declared variables.
+    pub(crate) search_variables: Vec<DomainId>,
+    pub(crate) outputs: Vec<Output>,
+}
+
+/// Compiles the model into a solver. The global constraints for which `use_global_propagator`
+/// holds are posted as propagators; the others are decomposed. If `free_search` is set, the
+/// search annotations of the model are ignored.
+pub(crate) fn compile(
+    model: FlatZincModel,
+    use_global_propagator: impl Fn(Globals) -> bool,
+    free_search: bool,
+) -> Result<CompiledModel, FlatZincError> {
+    let bounds = infer_bounds(&model);
+
+    let mut compiler = Compiler {
+        solver: Solver::default(),
+        use_global_propagator,
+        item: String::new(),
+        parameters: model
+            .parameters
+            .into_iter()
+            .map(|parameter| (parameter.name, parameter.value))
+            .collect(),
+        arrays: model
+            .variable_arrays
+            .iter()
+            .map(|array| (array.name.clone(), array.elements.clone()))
+            .collect(),
+        variables: HashMap::new(),
+        declared_variables: Vec::new(),
+        constants: HashMap::new(),
+    };
+
+    let mut outputs = Vec::new();
+
+    for variable in &model.variables {
+        compiler.item.clone_from(&variable.name);
+        let domain = compiler.declare_variable(variable, &bounds)?;
+
+        if has_annotation(&variable.annotations, "output_var") {
+            outputs.push(Output::Variable {
+                name: variable.name.clone(),
+                variable: domain,
+                is_bool: variable.domain == Type::Bool,
+            });
+        }
+    }
+
+    for array in &model.variable_arrays {
+        compiler.item.clone_from(&array.name);
+
+        let Some(index_sets) = array
+            .annotations
+            .iter()
+            .find_map(|annotation| match annotation {
+                Expr::Call(name, arguments) if name == "output_array" => arguments.first(),
+                _ => None,
+            })
+        else {
+            continue;
+        };
+
+        let index_sets = compiler
+            .elements(index_sets)?
+            .iter()
+            .map(|index_set| match compiler.set(index_set)? {
+                IntSet::Range(lower_bound, upper_bound) => Ok((lower_bound, upper_bound)),
+                IntSet::Values(_) => Err(compiler.invalid("index sets should be ranges")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        outputs.push(Output::Array {
+            name: array.name.clone(),
+            index_sets,
+            variables: compiler.int_vars(&Expr::Array(array.elements.clone()))?,
+            is_bool: array.element_type == Type::Bool,
+        });
+    }
+
+    for constraint in &model.constraints {
+        compiler.item.clone_from(&constraint.name);
+        compiler.compile_constraint(constraint)?;
+    }
+
+    compiler.item = "solve".to_owned();
+    let objective = match &model.solve.goal {
+        Goal::Satisfy => Objective::Satisfy,
+        Goal::Minimize(objective) => Objective::Minimise(compiler.int_var(objective)?),
+        Goal::Maximize(objective) => Objective::Maximise(compiler.int_var(objective)?),
+    };
+
+    let mut search_variables = Vec::new();
+    if !free_search {
+        for annotation in &model.solve.annotations {
+            compiler.collect_search_variables(annotation, &mut search_variables)?;
+        }
+    }
+    search_variables.extend(compiler.declared_variables.iter().copied());
+    let mut seen = HashSet::new();
+    search_variables.retain(|&variable| seen.insert(variable));
+
+    Ok(CompiledModel {
+        solver: compiler.solver,
+        objective,
+        search_variables,
+        outputs,
+    })
+}
+
+fn has_annotation(annotations: &[Expr], name: &str) -> bool {
+    annotations
+        .iter()
+        .any(|annotation| matches!(annotation, Expr::Identifier(identifier) if identifier == name))
+}
+
+struct Compiler<UseGlobal> {
+    solver: Solver,
+    use_global_propagator: UseGlobal,
+    /// The name of the item which is being compiled, used in error messages.
+    item: String,
+    parameters: HashMap<String, Expr>,
+    /// The elements of the arrays of variables.
+    arrays: HashMap<String, Vec<Expr>>,
+    variables: HashMap<String, DomainId>,
+    /// The domains of the declared variables, in the order of declaration.
+    declared_variables: Vec<DomainId>,
+    /// The fixed domains created for constants.
+    constants: HashMap<i32, DomainId>,
+}
+
+impl<UseGlobal: Fn(Globals) -> bool> Compiler<UseGlobal> {
+    fn invalid(&self, message: impl Into<String>) -> FlatZincError {
+        FlatZincError::InvalidArgument {
+            constraint: self.item.clone(),
+            message: message.into(),
+        }
+    }
+
+    fn use_global_propagator(&self, global: Globals) -> bool {
+        (self.use_global_propagator)(global)
+    }
+
+    fn declare_variable(
+        &mut self,
+        variable: &Variable,
+        bounds: &HashMap<String, (i64, i64)>,
+    ) -> Result<DomainId, FlatZincError> {
+        let name = variable.name.clone();
+
+        let domain = match (&variable.domain, &variable.value) {
+            (Type::Float | Type::Set, _) => return Err(FlatZincError::UnsupportedType(name)),
+
+            // A variable without a domain which is assigned is an alias.
+            (Type::Bool | Type::Int, Some(value)) => self.int_var(value)?,
+
+            (Type::Bool, None) => self.solver.new_named_bounded_integer(0, 1, name),
+
+            (Type::Int, None) => {
+                let (lower_bound, upper_bound) = bounds
+                    .get(&name)
+                    .and_then(|&(lower_bound, upper_bound)| {
+                        Some((
+                            i32::try_from(lower_bound).ok()?,
+                            i32::try_from(upper_bound).ok()?,
+                        ))
+                    })
+                    .ok_or_else(|| FlatZincError::UnboundedVariable(name.clone()))?;
+
+                if lower_bound > upper_bound {
+                    return Err(FlatZincError::EmptyDomain(name));
+                }
+
+                self.solver
+                    .new_named_bounded_integer(lower_bound, upper_bound, name)
+            }
+
+            (Type::IntIn(set), value) => {
+                let domain = match set {
+                    IntSet::Range(lower_bound, upper_bound) if lower_bound <= upper_bound => self
+                        .solver
+                        .new_named_bounded_integer(*lower_bound, *upper_bound, name),
+                    IntSet::Values(values) if !values.is_empty() => {
+                        self.solver.new_named_sparse_integer(values.clone(), name)
+                    }
+                    _ => return Err(FlatZincError::EmptyDomain(name)),
+                };
+
+                if let Some(value) = value {
+                    let value = self.int_var(value)?;
+                    self.solver
+                        .add_constraint(constraints::binary_equals(domain, value))
+                        .post()?;
+                }
+
+                domain
+            }
+        };
+
+        let _ = self.variables.insert(variable.name.clone(), domain);
+        self.declared_variables.push(domain);
+
+        Ok(domain)
+    }
+
+    fn constant(&mut self, value: i32) -> DomainId {
+        let solver = &mut self.solver;
+        *self
+            .constants
+            .entry(value)
+            .or_insert_with(|| solver.new_bounded_integer(value, value))
+    }
+
+    fn array_element(&self, name: &str, index: i32) -> Result<Expr, FlatZincError> {
+        let elements = self.elements(&Expr::Identifier(name.to_owned()))?;
+
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| index.checked_sub(1))
+            .and_then(|index| elements.get(index).cloned())
+            .ok_or_else(|| self.invalid(format!("index {index} is out of bounds for '{name}'")))
+    }
+
+    fn elements(&self, expr: &Expr) -> Result<Vec<Expr>, FlatZincError> {
+        match expr {
+            Expr::Array(elements) => Ok(elements.clone()),
+            Expr::Identifier(name) => {
+                if let Some(elements) = self.arrays.get(name) {
+                    Ok(elements.clone())
+                } else if let Some(value) = self.parameters.get(name) {
+                    self.elements(value)
+                } else {
+                    Err(FlatZincError::UnknownIdentifier(name.clone()))
+                }
+            }
+            _ => Err(self.invalid(format!("expected an array, found {expr:?}"))),
+        }
+    }
+
+    fn int(&self, expr: &Expr) -> Result<i32, FlatZincError> {
+        match expr {
+            Expr::Int(value) => Ok(*value),
+            Expr::Bool(value) => Ok(i32::from(*value)),
+            Expr::Identifier(name) => match self.parameters.get(name) {
+                Some(value) => self.int(value),
+                None if self.variables.contains_key(name) => {
+                    Err(self.invalid(format!("expected a constant, found variable '{name}'")))
+                }
+                None => Err(FlatZincError::UnknownIdentifier(name.clone())),
+            },
+            Expr::ArrayAccess(name, index) => self.int(&self.array_element(name, *index)?),
+            _ => Err(self.invalid(format!("expected an integer, found {expr:?}"))),
+        }
+    }
+
+    fn ints(&self, expr: &Expr) -> Result<Vec<i32>, FlatZincError> {
+        self.elements(expr)?
+            .iter()
+            .map(|element| self.int(element))
+            .collect()
+    }
+
+    fn naturals(&self, expr: &Expr) -> Result<Vec<u32>, FlatZincError> {
+        self.ints(expr)?
+            .into_iter()
+            .map(|value| {
+                u32::try_from(value).map_err(|_| {
+                    self.invalid(format!("expected a non-negative integer, found {value}"))
+                })
+            })
+            .collect()
+    }
+
+    fn set(&self, expr: &Expr) -> Result<IntSet, FlatZincError> {
+        match expr {
+            Expr::Set(set) => Ok(set.clone()),
+            Expr::Identifier(name) => match self.parameters.get(name) {
+                Some(value) => self.set(value),
+                None => Err(FlatZincError::UnknownIdentifier(name.clone())),
+            },
+            _ => Err(self.invalid(format!("expected a set, found {expr:?}"))),
+        }
+    }
+
+    /// Resolves an integer or Boolean expression to a domain; constants are fixed domains.
+    fn int_var(&mut self, expr: &Expr) -> Result<DomainId, FlatZincError> {
+        match expr {
+            Expr::Identifier(name) => match self.variables.get(name) {
+                Some(&domain) => Ok(domain),
+                None => {
+                    let value = self.int(expr)?;
+                    Ok(self.constant(value))
+                }
+            },
+            Expr::ArrayAccess(name, index) => {
+                let element = self.array_element(name, *index)?;
+                self.int_var(&element)
+            }
+            _ => {
+                let value = self.int(expr)?;
+                Ok(self.constant(value))
+            }
+        }
+    }
+
+    fn int_vars(&mut self, expr: &Expr) -> Result<Vec<DomainId>, FlatZincError> {
+        self.elements(expr)?
+            .iter()
+            .map(|element| self.int_var(element))
+            .collect()
+    }
+
+    /// The same as [`Compiler::int_vars`], but as views for the constraints which require them.
+    fn views(&mut self, expr: &Expr) -> Result<Vec<AffineView<DomainId>>, FlatZincError> {
+        Ok(self
+            .int_vars(expr)?
+            .into_iter()
+            .map(AffineView::from)
+            .collect())
+    }
+
+    fn literal(&mut self, expr: &Expr) -> Result<Literal, FlatZincError> {
+        match expr {
+            Expr::Bool(true) => Ok(self.solver.get_true_literal()),
+            Expr::Bool(false) => Ok(self.solver.get_false_literal()),
+            _ => {
+                let domain = self.int_var(expr)?;
+                Ok(self.solver.get_literal(predicate![domain >= 1]))
+            }
+        }
+    }
+
+    fn literals(&mut self, expr: &Expr) -> Result<Vec<Literal>, FlatZincError> {
+        self.elements(expr)?
+            .iter()
+            .map(|element| self.literal(element))
+            .collect()
+    }
+
+    /// The terms `weights_i * variables_i` of a linear constraint; terms with a weight of zero are
+    /// left out.
+    fn linear_terms(
+        &mut self,
+        weights: &Expr,
+        variables: &Expr,
+    ) -> Result<Vec<AffineView<DomainId>>, FlatZincError> {
+        let weights = self.ints(weights)?;
+        let variables = self.int_vars(variables)?;
+
+        if weights.len() != variables.len() {
+            return Err(self.invalid("the weights and variables differ in length"));
+        }
+
+        Ok(weights
+            .into_iter()
+            .zip(variables)
+            .filter(|&(weight, _)| weight != 0)
+            .map(|(weight, variable)| variable.scaled(weight))
+            .collect())
+    }
+
+    /// Posts the constraint, or its reification if a reification literal is given.
+    fn post_negatable(
+        &mut self,
+        constraint: impl NegatableConstraint,
+        reification: Option<&Expr>,
+    ) -> Result<(), FlatZincError> {
+        match reification {
+            Some(reification) => {
+                let reification_literal = self.literal(reification)?;
+                self.solver
+                    .add_constraint(constraint)
+                    .reify(reification_literal)?;
+            }
+            None => self.solver.add_constraint(constraint).post()?,
+        }
+
+        Ok(())
+    }
+
+    fn post_maximum(
+        &mut self,
+        array: Vec<AffineView<DomainId>>,
+        rhs: AffineView<DomainId>,
+    ) -> Result<(), FlatZincError> {
+        if self.use_global_propagator(Globals::Maximum) {
+            self.solver
+                .add_constraint(constraints::maximum(array, rhs))
+                .post()?;
+        } else {
+            self.solver
+                .add_constraint(constraints::maximum_decomposition(array, rhs))
+                .post()?;
+        }
+
+        Ok(())
+    }
+
+    /// Posts `a * b = c`. If neither factor is fixed, the constraint is decomposed into
+    /// `[a = v] -> v * b = c` for every value `v` of the factor with the smallest domain.
+    fn post_times(&mut self, a: DomainId, b: DomainId, c: DomainId) -> Result<(), FlatZincError> {
+        let width = |solver: &Solver, domain: DomainId| {
+            solver.upper_bound(&domain) as i64 - solver.lower_bound(&domain) as i64
+        };
+
+        let (a, b) = if width(&self.solver, a) <= width(&self.solver, b) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        for value in self.solver.lower_bound(&a)..=self.solver.upper_bound(&a) {
+            let terms = if value == 0 {
+                vec![c.scaled(1)]
+            } else {
+                vec![b.scaled(value), c.scaled(-1)]
+            };
+            let product = constraints::equals(terms, 0);
+
+            if self.solver.lower_bound(&a) == self.solver.upper_bound(&a) {
+                self.solver.add_constraint(product).post()?;
+            } else {
+                let a_equals_value = self.solver.get_literal(predicate![a == value]);
+                self.solver
+                    .add_constraint(product)
+                    .implied_by(a_equals_value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn compile_constraint(&mut self, constraint: &ConstraintItem) -> Result<(), FlatZincError> {
+        let name = constraint.name.as_str();
+        let arguments = constraint.arguments.as_slice();
+
+        // The reified variants have the reification literal as their last argument.
+        let (name, arguments, reification) =
+            match (name.strip_suffix("_reif"), arguments.split_last()) {
+                (Some(base_name), Some((reification, arguments))) => {
+                    (base_name, arguments, Some(reification))
+                }
+                _ => (name, arguments, None),
+            };
+
+        match (name, arguments, reification) {
+            ("int_eq" | "bool_eq", [a, b], reification) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_negatable(constraints::binary_equals(a, b), reification)?;
+            }
+            ("int_ne" | "bool_xor", [a, b], reification) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_negatable(constraints::binary_not_equals(a, b), reification)?;
+            }
+            ("bool_xor", [a, b, r], None) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_negatable(constraints::binary_not_equals(a, b), Some(r))?;
+            }
+            ("int_le" | "bool_le", [a, b], reification) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_negatable(constraints::binary_less_than_or_equals(a, b), reification)?;
+            }
+            ("int_lt" | "bool_lt", [a, b], reification) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_negatable(constraints::binary_less_than(a, b), reification)?;
+            }
+            ("int_lin_eq", [weights, variables, rhs], reification) => {
+                let terms = self.linear_terms(weights, variables)?;
+                let rhs = self.int(rhs)?;
+                self.post_negatable(constraints::equals(terms, rhs), reification)?;
+            }
+            ("int_lin_ne", [weights, variables, rhs], reification) => {
+                let terms = self.linear_terms(weights, variables)?;
+                let rhs = self.int(rhs)?;
+                self.post_negatable(constraints::not_equals(terms, rhs), reification)?;
+            }
+            ("int_lin_le", [weights, variables, rhs], reification) => {
+                let terms = self.linear_terms(weights, variables)?;
+                let rhs = self.int(rhs)?;
+                self.post_negatable(constraints::less_than_or_equals(terms, rhs), reification)?;
+            }
+            ("int_plus", [a, b, c], None) => {
+                let (a, b, c) = (self.int_var(a)?, self.int_var(b)?, self.int_var(c)?);
+                self.solver
+                    .add_constraint(constraints::plus(a, b, c))
+                    .post()?;
+            }
+            ("int_times", [a, b, c], None) => {
+                let (a, b, c) = (self.int_var(a)?, self.int_var(b)?, self.int_var(c)?);
+                self.post_times(a, b, c)?;
+            }
+            ("int_abs", [a, b], None) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.post_maximum(vec![a.scaled(1), a.scaled(-1)], b.scaled(1))?;
+            }
+            ("int_max", [a, b, c], None) => {
+                let (a, b, c) = (self.int_var(a)?, self.int_var(b)?, self.int_var(c)?);
+                self.post_maximum(vec![a.scaled(1), b.scaled(1)], c.scaled(1))?;
+            }
+            ("int_min", [a, b, c], None) => {
+                let (a, b, c) = (self.int_var(a)?, self.int_var(b)?, self.int_var(c)?);
+                self.post_maximum(vec![a.scaled(-1), b.scaled(-1)], c.scaled(-1))?;
+            }
+            ("set_in", [x, set], reification) => {
+                let x = self.int_var(x)?;
+                let set = self.set(set)?;
+                self.compile_set_in(x, set, reification)?;
+            }
+            (
+                "array_int_element"
+                | "array_var_int_element"
+                | "array_bool_element"
+                | "array_var_bool_element",
+                [index, array, rhs],
+                None,
+            ) => {
+                let index = self.int_var(index)?;
+                let array = self.int_vars(array)?;
+                let rhs = self.int_var(rhs)?;
+
+                // The element constraint does not restrict the index to the array.
+                let length = array.len() as i32;
+                self.solver
+                    .add_constraint(constraints::conjunction(vec![
+                        self.solver.get_literal(predicate![index >= 1]),
+                        self.solver.get_literal(predicate![index <= length]),
+                    ]))
+                    .post()?;
+
+                if self.use_global_propagator(Globals::Element) {
+                    self.solver
+                        .add_constraint(constraints::element(index, array, rhs))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::element_decomposition(index, array, rhs))
+                        .post()?;
+                }
+            }
+            ("bool2int", [b, x], None) => {
+                let b = self.literal(b)?;
+                let x = self.int_var(x)?;
+                self.solver
+                    .add_constraint(constraints::bool2int(b, x))
+                    .post()?;
+            }
+            ("bool_not", [a, b], None) => {
+                let (a, b) = (self.int_var(a)?, self.int_var(b)?);
+                self.solver
+                    .add_constraint(constraints::equals([a, b], 1))
+                    .post()?;
+            }
+            ("bool_and" | "bool_or", [a, b, r], None) => {
+                let literals = vec![self.literal(a)?, self.literal(b)?];
+                let r = self.literal(r)?;
+                self.compile_reified_array(name == "bool_and", literals, r)?;
+            }
+            ("array_bool_and" | "array_bool_or", [literals, r], None) => {
+                let literals = self.literals(literals)?;
+                let r = self.literal(r)?;
+                self.compile_reified_array(name == "array_bool_and", literals, r)?;
+            }
+            ("array_bool_xor", [literals], None) => {
+                let literals = self.literals(literals)?;
+                self.solver
+                    .add_constraint(constraints::xor(literals, true))
+                    .post()?;
+            }
+            ("bool_clause", [positive, negative], reification) => {
+                let mut literals = self.literals(positive)?;
+                literals.extend(self.literals(negative)?.into_iter().map(|literal| !literal));
+                self.post_negatable(constraints::clause(literals), reification)?;
+            }
+            ("bool_lin_eq", [weights, literals, rhs], None) => {
+                let weights = self.ints(weights)?;
+                let literals = self.literals(literals)?;
+                let rhs = self.int_var(rhs)?;
+                self.solver
+                    .add_constraint(constraints::boolean_equals(weights, literals, rhs))
+                    .post()?;
+            }
+            ("bool_lin_le", [weights, literals, rhs], None) => {
+                let weights = self.ints(weights)?;
+                let literals = self.literals(literals)?;
+                let rhs = self.int(rhs)?;
+                self.solver
+                    .add_constraint(constraints::boolean_less_than_or_equals(
+                        weights, literals, rhs,
+                    ))
+                    .post()?;
+            }
+            _ if name.starts_with("munchkin_") && reification.is_none() => {
+                self.compile_global(constraint)?
+            }
+            _ => {
+                return Err(FlatZincError::UnsupportedConstraint(
+                    constraint.name.clone(),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Posts `r <-> /\ literals` if `is_conjunction`, and `r <-> \/ literals` otherwise.
+    fn compile_reified_array(
+        &mut self,
+        is_conjunction: bool,
+        literals: Vec<Literal>,
+        r: Literal,
+    ) -> Result<(), FlatZincError> {
+        if is_conjunction {
+            self.solver
+                .add_constraint(constraints::array_bool_and(literals, r))
+                .post()?;
+        } else {
+            self.solver
+                .add_constraint(constraints::array_bool_or(literals, r))
+                .post()?;
+        }
+
+        Ok(())
+    }
+
+    fn compile_set_in(
+        &mut self,
+        x: DomainId,
+        set: IntSet,
+        reification: Option<&Expr>,
+    ) -> Result<(), FlatZincError> {
+        let reification = reification.map(|r| self.literal(r)).transpose()?;
+
+        match set {
+            IntSet::Range(lower_bound, upper_bound) => {
+                let bounds = vec![
+                    self.solver.get_literal(predicate![x >= lower_bound]),
+                    self.solver.get_literal(predicate![x <= upper_bound]),
+                ];
+
+                match reification {
+                    Some(r) => self.compile_reified_array(true, bounds, r)?,
+                    None => self
+                        .solver
+                        .add_constraint(constraints::conjunction(bounds))
+                        .post()?,
+                }
+            }
+            IntSet::Values(values) => match reification {
+                Some(r) => {
+                    let equalities = values
+                        .into_iter()
+                        .map(|value| self.solver.get_literal(predicate![x == value]))
+                        .collect();
+                    self.compile_reified_array(false, equalities, r)?;
+                }
+                None => {
+                    let disequalities = (self.solver.lower_bound(&x)..=self.solver.upper_bound(&x))
+                        .filter(|value| !values.contains(value))
+                        .map(|value| self.solver.get_literal(predicate![x != value]))
+                        .collect::<Vec<_>>();
+                    self.solver
+                        .add_constraint(constraints::conjunction(disequalities))
+                        .post()?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the global constraints which are declared in `mznlib`.
+    /// Creates the automaton of a `regular` constraint, whose states are `1..=num_states` and whose
+    /// symbols are `1..=num_symbols`. The transitions to state 0 go to the failing state.
+    fn automaton(
+        &self,
+        num_states: &Expr,
+        num_symbols: &Expr,
+        table: &Expr,
+        initial: &Expr,
+        accepting: &Expr,
+    ) -> Result<Automaton, FlatZincError> {
+        let num_states = self.int(num_states)?;
+        let num_symbols = self.int(num_symbols)?;
+        let table = self.ints(table)?;
+        let initial = self.int(initial)?;
+        let accepting = self.set(accepting)?.values();
+
+        if num_states < 1 || num_symbols < 0 {
+            return Err(self.invalid("expected at least one state and no negative symbols"));
+        }
+        if table.len() != (num_states * num_symbols) as usize {
+            return Err(self.invalid(format!(
+                "expected a transition table with {} entries, found {}",
+                num_states * num_symbols,
+                table.len()
+            )));
+        }
+
+        let is_state = |state: i32| (1..=num_states).contains(&state);
+        if !is_state(initial) || !accepting.iter().all(|&state| is_state(state)) {
+            return Err(self.invalid("the initial and accepting states should be in 1..=Q"));
+        }
+        if let Some(&state) = table.iter().find(|&&state| state != 0 && !is_state(state)) {
+            return Err(self.invalid(format!("transition to state {state} is not in 0..=Q")));
+        }
+
+        let transitions =
+            table
+                .iter()
+                .enumerate()
+                .filter(|&(_, &to)| to != 0)
+                .map(|(index, &to)| {
+                    let from = index / num_symbols as usize;
+                    let symbol = (index % num_symbols as usize) as i32 + 1;
+                    (from, symbol, to as usize - 1)
+                });
+
+        Ok(Automaton::new(
+            num_states as usize,
+            initial as usize - 1,
+            accepting.iter().map(|&state| state as usize - 1),
+            transitions,
+        ))
+    }
+
+    fn compile_global(&mut self, constraint: &ConstraintItem) -> Result<(), FlatZincError> {
+        match (constraint.name.as_str(), constraint.arguments.as_slice()) {
+            ("munchkin_all_different", [variables]) => {
+                let variables = self.int_vars(variables)?;
+
+                if self.use_global_propagator(Globals::AllDifferent) {
+                    self.solver
+                        .add_constraint(constraints::all_different(variables))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::all_different_decomposition(variables))
+                        .post()?;
+                }
+            }
+            ("munchkin_circuit", [successors]) => {
+                let successors = self.views(successors)?;
+
+                self.solver
+                    .add_constraint(constraints::circuit(
+                        successors,
+                        sub_circuit_elimination(&self.use_global_propagator)?,
+                        !self.use_global_propagator(Globals::AllDifferent),
+                        !self.use_global_propagator(Globals::Element),
+                    ))
+                    .post()?;
+            }
+            ("munchkin_subcircuit", [successors]) => {
+                let successors = self.int_vars(successors)?;
+                let use_all_different_decomposition =
+                    !self.use_global_propagator(Globals::AllDifferent);
+
+                if self.use_global_propagator(Globals::SubCircuit) {
+                    self.solver
+                        .add_constraint(constraints::subcircuit(
+                            successors,
+                            use_all_different_decomposition,
+                        ))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::subcircuit_decomposition(
+                            successors,
+                            use_all_different_decomposition,
+                        ))
+                        .post()?;
+                }
+            }
+            (
+                "munchkin_cumulative",
+                [start_times, durations, resource_requirements, resource_capacity],
+            ) => {
+                let start_times = self.int_vars(start_times)?;
+                let durations = self.naturals(durations)?;
+                let resource_requirements = self.naturals(resource_requirements)?;
+                let resource_capacity = u32::try_from(self.int(resource_capacity)?)
+                    .map_err(|_| self.invalid("the capacity should be non-negative"))?;
+
+                if self.use_global_propagator(Globals::Disjunctive)
+                    && is_disjunctive(&resource_requirements, resource_capacity)
+                {
+                    // Tasks which do not require the resource are not constrained.
+                    let (start_times, durations): (Vec<_>, Vec<_>) = start_times
+                        .into_iter()
+                        .zip(durations)
+                        .zip(resource_requirements)
+                        .filter(|&(_, requirement)| requirement > 0)
+                        .map(|(task, _)| task)
+                        .unzip();
+
+                    self.solver
+                        .add_constraint(constraints::disjunctive(start_times, durations))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::cumulative(
+                            cumulative_impl(&self.use_global_propagator)?,
+                            start_times,
+                            durations,
+                            resource_requirements,
+                            resource_capacity,
+                        ))
+                        .post()?;
+                }
+            }
+            ("munchkin_disjunctive", [start_times, durations]) => {
+                let start_times = self.int_vars(start_times)?;
+                let durations = self.naturals(durations)?;
+
+                if self.use_global_propagator(Globals::Disjunctive) {
+                    self.solver
+                        .add_constraint(constraints::disjunctive(start_times, durations))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::disjunctive_decomposition(
+                            start_times,
+                            durations,
+                        ))
+                        .post()?;
+                }
+            }
+            ("munchkin_among", [count, variables, values]) => {
+                let count = self.int_var(count)?;
+                let variables = self.int_vars(variables)?;
+                let values = self.set(values)?.values();
+
+                if self.use_global_propagator(Globals::Among) {
+                    self.solver
+                        .add_constraint(constraints::among(variables, values, count))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::among_decomposition(
+                            variables,
+                            values,
+                            count.scaled(1),
+                        ))
+                        .post()?;
+                }
+            }
+            ("munchkin_count_eq", [variables, value, count]) => {
+                let variables = self.int_vars(variables)?;
+                let value = self.int(value)?;
+                let count = self.int_var(count)?;
+
+                if self.use_global_propagator(Globals::Count) {
+                    self.solver
+                        .add_constraint(constraints::count_eq(variables, value, count))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::count_eq_decomposition(
+                            variables,
+                            value,
+                            count.scaled(1),
+                        ))
+                        .post()?;
+                }
+            }
+            ("munchkin_global_cardinality", [variables, values, counts]) => {
+                let variables = self.int_vars(variables)?;
+                let values = self.ints(values)?;
+                let counts = self.views(counts)?;
+
+                if self.use_global_propagator(Globals::GlobalCardinality) {
+                    self.solver
+                        .add_constraint(constraints::global_cardinality(variables, values, counts))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::global_cardinality_decomposition(
+                            variables, values, counts,
+                        ))
+                        .post()?;
+                }
+            }
+            ("munchkin_inverse", [f, g]) => {
+                let f = self.int_vars(f)?;
+                let g = self.int_vars(g)?;
+
+                if self.use_global_propagator(Globals::Inverse) {
+                    self.solver
+                        .add_constraint(constraints::inverse(f, g))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::inverse_decomposition(f, g))
+                        .post()?;
+                }
+            }
+            ("munchkin_bin_packing_load", [loads, bins, sizes]) => {
+                let loads = self.views(loads)?;
+                let bins = self.int_vars(bins)?;
+                let sizes = self.naturals(sizes)?;
+
+                if self.use_global_propagator(Globals::BinPacking) {
+                    self.solver
+                        .add_constraint(constraints::bin_packing(bins, sizes, loads))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::bin_packing_decomposition(bins, sizes, loads))
+                        .post()?;
+                }
+            }
+            ("munchkin_diffn", [xs, ys, widths, heights]) => {
+                let xs = self.int_vars(xs)?;
+                let ys = self.int_vars(ys)?;
+                let widths = self.naturals(widths)?;
+                let heights = self.naturals(heights)?;
+
+                if self.use_global_propagator(Globals::DiffnCumulativeProjections) {
+                    self.solver
+                        .add_constraint(constraints::diffn_cumulative_projections(
+                            cumulative_impl(&self.use_global_propagator)?,
+                            xs.clone(),
+                            ys.clone(),
+                            widths.clone(),
+                            heights.clone(),
+                        ))
+                        .post()?;
+                }
+
+                if self.use_global_propagator(Globals::Diffn) {
+                    self.solver
+                        .add_constraint(constraints::diffn(xs, ys, widths, heights))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::diffn_decomposition(xs, ys, widths, heights))
+                        .post()?;
+                }
+            }
+            ("munchkin_lex_less" | "munchkin_lex_lesseq", [xs, ys]) => {
+                let xs = self.int_vars(xs)?;
+                let ys = self.int_vars(ys)?;
+
+                if constraint.name == "munchkin_lex_less" {
+                    self.solver
+                        .add_constraint(constraints::lex_less(xs, ys))
+                        .post()?;
+                } else {
+                    self.solver
+                        .add_constraint(constraints::lex_less_eq(xs, ys))
+                        .post()?;
+                }
+            }
+            ("munchkin_value_precede_chain", [values, variables]) => {
+                let values = self.ints(values)?;
+                let variables = self.int_vars(variables)?;
+
+                self.solver
+                    .add_constraint(constraints::value_precede_chain(values, variables))
+                    .post()?;
+            }
+            (
+                "munchkin_regular",
+                [variables, num_states, num_symbols, table, initial, accepting],
+            ) => {
+                let variables = self.int_vars(variables)?;
+                let automaton =
+                    self.automaton(num_states, num_symbols, table, initial, accepting)?;
+
+                self.solver
+                    .add_constraint(constraints::regular(variables, automaton))
+                    .post()?;
+            }
+            ("munchkin_maximum", [rhs, array]) => {
+                let rhs = self.int_var(rhs)?;
+                let array = self.views(array)?;
+                self.post_maximum(array, rhs.scaled(1))?;
+            }
+            ("munchkin_minimum", [rhs, array]) => {
+                let rhs = self.int_var(rhs)?;
+                let array = self
+                    .int_vars(array)?
+                    .into_iter()
+                    .map(|variable| variable.scaled(-1))
+                    .collect();
+                self.post_maximum(array, rhs.scaled(-1))?;
+            }
+            _ => {
+                return Err(FlatZincError::UnsupportedConstraint(
+                    constraint.name.clone(),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the variables of a search annotation to `search_variables`, in order.
+    fn collect_search_variables(
+        &mut self,
+        annotation: &Expr,
+        search_variables: &mut Vec<DomainId>,
+    ) -> Result<(), FlatZincError> {
+        let Expr::Call(name, arguments) = annotation else {
+            return Ok(());
+        };
+
+        match (name.as_str(), arguments.as_slice()) {
+            ("seq_search" | "warm_start_array", [annotations]) => {
+                for annotation in self.elements(annotations)? {
+                    self.collect_search_variables(&annotation, search_variables)?;
+                }
+            }
+            (
+                "int_search" | "bool_search",
+                [variables, variable_selection, value_selection, ..],
+            ) => {
+                let is_supported = matches!(
+                    (variable_selection, value_selection),
+                    (Expr::Identifier(variable_selection), Expr::Identifier(value_selection))
+                        if variable_selection == "input_order" && value_selection == "indomain_min"
+                );
+                if !is_supported {
+                    warn!(
+                        "Only input_order and indomain_min are supported in {name}, falling back \
+                         to them"
+                    );
+                }
+
+                search_variables.extend(self.int_vars(variables)?);
+            }
+            _ => warn!("Ignoring unsupported search annotation {name}"),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use munchkin::branching::InDomainMin;
+    use munchkin::branching::InputOrder;
+    use munchkin::results::OptimisationResult;
+    use munchkin::results::ProblemSolution;
+    use munchkin::results::SatisfactionResult;
+    use munchkin::termination::Indefinite;
+
+    use super::*;
+    use crate::flatzinc::parse;
+
+    fn compile_source(source: &str) -> Result<CompiledModel, FlatZincError> {
+        compile(parse(source).expect("valid model"), |_| false, false)
+    }
+
+    fn brancher(
+        model: &CompiledModel,
+    ) -> IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin> {
+        IndependentVariableValueBrancher::new(
+            InputOrder::new(model.search_variables.clone()),
+            InDomainMin,
+        )
+    }
+
+    #[test]
+    fn optimisation_model_is_solved() {
+        let mut model = compile_source(
+            "array [1..3] of int: ws = [2, 3, 4];
+             var 0..5: x :: output_var;
+             var 0..5: y :: output_var;
+             var bool: b :: output_var;
+             var int: objective :: is_defined_var;
+             constraint int_lin_le([1, 1], [x, y], 6);
+             constraint int_le_reif(x, 2, b);
+             constraint bool_clause([b], []);
+             constraint int_lin_eq([2, 3, -1], [x, y, objective], 0) :: defines_var(objective);
+             solve maximize objective;",
+        )
+        .expect("the model compiles");
+
+        let Objective::Maximise(objective) = model.objective else {
+            panic!("expected a maximisation objective");
+        };
+
+        let mut brancher = brancher(&model);
+        match model
+            .solver
+            .maximise(&mut brancher, &mut Indefinite, objective)
+        {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(solution.get_integer_value(objective), 17);
+            }
+            result => panic!("expected an optimal solution, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn constants_and_array_accesses_are_resolved() {
+        let mut model = compile_source(
+            "array [1..3] of int: costs = [5, 7, 9];
+             var 1..3: i;
+             var 0..10: c;
+             array [1..2] of var int: xs :: output_array([1..2]) = [i, c];
+             constraint array_int_element(i, costs, c);
+             constraint int_ne(xs[1], 1);
+             constraint set_in(c, {5, 9});
+             solve satisfy;",
+        )
+        .expect("the model compiles");
+
+        let mut brancher = brancher(&model);
+        match model.solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                let Output::Array { variables, .. } = &model.outputs[0] else {
+                    panic!("expected an output array");
+                };
+
+                assert_eq!(solution.get_integer_value(variables[0]), 3);
+                assert_eq!(solution.get_integer_value(variables[1]), 9);
+            }
+            result => panic!("expected a solution, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn regular_constraint_is_compiled() {
+        // Accepts the sequences over {1, 2} which end with a 2 and do not contain two
+        // consecutive 2s.
+        let mut model = compile_source(
+            "array [1..4] of int: d = [1, 2, 1, 0];
+             var 1..2: x1 :: output_var;
+             var 1..2: x2 :: output_var;
+             var 1..2: x3 :: output_var;
+             constraint munchkin_regular([x1, x2, x3], 2, 2, d, 1, {2});
+             solve satisfy;",
+        )
+        .expect("the model compiles");
+
+        let mut brancher = brancher(&model);
+        match model.solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                let values: Vec<i32> = model.outputs[..]
+                    .iter()
+                    .map(|output| match output {
+                        Output::Variable { variable, .. } => solution.get_integer_value(*variable),
+                        _ => panic!("expected an output variable"),
+                    })
+                    .collect();
+
+                assert_eq!(values, vec![1, 1, 2]);
+            }
+            result => panic!("expected a solution, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn regular_constraint_with_invalid_table_is_reported() {
+        let result = compile_source(
+            "var 1..2: x;
+             constraint munchkin_regular([x], 2, 2, [1, 2, 1], 1, {2});
+             solve satisfy;",
+        );
+
+        assert!(matches!(result, Err(FlatZincError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn root_infeasibility_is_reported() {
+        let result = compile_source(
+            "var 1..3: x;
+             constraint int_le(x, 0);
+             solve satisfy;",
+        );
+
+        assert!(matches!(result, Err(FlatZincError::Infeasible(_))));
+    }
+
+    #[test]
+    fn unsupported_constraints_are_reported() {
+        let result = compile_source(
+            "var 1..3: x;
+             var 1..3: y;
+             var 1..3: z;
+             constraint int_div(x, y, z);
+             solve satisfy;",
+        );
+
+        assert!(matches!(
+            result,
+            Err(FlatZincError::UnsupportedConstraint(name)) if name == "int_div"
+        ));
+    }
+}
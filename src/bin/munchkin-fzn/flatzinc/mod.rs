@@ -0,0 +1,51 @@
+//! Reading FlatZinc models into a [`Solver`](munchkin::Solver).
+//!
+//! A model is first parsed into its [`ast`], which is then compiled into a solver. The builtins
+//! of FlatZinc are mapped to the constraints in [`munchkin::constraints`]; the global constraints
+//! are declared by the redefinitions in `mznlib` as `munchkin_*` predicates.
+
+mod ast;
+mod bounds;
+mod compiler;
+mod output;
+mod parser;
+
+pub(crate) use compiler::compile;
+pub(crate) use compiler::CompiledModel;
+pub(crate) use compiler::Objective;
+pub(crate) use output::print_solution;
+pub(crate) use output::Output;
+pub(crate) use parser::parse;
+
+use munchkin::model::ModelError;
+use munchkin::ConstraintOperationError;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum FlatZincError {
+    #[error("syntax error on line {line}: {message}")]
+    Syntax { line: usize, message: String },
+
+    #[error("the constraint '{0}' is not supported")]
+    UnsupportedConstraint(String),
+
+    #[error("the type of '{0}' is not supported")]
+    UnsupportedType(String),
+
+    #[error("unknown identifier '{0}'")]
+    UnknownIdentifier(String),
+
+    #[error("invalid argument for '{constraint}': {message}")]
+    InvalidArgument { constraint: String, message: String },
+
+    #[error("could not determine the bounds of '{0}'")]
+    UnboundedVariable(String),
+
+    #[error("the domain of '{0}' is empty")]
+    EmptyDomain(String),
+
+    #[error(transparent)]
+    Model(#[from] ModelError),
+
+    #[error("the model is infeasible at the root")]
+    Infeasible(#[from] ConstraintOperationError),
+}
@@ -0,0 +1,69 @@
+//! Printing solutions in the output format of FlatZinc.
+
+use munchkin::results::ProblemSolution;
+use munchkin::variables::DomainId;
+
+/// A variable or array annotated with `output_var` or `output_array`.
+#[derive(Clone, Debug)]
+pub(crate) enum Output {
+    Variable {
+        name: String,
+        variable: DomainId,
+        is_bool: bool,
+    },
+    Array {
+        name: String,
+        /// The bounds of the index set of every dimension of the array.
+        index_sets: Vec<(i32, i32)>,
+        variables: Vec<DomainId>,
+        is_bool: bool,
+    },
+}
+
+/// Prints the values of the outputs in the solution, followed by the solution separator.
+pub(crate) fn print_solution(outputs: &[Output], solution: &impl ProblemSolution) {
+    for output in outputs {
+        match output {
+            Output::Variable {
+                name,
+                variable,
+                is_bool,
+            } => println!("{name} = {};", format_value(solution, *variable, *is_bool)),
+
+            Output::Array {
+                name,
+                index_sets,
+                variables,
+                is_bool,
+            } => {
+                let index_sets: Vec<String> = index_sets
+                    .iter()
+                    .map(|(lower_bound, upper_bound)| format!("{lower_bound}..{upper_bound}"))
+                    .collect();
+                let values: Vec<String> = variables
+                    .iter()
+                    .map(|&variable| format_value(solution, variable, *is_bool))
+                    .collect();
+
+                println!(
+                    "{name} = array{}d({}, [{}]);",
+                    index_sets.len(),
+                    index_sets.join(", "),
+                    values.join(", ")
+                );
+            }
+        }
+    }
+
+    println!("----------");
+}
+
+fn format_value(solution: &impl ProblemSolution, variable: DomainId, is_bool: bool) -> String {
+    let value = solution.get_integer_value(variable);
+
+    if is_bool {
+        (value == 1).to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,667 @@
+//! A recursive-descent parser for FlatZinc.
+//!
+//! Predicate declarations are skipped, as the solver only needs to know the names of the
+//! constraints it supports.
+
+use super::ast::ConstraintItem;
+use super::ast::Expr;
+use super::ast::FlatZincModel;
+use super::ast::Goal;
+use super::ast::IntSet;
+use super::ast::Parameter;
+use super::ast::SolveItem;
+use super::ast::Type;
+use super::ast::Variable;
+use super::ast::VariableArray;
+use super::FlatZincError;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Int(i32),
+    Float(f64),
+    String(String),
+    Semicolon,
+    Colon,
+    DoubleColon,
+    Comma,
+    DotDot,
+    Equals,
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenBracket,
+    CloseBracket,
+    OpenBrace,
+    CloseBrace,
+}
+
+/// Parses the source of a FlatZinc model.
+pub(crate) fn parse(source: &str) -> Result<FlatZincModel, FlatZincError> {
+    let tokens = tokenize(source)?;
+
+    Parser {
+        tokens,
+        position: 0,
+    }
+    .parse_model()
+}
+
+fn syntax_error(line: usize, message: impl Into<String>) -> FlatZincError {
+    FlatZincError::Syntax {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Splits the source into tokens, each paired with the line on which it occurs.
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, FlatZincError> {
+    let characters: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut index = 0;
+
+    while index < characters.len() {
+        let character = characters[index];
+        let next = characters.get(index + 1).copied();
+
+        let token = match character {
+            '\n' => {
+                line += 1;
+                index += 1;
+                continue;
+            }
+            _ if character.is_whitespace() => {
+                index += 1;
+                continue;
+            }
+            '%' => {
+                while index < characters.len() && characters[index] != '\n' {
+                    index += 1;
+                }
+                continue;
+            }
+            ';' => Token::Semicolon,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '[' => Token::OpenBracket,
+            ']' => Token::CloseBracket,
+            '{' => Token::OpenBrace,
+            '}' => Token::CloseBrace,
+            ':' if next == Some(':') => {
+                index += 2;
+                tokens.push((Token::DoubleColon, line));
+                continue;
+            }
+            ':' => Token::Colon,
+            '.' if next == Some('.') => {
+                index += 2;
+                tokens.push((Token::DotDot, line));
+                continue;
+            }
+            '"' => {
+                let start = index + 1;
+                index = start;
+                while index < characters.len() && characters[index] != '"' {
+                    index += if characters[index] == '\\' { 2 } else { 1 };
+                }
+                if index >= characters.len() {
+                    return Err(syntax_error(line, "unterminated string literal"));
+                }
+
+                let string = characters[start..index].iter().collect();
+                index += 1;
+                tokens.push((Token::String(string), line));
+                continue;
+            }
+            _ if character.is_ascii_digit()
+                || (character == '-' && next.is_some_and(|c| c.is_ascii_digit())) =>
+            {
+                let start = index;
+                index += 1;
+                while index < characters.len() && characters[index].is_ascii_digit() {
+                    index += 1;
+                }
+
+                // A '.' which is not part of '..' makes the number a float.
+                let is_float = characters.get(index) == Some(&'.')
+                    && characters
+                        .get(index + 1)
+                        .is_some_and(|c| c.is_ascii_digit());
+                if is_float {
+                    index += 1;
+                    while index < characters.len()
+                        && (characters[index].is_ascii_alphanumeric()
+                            || matches!(characters[index], '.' | '-' | '+'))
+                    {
+                        index += 1;
+                    }
+                }
+
+                let text: String = characters[start..index].iter().collect();
+                let token = if is_float {
+                    text.parse()
+                        .map(Token::Float)
+                        .map_err(|_| syntax_error(line, format!("invalid float '{text}'")))?
+                } else {
+                    text.parse().map(Token::Int).map_err(|_| {
+                        syntax_error(line, format!("integer '{text}' does not fit in 32 bits"))
+                    })?
+                };
+
+                tokens.push((token, line));
+                continue;
+            }
+            _ if character.is_ascii_alphabetic() || character == '_' => {
+                let start = index;
+                while index < characters.len()
+                    && (characters[index].is_ascii_alphanumeric() || characters[index] == '_')
+                {
+                    index += 1;
+                }
+
+                let identifier = characters[start..index].iter().collect();
+                tokens.push((Token::Identifier(identifier), line));
+                continue;
+            }
+            _ => {
+                return Err(syntax_error(
+                    line,
+                    format!("unexpected character '{character}'"),
+                ))
+            }
+        };
+
+        tokens.push((token, line));
+        index += 1;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn peek_second(&self) -> Option<&Token> {
+        self.tokens.get(self.position + 1).map(|(token, _)| token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or(self.tokens.last())
+            .map_or(1, |&(_, line)| line)
+    }
+
+    fn error(&self, message: impl Into<String>) -> FlatZincError {
+        syntax_error(self.line(), message)
+    }
+
+    fn next(&mut self) -> Result<Token, FlatZincError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .map(|(token, _)| token.clone())
+            .ok_or_else(|| self.error("unexpected end of file"))?;
+        self.position += 1;
+
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), FlatZincError> {
+        let token = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            self.position -= 1;
+            Err(self.error(format!("expected {expected:?}, found {token:?}")))
+        }
+    }
+
+    /// Consumes the next token if it is the given one.
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Identifier(identifier)) if identifier == keyword)
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), FlatZincError> {
+        self.expect(Token::Identifier(keyword.to_owned()))
+    }
+
+    fn identifier(&mut self) -> Result<String, FlatZincError> {
+        match self.next()? {
+            Token::Identifier(identifier) => Ok(identifier),
+            token => {
+                self.position -= 1;
+                Err(self.error(format!("expected an identifier, found {token:?}")))
+            }
+        }
+    }
+
+    fn int(&mut self) -> Result<i32, FlatZincError> {
+        match self.next()? {
+            Token::Int(value) => Ok(value),
+            token => {
+                self.position -= 1;
+                Err(self.error(format!("expected an integer, found {token:?}")))
+            }
+        }
+    }
+
+    fn parse_model(mut self) -> Result<FlatZincModel, FlatZincError> {
+        let mut parameters = Vec::new();
+        let mut variables = Vec::new();
+        let mut variable_arrays = Vec::new();
+        let mut constraints = Vec::new();
+        let mut solve = None;
+
+        while self.peek().is_some() {
+            if self.is_keyword("predicate") {
+                self.skip_item()?;
+            } else if self.is_keyword("constraint") {
+                constraints.push(self.parse_constraint()?);
+            } else if self.is_keyword("solve") {
+                if solve.is_some() {
+                    return Err(self.error("a model has only one solve item"));
+                }
+                solve = Some(self.parse_solve()?);
+            } else if self.is_keyword("var") {
+                variables.push(self.parse_variable()?);
+            } else if self.is_keyword("array") {
+                self.expect_keyword("array")?;
+                self.parse_index_set()?;
+                self.expect_keyword("of")?;
+
+                if self.accept(&Token::Identifier("var".to_owned())) {
+                    variable_arrays.push(self.parse_variable_array()?);
+                } else {
+                    parameters.push(self.parse_parameter()?);
+                }
+            } else {
+                parameters.push(self.parse_parameter()?);
+            }
+        }
+
+        let solve = solve.ok_or_else(|| self.error("the model has no solve item"))?;
+
+        Ok(FlatZincModel {
+            parameters,
+            variables,
+            variable_arrays,
+            constraints,
+            solve,
+        })
+    }
+
+    /// Skips the tokens up to and including the next ';'.
+    fn skip_item(&mut self) -> Result<(), FlatZincError> {
+        while self.next()? != Token::Semicolon {}
+        Ok(())
+    }
+
+    /// Parses the index set of an array declaration, which is either `1..n` or `int`.
+    fn parse_index_set(&mut self) -> Result<(), FlatZincError> {
+        self.expect(Token::OpenBracket)?;
+        if !self.accept(&Token::Identifier("int".to_owned())) {
+            let _ = self.int()?;
+            self.expect(Token::DotDot)?;
+            let _ = self.int()?;
+        }
+        self.expect(Token::CloseBracket)
+    }
+
+    fn parse_type(&mut self) -> Result<Type, FlatZincError> {
+        match self.next()? {
+            Token::Identifier(identifier) => match identifier.as_str() {
+                "bool" => Ok(Type::Bool),
+                "int" => Ok(Type::Int),
+                "float" => Ok(Type::Float),
+                "set" => {
+                    self.expect_keyword("of")?;
+                    let _ = self.parse_type()?;
+                    Ok(Type::Set)
+                }
+                _ => Err(self.error(format!("unknown type '{identifier}'"))),
+            },
+            Token::Int(lower_bound) => {
+                self.expect(Token::DotDot)?;
+                let upper_bound = self.int()?;
+                Ok(Type::IntIn(IntSet::Range(lower_bound, upper_bound)))
+            }
+            Token::Float(_) => {
+                self.expect(Token::DotDot)?;
+                let _ = self.next()?;
+                Ok(Type::Float)
+            }
+            Token::OpenBrace => Ok(Type::IntIn(IntSet::Values(self.parse_set_values()?))),
+            token => Err(self.error(format!("expected a type, found {token:?}"))),
+        }
+    }
+
+    /// Parses the values of a set literal `{v1, ..., vn}`, after the opening brace.
+    fn parse_set_values(&mut self) -> Result<Vec<i32>, FlatZincError> {
+        let mut values = Vec::new();
+
+        if self.accept(&Token::CloseBrace) {
+            return Ok(values);
+        }
+
+        loop {
+            values.push(self.int()?);
+            if self.accept(&Token::CloseBrace) {
+                return Ok(values);
+            }
+            self.expect(Token::Comma)?;
+        }
+    }
+
+    fn parse_annotations(&mut self) -> Result<Vec<Expr>, FlatZincError> {
+        let mut annotations = Vec::new();
+
+        while self.accept(&Token::DoubleColon) {
+            annotations.push(self.parse_expr()?);
+        }
+
+        Ok(annotations)
+    }
+
+    fn parse_parameter(&mut self) -> Result<Parameter, FlatZincError> {
+        let _ = self.parse_type()?;
+        self.expect(Token::Colon)?;
+        let name = self.identifier()?;
+        let _ = self.parse_annotations()?;
+        self.expect(Token::Equals)?;
+        let value = self.parse_expr()?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(Parameter { name, value })
+    }
+
+    fn parse_variable(&mut self) -> Result<Variable, FlatZincError> {
+        self.expect_keyword("var")?;
+        let domain = self.parse_type()?;
+        self.expect(Token::Colon)?;
+        let name = self.identifier()?;
+        let annotations = self.parse_annotations()?;
+
+        let value = if self.accept(&Token::Equals) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect(Token::Semicolon)?;
+
+        Ok(Variable {
+            name,
+            domain,
+            annotations,
+            value,
+        })
+    }
+
+    /// Parses an array of variables, after `array [...] of var`.
+    fn parse_variable_array(&mut self) -> Result<VariableArray, FlatZincError> {
+        let element_type = self.parse_type()?;
+        self.expect(Token::Colon)?;
+        let name = self.identifier()?;
+        let annotations = self.parse_annotations()?;
+        self.expect(Token::Equals)?;
+
+        let elements = match self.parse_expr()? {
+            Expr::Array(elements) => elements,
+            _ => return Err(self.error(format!("expected the elements of array '{name}'"))),
+        };
+        self.expect(Token::Semicolon)?;
+
+        Ok(VariableArray {
+            name,
+            element_type,
+            annotations,
+            elements,
+        })
+    }
+
+    fn parse_constraint(&mut self) -> Result<ConstraintItem, FlatZincError> {
+        self.expect_keyword("constraint")?;
+        let name = self.identifier()?;
+
+        self.expect(Token::OpenParenthesis)?;
+        let arguments = self.parse_exprs(Token::CloseParenthesis)?;
+
+        let annotations = self.parse_annotations()?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(ConstraintItem {
+            name,
+            arguments,
+            annotations,
+        })
+    }
+
+    fn parse_solve(&mut self) -> Result<SolveItem, FlatZincError> {
+        self.expect_keyword("solve")?;
+        let annotations = self.parse_annotations()?;
+
+        let goal = match self.identifier()?.as_str() {
+            "satisfy" => Goal::Satisfy,
+            "minimize" => Goal::Minimize(self.parse_expr()?),
+            "maximize" => Goal::Maximize(self.parse_expr()?),
+            goal => return Err(self.error(format!("unknown goal '{goal}'"))),
+        };
+        self.expect(Token::Semicolon)?;
+
+        Ok(SolveItem { annotations, goal })
+    }
+
+    /// Parses comma-separated expressions up to and including the `closing` token.
+    fn parse_exprs(&mut self, closing: Token) -> Result<Vec<Expr>, FlatZincError> {
+        let mut exprs = Vec::new();
+
+        if self.accept(&closing) {
+            return Ok(exprs);
+        }
+
+        loop {
+            exprs.push(self.parse_expr()?);
+            if self.accept(&closing) {
+                return Ok(exprs);
+            }
+            self.expect(Token::Comma)?;
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, FlatZincError> {
+        match self.next()? {
+            Token::Int(lower_bound) if self.peek() == Some(&Token::DotDot) => {
+                self.expect(Token::DotDot)?;
+                let upper_bound = self.int()?;
+                Ok(Expr::Set(IntSet::Range(lower_bound, upper_bound)))
+            }
+            Token::Int(value) => Ok(Expr::Int(value)),
+            Token::Float(value) => {
+                if self.accept(&Token::DotDot) {
+                    let _ = self.next()?;
+                }
+                Ok(Expr::Float(value))
+            }
+            Token::String(string) => Ok(Expr::String(string)),
+            Token::OpenBrace => Ok(Expr::Set(IntSet::Values(self.parse_set_values()?))),
+            Token::OpenBracket => Ok(Expr::Array(self.parse_exprs(Token::CloseBracket)?)),
+            Token::Identifier(identifier) => match (identifier.as_str(), self.peek()) {
+                ("true", _) => Ok(Expr::Bool(true)),
+                ("false", _) => Ok(Expr::Bool(false)),
+                (_, Some(Token::OpenParenthesis)) => {
+                    self.expect(Token::OpenParenthesis)?;
+                    let arguments = self.parse_exprs(Token::CloseParenthesis)?;
+                    Ok(Expr::Call(identifier, arguments))
+                }
+                (_, Some(Token::OpenBracket))
+                    if self.peek_second() != Some(&Token::CloseBracket) =>
+                {
+                    self.expect(Token::OpenBracket)?;
+                    let index = self.int()?;
+                    self.expect(Token::CloseBracket)?;
+                    Ok(Expr::ArrayAccess(identifier, index))
+                }
+                _ => Ok(Expr::Identifier(identifier)),
+            },
+            token => {
+                self.position -= 1;
+                Err(self.error(format!("expected an expression, found {token:?}")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declarations_are_parsed() {
+        let model = parse(
+            "predicate munchkin_all_different(array [int] of var int: x);
+             int: n = 3;
+             array [1..2] of int: ws = [2, -3];
+             var 1..3: x :: output_var;
+             var {1, 4}: y;
+             var bool: b :: var_is_introduced = true;
+             array [1..2] of var int: xs :: output_array([1..2]) = [x, y];
+             solve satisfy;",
+        )
+        .expect("valid model");
+
+        assert_eq!(
+            model.parameters,
+            vec![
+                Parameter {
+                    name: "n".to_owned(),
+                    value: Expr::Int(3),
+                },
+                Parameter {
+                    name: "ws".to_owned(),
+                    value: Expr::Array(vec![Expr::Int(2), Expr::Int(-3)]),
+                },
+            ]
+        );
+
+        assert_eq!(model.variables.len(), 3);
+        assert_eq!(model.variables[0].domain, Type::IntIn(IntSet::Range(1, 3)));
+        assert_eq!(
+            model.variables[0].annotations,
+            vec![Expr::Identifier("output_var".to_owned())]
+        );
+        assert_eq!(
+            model.variables[1].domain,
+            Type::IntIn(IntSet::Values(vec![1, 4]))
+        );
+        assert_eq!(model.variables[2].value, Some(Expr::Bool(true)));
+
+        assert_eq!(
+            model.variable_arrays,
+            vec![VariableArray {
+                name: "xs".to_owned(),
+                element_type: Type::Int,
+                annotations: vec![Expr::Call(
+                    "output_array".to_owned(),
+                    vec![Expr::Array(vec![Expr::Set(IntSet::Range(1, 2))])]
+                )],
+                elements: vec![
+                    Expr::Identifier("x".to_owned()),
+                    Expr::Identifier("y".to_owned())
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn constraints_and_solve_item_are_parsed() {
+        let model = parse(
+            "var 0..10: x;
+             array [1..2] of int: ws = [1, 1];
+             constraint int_lin_le(ws, [x, x], 8) :: domain;
+             constraint set_in(x, {1, 3});
+             solve :: int_search([x], input_order, indomain_min, complete) minimize x;",
+        )
+        .expect("valid model");
+
+        assert_eq!(
+            model.constraints,
+            vec![
+                ConstraintItem {
+                    name: "int_lin_le".to_owned(),
+                    arguments: vec![
+                        Expr::Identifier("ws".to_owned()),
+                        Expr::Array(vec![
+                            Expr::Identifier("x".to_owned()),
+                            Expr::Identifier("x".to_owned())
+                        ]),
+                        Expr::Int(8),
+                    ],
+                    annotations: vec![Expr::Identifier("domain".to_owned())],
+                },
+                ConstraintItem {
+                    name: "set_in".to_owned(),
+                    arguments: vec![
+                        Expr::Identifier("x".to_owned()),
+                        Expr::Set(IntSet::Values(vec![1, 3])),
+                    ],
+                    annotations: vec![],
+                },
+            ]
+        );
+
+        assert_eq!(
+            model.solve.goal,
+            Goal::Minimize(Expr::Identifier("x".to_owned()))
+        );
+        assert_eq!(model.solve.annotations.len(), 1);
+    }
+
+    #[test]
+    fn comments_and_array_accesses_are_handled() {
+        let model = parse(
+            "% A comment
+             array [1..2] of var 0..1: xs = [X_1, X_2]; % another comment
+             constraint int_le(xs[1], xs[2]);
+             solve maximize xs[2];",
+        )
+        .expect("valid model");
+
+        assert_eq!(
+            model.constraints[0].arguments,
+            vec![
+                Expr::ArrayAccess("xs".to_owned(), 1),
+                Expr::ArrayAccess("xs".to_owned(), 2)
+            ]
+        );
+        assert_eq!(
+            model.solve.goal,
+            Goal::Maximize(Expr::ArrayAccess("xs".to_owned(), 2))
+        );
+    }
+
+    #[test]
+    fn syntax_errors_report_the_line() {
+        let error = parse("var 1..3: x;\nconstraint int_le(x 3);\nsolve satisfy;")
+            .expect_err("missing comma");
+
+        assert!(matches!(error, FlatZincError::Syntax { line: 2, .. }));
+    }
+}
@@ -0,0 +1,219 @@
+//! A FlatZinc front-end for Munchkin, which allows MiniZinc models to be solved.
+//!
+//! MiniZinc compiles a model with the redefinitions in `mznlib` to FlatZinc, which is then read,
+//! compiled into a [`Solver`] and solved by this binary. The solutions are printed in the output
+//! format of FlatZinc.
+
+mod flatzinc;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Parser;
+use flatzinc::CompiledModel;
+use flatzinc::FlatZincError;
+use flatzinc::Objective;
+use flatzinc::Output;
+use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use munchkin::branching::InDomainMin;
+use munchkin::branching::InputOrder;
+use munchkin::model::Globals;
+use munchkin::predicate;
+use munchkin::results::OptimisationResult;
+use munchkin::results::ProblemSolution;
+use munchkin::results::SatisfactionResult;
+use munchkin::statistics::configure;
+use munchkin::termination::TerminationCondition;
+use munchkin::termination::TimeBudget;
+use munchkin::variables::DomainId;
+use munchkin::Solver;
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// The FlatZinc model to solve.
+    instance: PathBuf,
+
+    /// Print all solutions of a satisfaction problem, or all intermediate solutions of an
+    /// optimisation problem.
+    #[arg(short = 'a', long = "all-solutions")]
+    all_solutions: bool,
+
+    /// Ignore the search annotations of the model.
+    #[arg(short = 'f', long = "free-search")]
+    free_search: bool,
+
+    /// Print the statistics of the solver.
+    #[arg(short = 's', long = "statistics")]
+    statistics: bool,
+
+    /// The number of milliseconds the solver is allowed to run.
+    #[arg(short = 't', long = "time-limit")]
+    time_limit: Option<u64>,
+
+    /// The constraints that should _not_ be decomposed.
+    ///
+    /// Multiple constraints can be provided by passing this option multiple times.
+    #[arg(short = 'G', long = "global")]
+    globals: Vec<Globals>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    configure(args.statistics, "%%%mzn-stat:", Some("%%%mzn-stat-end"));
+
+    let source = std::fs::read_to_string(&args.instance)
+        .with_context(|| format!("Error reading {}", args.instance.display()))?;
+
+    let model = flatzinc::parse(&source)
+        .with_context(|| format!("Failed to parse FlatZinc from {}", args.instance.display()))?;
+
+    let model = match flatzinc::compile(
+        model,
+        |global| args.globals.contains(&global),
+        args.free_search,
+    ) {
+        Ok(model) => model,
+        Err(FlatZincError::Infeasible(_) | FlatZincError::EmptyDomain(_)) => {
+            println!("=====UNSATISFIABLE=====");
+            return Ok(());
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut time_budget = args
+        .time_limit
+        .map(|time_limit| TimeBudget::starting_now(Duration::from_millis(time_limit)));
+
+    match model.objective {
+        Objective::Satisfy => satisfy(model, args.all_solutions, &mut time_budget),
+        Objective::Minimise(_) | Objective::Maximise(_) => {
+            optimise(model, args.all_solutions, &mut time_budget)
+        }
+    }
+
+    Ok(())
+}
+
+fn brancher(
+    search_variables: Vec<DomainId>,
+) -> IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin> {
+    IndependentVariableValueBrancher::new(InputOrder::new(search_variables), InDomainMin)
+}
+
+/// Solves a satisfaction problem. When all solutions are requested, every solution is excluded
+/// by a clause over the output variables before the next one is searched for.
+fn satisfy(model: CompiledModel, all_solutions: bool, termination: &mut impl TerminationCondition) {
+    let CompiledModel {
+        mut solver,
+        search_variables,
+        outputs,
+        ..
+    } = model;
+
+    let mut brancher = brancher(search_variables);
+    let mut has_solution = false;
+
+    loop {
+        match solver.satisfy(&mut brancher, termination) {
+            SatisfactionResult::Satisfiable(solution) => {
+                has_solution = true;
+                flatzinc::print_solution(&outputs, &solution);
+
+                if !all_solutions {
+                    break;
+                }
+
+                let blocking_clause = blocking_clause(&solver, &outputs, &solution);
+                if blocking_clause.is_empty() || solver.add_clause(blocking_clause).is_err() {
+                    println!("==========");
+                    break;
+                }
+            }
+            SatisfactionResult::Unsatisfiable => {
+                if has_solution {
+                    println!("==========");
+                } else {
+                    println!("=====UNSATISFIABLE=====");
+                }
+                break;
+            }
+            SatisfactionResult::Unknown => {
+                if !has_solution {
+                    println!("=====UNKNOWN=====");
+                }
+                break;
+            }
+        }
+    }
+
+    solver.log_statistics();
+}
+
+/// The clause which excludes the values of the output variables in the solution.
+fn blocking_clause(
+    solver: &Solver,
+    outputs: &[Output],
+    solution: &impl ProblemSolution,
+) -> Vec<munchkin::variables::Literal> {
+    outputs
+        .iter()
+        .flat_map(|output| match output {
+            Output::Variable { variable, .. } => std::slice::from_ref(variable),
+            Output::Array { variables, .. } => variables.as_slice(),
+        })
+        .map(|&variable| {
+            let value = solution.get_integer_value(variable);
+            solver.get_literal(predicate![variable != value])
+        })
+        .collect()
+}
+
+/// Solves an optimisation problem. When all solutions are requested, every improving solution is
+/// printed; otherwise only the best solution is.
+fn optimise(
+    model: CompiledModel,
+    all_solutions: bool,
+    termination: &mut impl TerminationCondition,
+) {
+    let CompiledModel {
+        mut solver,
+        objective,
+        search_variables,
+        outputs,
+    } = model;
+
+    let mut brancher = brancher(search_variables);
+
+    if all_solutions {
+        let callback_outputs = outputs.clone();
+        solver.with_solution_callback(move |solution| {
+            flatzinc::print_solution(&callback_outputs, solution);
+        });
+    }
+
+    let result = match objective {
+        Objective::Minimise(objective) => solver.minimise(&mut brancher, termination, objective),
+        Objective::Maximise(objective) => solver.maximise(&mut brancher, termination, objective),
+        Objective::Satisfy => unreachable!("satisfaction problems are not optimised"),
+    };
+
+    match result {
+        OptimisationResult::Optimal(solution) => {
+            if !all_solutions {
+                flatzinc::print_solution(&outputs, &solution);
+            }
+            println!("==========");
+        }
+        OptimisationResult::Satisfiable(solution) => {
+            if !all_solutions {
+                flatzinc::print_solution(&outputs, &solution);
+            }
+        }
+        OptimisationResult::Unsatisfiable => println!("=====UNSATISFIABLE====="),
+        OptimisationResult::Unknown => println!("=====UNKNOWN====="),
+    }
+
+    solver.log_statistics();
+}
@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::ops::DerefMut;
 
 #[cfg(doc)]
@@ -10,11 +11,14 @@ use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 use crate::branching::SelectionContext;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
 use crate::results::solution_iterator::SolutionIterator;
+#[cfg(doc)]
+use crate::Solver;
 
 /// A trait for definining a branching strategy (oftentimes utilising a [`VariableSelector`] and a
 /// [`ValueSelector`]).
@@ -62,6 +66,27 @@ pub trait Brancher {
     /// analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 
+    /// Returns whether the [`Brancher`] makes use of the variables which appear in conflicts (see
+    /// [`Brancher::on_appearance_in_conflict_literal`] and
+    /// [`Brancher::on_appearance_in_conflict_integer`]). If the solver does not learn from
+    /// conflicts, then it only explains a conflict to inform the [`Brancher`] when this returns
+    /// `true`.
+    ///
+    /// By default, this returns `false`.
+    fn uses_conflict_appearances(&self) -> bool {
+        false
+    }
+
+    /// A function which is called when a conflict is found which was caused by a propagator,
+    /// before the conflict is analysed; `propagator` is the [`PropagatorId`] of that propagator.
+    fn on_propagator_conflict(&mut self, _propagator: PropagatorId) {}
+
+    /// A function which is called after the solver has propagated the last decision made by the
+    /// [`Brancher`]; `is_conflicting` indicates whether propagation resulted in a conflict. The
+    /// [`SelectionContext`] reflects the domains after propagation (e.g. see
+    /// [`SelectionContext::get_domains_changed_at_current_decision_level`]).
+    fn on_decision_propagated(&mut self, _context: &SelectionContext, _is_conflicting: bool) {}
+
     /// This method is called when a solution is found; this will either be called when a new
     /// incumbent solution is found (i.e. a solution with a better objective value than previously
     /// known) or when a new solution is found when iterating over solutions using
@@ -70,6 +95,15 @@ pub trait Brancher {
 
     /// This method is called whenever a restart is performed.
     fn on_restart(&mut self) {}
+
+    /// Returns whether the [`Brancher`] makes use of the hints which are added to the solver (see
+    /// [`Solver::add_hint`]); the hints are ignored by [`Brancher`]s for which this returns
+    /// `false`.
+    ///
+    /// By default, this returns `false`.
+    fn uses_hints(&self) -> bool {
+        false
+    }
 }
 
 impl Brancher for Box<dyn Brancher> {
@@ -97,6 +131,19 @@ impl Brancher for Box<dyn Brancher> {
         self.deref_mut().on_appearance_in_conflict_integer(variable);
     }
 
+    fn uses_conflict_appearances(&self) -> bool {
+        self.deref().uses_conflict_appearances()
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.deref_mut().on_propagator_conflict(propagator);
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.deref_mut()
+            .on_decision_propagated(context, is_conflicting);
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.deref_mut().on_solution(solution);
     }
@@ -104,4 +151,8 @@ impl Brancher for Box<dyn Brancher> {
     fn on_restart(&mut self) {
         self.deref_mut().on_restart();
     }
+
+    fn uses_hints(&self) -> bool {
+        self.deref().uses_hints()
+    }
 }
@@ -0,0 +1,225 @@
+//! A [`Brancher`] which alternates between a user-provided [`Brancher`] and the
+//! [`DefaultBrancher`].
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+#[cfg(doc)]
+use crate::branching::Vsids;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::DefaultBrancher;
+use crate::Solver;
+
+/// Determines when the [`AlternatingBrancher`] switches between the provided [`Brancher`] and the
+/// [`DefaultBrancher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlternatingStrategy {
+    /// Switch to the other [`Brancher`] every time a solution is found.
+    EverySolution,
+    /// Switch to the other [`Brancher`] every time the provided number of solutions has been
+    /// found.
+    EveryXSolutions(u64),
+    /// Use the provided [`Brancher`] until the first solution is found, after which only the
+    /// [`DefaultBrancher`] is used.
+    SwitchToDefaultAfterFirstSolution,
+    /// Switch to the other [`Brancher`] every time a restart is performed. Note that the solver
+    /// currently does not perform restarts, in which case only the provided [`Brancher`] is used.
+    EveryRestart,
+}
+
+/// An implementation of a [`Brancher`] which alternates between a provided [`Brancher`] and the
+/// [`DefaultBrancher`] (see [`Solver::default_brancher_over_all_propositional_variables`])
+/// according to an [`AlternatingStrategy`]. The search starts with the provided [`Brancher`].
+///
+/// Every event (e.g. [`Brancher::on_conflict`] or [`Brancher::on_solution`]) is forwarded to both
+/// of the [`Brancher`]s, such that the [`Brancher`] which is not in use keeps its state (e.g. the
+/// activities of [`Vsids`]) up-to-date.
+///
+/// Note that the [`DefaultBrancher`] is created over the variables which exist when
+/// [`AlternatingBrancher::new`] is called.
+#[derive(Debug)]
+pub struct AlternatingBrancher<OtherBrancher> {
+    other_brancher: OtherBrancher,
+    default_brancher: DefaultBrancher,
+    is_using_default_brancher: bool,
+    strategy: AlternatingStrategy,
+    /// The number of solutions which have been found since the last switch.
+    num_solutions_since_switch: u64,
+}
+
+impl<OtherBrancher: Brancher> AlternatingBrancher<OtherBrancher> {
+    pub fn new(
+        solver: &Solver,
+        other_brancher: OtherBrancher,
+        strategy: AlternatingStrategy,
+    ) -> Self {
+        AlternatingBrancher {
+            other_brancher,
+            default_brancher: solver.default_brancher_over_all_propositional_variables(),
+            is_using_default_brancher: false,
+            strategy,
+            num_solutions_since_switch: 0,
+        }
+    }
+
+    /// Returns whether the [`DefaultBrancher`] is currently used to make decisions.
+    pub fn is_using_default_brancher(&self) -> bool {
+        self.is_using_default_brancher
+    }
+
+    fn toggle_brancher(&mut self) {
+        self.is_using_default_brancher = !self.is_using_default_brancher;
+        self.num_solutions_since_switch = 0;
+    }
+}
+
+impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        if self.is_using_default_brancher {
+            self.default_brancher.next_decision(context)
+        } else {
+            self.other_brancher.next_decision(context)
+        }
+    }
+
+    fn on_conflict(&mut self) {
+        self.other_brancher.on_conflict();
+        self.default_brancher.on_conflict();
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.other_brancher.on_unassign_literal(literal);
+        self.default_brancher.on_unassign_literal(literal);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.other_brancher.on_unassign_integer(variable, value);
+        self.default_brancher.on_unassign_integer(variable, value);
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.other_brancher
+            .on_appearance_in_conflict_literal(literal);
+        self.default_brancher
+            .on_appearance_in_conflict_literal(literal);
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.other_brancher
+            .on_appearance_in_conflict_integer(variable);
+        self.default_brancher
+            .on_appearance_in_conflict_integer(variable);
+    }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        self.other_brancher.uses_conflict_appearances()
+            || self.default_brancher.uses_conflict_appearances()
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.other_brancher.on_propagator_conflict(propagator);
+        self.default_brancher.on_propagator_conflict(propagator);
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.other_brancher
+            .on_decision_propagated(context, is_conflicting);
+        self.default_brancher
+            .on_decision_propagated(context, is_conflicting);
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.other_brancher.on_solution(solution);
+        self.default_brancher.on_solution(solution);
+
+        self.num_solutions_since_switch += 1;
+        match self.strategy {
+            AlternatingStrategy::EverySolution => self.toggle_brancher(),
+            AlternatingStrategy::EveryXSolutions(num_solutions) => {
+                if self.num_solutions_since_switch >= num_solutions {
+                    self.toggle_brancher();
+                }
+            }
+            AlternatingStrategy::SwitchToDefaultAfterFirstSolution => {
+                self.is_using_default_brancher = true;
+            }
+            AlternatingStrategy::EveryRestart => {}
+        }
+    }
+
+    fn on_restart(&mut self) {
+        self.other_brancher.on_restart();
+        self.default_brancher.on_restart();
+
+        if self.strategy == AlternatingStrategy::EveryRestart {
+            self.toggle_brancher();
+        }
+    }
+
+    fn uses_hints(&self) -> bool {
+        self.other_brancher.uses_hints() || self.default_brancher.uses_hints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlternatingBrancher;
+    use super::AlternatingStrategy;
+    use crate::basic_types::Solution;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::Brancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::Solver;
+
+    fn create_brancher(strategy: AlternatingStrategy) -> AlternatingBrancher<impl Brancher> {
+        let mut solver = Solver::default();
+        let variable = solver.new_bounded_integer(0, 5);
+
+        AlternatingBrancher::new(
+            &solver,
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![variable]), InDomainMin),
+            strategy,
+        )
+    }
+
+    #[test]
+    fn every_x_solutions_switches_after_x_solutions() {
+        let mut brancher = create_brancher(AlternatingStrategy::EveryXSolutions(2));
+        let solution = Solution::default();
+
+        brancher.on_solution(solution.as_reference());
+        assert!(!brancher.is_using_default_brancher());
+
+        brancher.on_solution(solution.as_reference());
+        assert!(brancher.is_using_default_brancher());
+
+        brancher.on_solution(solution.as_reference());
+        brancher.on_solution(solution.as_reference());
+        assert!(!brancher.is_using_default_brancher());
+    }
+
+    #[test]
+    fn switch_to_default_after_first_solution_does_not_switch_back() {
+        let mut brancher = create_brancher(AlternatingStrategy::SwitchToDefaultAfterFirstSolution);
+        let solution = Solution::default();
+
+        brancher.on_solution(solution.as_reference());
+        brancher.on_solution(solution.as_reference());
+        assert!(brancher.is_using_default_brancher());
+    }
+
+    #[test]
+    fn every_restart_switches_on_restart() {
+        let mut brancher = create_brancher(AlternatingStrategy::EveryRestart);
+
+        brancher.on_solution(Solution::default().as_reference());
+        assert!(!brancher.is_using_default_brancher());
+
+        brancher.on_restart();
+        assert!(brancher.is_using_default_brancher());
+    }
+}
@@ -8,6 +8,7 @@ use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 use crate::branching::ValueSelector;
 use crate::branching::VariableSelector;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
@@ -89,6 +90,19 @@ where
             .on_appearance_in_conflict_integer(variable)
     }
 
+    fn uses_conflict_appearances(&self) -> bool {
+        self.variable_selector.uses_conflict_appearances()
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.variable_selector.on_propagator_conflict(propagator)
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.variable_selector
+            .on_decision_propagated(context, is_conflicting)
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.value_selector.on_solution(solution);
     }
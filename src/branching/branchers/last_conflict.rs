@@ -0,0 +1,292 @@
+//! A [`Brancher`] which wraps another [`Brancher`] and prioritises the variables which were
+//! involved in recent conflicts.
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::munchkin_assert_simple;
+use crate::predicate;
+
+/// An implementation of a [`Brancher`] which implements last-conflict reasoning \[1\] on top of
+/// another [`Brancher`]; it keeps branching on the variable of the decision which led to the most
+/// recent conflict until that variable is fixed, after which the wrapped [`Brancher`] is used
+/// again.
+///
+/// The generalisation to `k` variables (see \[1\]) is achieved by remembering the (at most) `k`
+/// most recent conflict variables; the most recent of these which is not fixed is branched on. In
+/// this way, the variables which were involved in conflicts are ordered by the recency of their
+/// conflicts, similar to conflict ordering search \[2\].
+///
+/// As in \[1\], a decision is considered to have led to a conflict if propagating it results in a
+/// conflict (see [`Brancher::on_decision_propagated`]). Only decisions over integer variables are
+/// taken into account. When branching on a conflict variable `x`, the decision `[x <= lb(x)]` is
+/// returned.
+///
+/// # Bibliography
+/// \[1\] C. Lecoutre, L. Saïs, S. Tabary, and V. Vidal, ‘Reasoning from last conflict(s) in
+/// constraint programming’, Artificial Intelligence, vol. 173, no. 18, pp. 1592–1614, 2009.
+///
+/// \[2\] S. Gay, R. Hartert, C. Lecoutre, and P. Schaus, ‘Conflict ordering search for scheduling
+/// problems’, in CP, 2015, pp. 140–148.
+#[derive(Debug)]
+pub struct LastConflict<WrappedBrancher> {
+    brancher: WrappedBrancher,
+    /// The variables of the decisions which led to the most recent conflicts, ordered from most
+    /// to least recent.
+    conflict_variables: Vec<DomainId>,
+    /// The maximum number of conflict variables which are remembered. This value is constant.
+    num_conflict_variables: usize,
+    /// The variable of the last decision (if it was a decision over an integer variable).
+    last_decision_variable: Option<DomainId>,
+}
+
+impl<WrappedBrancher: Brancher> LastConflict<WrappedBrancher> {
+    /// Creates a [`LastConflict`] brancher which only remembers the variable of the most recent
+    /// conflict.
+    pub fn new(brancher: WrappedBrancher) -> Self {
+        Self::with_num_conflict_variables(brancher, 1)
+    }
+
+    /// Creates a [`LastConflict`] brancher which remembers the variables of the
+    /// `num_conflict_variables` most recent conflicts.
+    pub fn with_num_conflict_variables(
+        brancher: WrappedBrancher,
+        num_conflict_variables: usize,
+    ) -> Self {
+        munchkin_assert_simple!(
+            num_conflict_variables > 0,
+            "The LastConflict brancher should remember at least one conflict variable"
+        );
+
+        LastConflict {
+            brancher,
+            conflict_variables: vec![],
+            num_conflict_variables,
+            last_decision_variable: None,
+        }
+    }
+}
+
+impl<WrappedBrancher: Brancher> Brancher for LastConflict<WrappedBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        let decision = match self
+            .conflict_variables
+            .iter()
+            .find(|&variable| !context.is_integer_fixed(variable))
+        {
+            Some(&variable) => Some(predicate!(variable <= context.lower_bound(&variable))),
+            None => self.brancher.next_decision(context),
+        };
+
+        self.last_decision_variable = decision.and_then(|decision| decision.get_domain());
+        decision
+    }
+
+    fn on_conflict(&mut self) {
+        self.brancher.on_conflict();
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.brancher.on_unassign_literal(literal);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.brancher.on_unassign_integer(variable, value);
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.brancher.on_appearance_in_conflict_literal(literal);
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.brancher.on_appearance_in_conflict_integer(variable);
+    }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        self.brancher.uses_conflict_appearances()
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.brancher.on_propagator_conflict(propagator);
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.brancher
+            .on_decision_propagated(context, is_conflicting);
+
+        if !is_conflicting {
+            return;
+        }
+
+        if let Some(variable) = self.last_decision_variable {
+            self.conflict_variables
+                .retain(|&conflict_variable| conflict_variable != variable);
+            self.conflict_variables.insert(0, variable);
+            self.conflict_variables
+                .truncate(self.num_conflict_variables);
+        }
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.brancher.on_solution(solution);
+    }
+
+    fn on_restart(&mut self) {
+        self.brancher.on_restart();
+    }
+    fn uses_hints(&self) -> bool {
+        self.brancher.uses_hints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LastConflict;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::Brancher;
+    use crate::branching::InDomainMax;
+    use crate::branching::InputOrder;
+    use crate::branching::SelectionContext;
+    use crate::constraints;
+    use crate::engine::predicates::predicate::Predicate;
+    use crate::engine::termination::indefinite::Indefinite;
+    use crate::engine::variables::DomainId;
+    use crate::predicate;
+    use crate::results::SatisfactionResult;
+    use crate::variables::TransformableVariable;
+    use crate::Solver;
+
+    /// Calls `f` with a context in which the first domain is fixed if `is_first_fixed` holds.
+    fn with_context<T>(is_first_fixed: bool, f: impl FnOnce(&mut SelectionContext) -> T) -> T {
+        let first_domain = if is_first_fixed { (2, 2) } else { (0, 5) };
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![first_domain, (0, 5)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        f(&mut context)
+    }
+
+    /// Lets `brancher` make a decision while the first domain is fixed if `is_first_fixed` holds,
+    /// after which propagating the decision results in a conflict if `is_conflicting` holds.
+    fn make_decision(
+        brancher: &mut impl Brancher,
+        is_first_fixed: bool,
+        is_conflicting: bool,
+    ) -> Option<Predicate> {
+        with_context(is_first_fixed, |context| {
+            let decision = brancher.next_decision(context);
+            brancher.on_decision_propagated(context, is_conflicting);
+            decision
+        })
+    }
+
+    #[test]
+    fn conflict_variable_is_branched_on_first() {
+        let domains = [DomainId::new(0), DomainId::new(1)];
+        let mut brancher = LastConflict::new(IndependentVariableValueBrancher::new(
+            InputOrder::new(domains.to_vec()),
+            InDomainMax,
+        ));
+
+        assert_eq!(
+            make_decision(&mut brancher, true, true),
+            Some(predicate!(domains[1] >= 5))
+        );
+
+        // The wrapped brancher would select the first variable.
+        assert_eq!(
+            make_decision(&mut brancher, false, false),
+            Some(predicate!(domains[1] <= 0))
+        );
+    }
+
+    #[test]
+    fn decision_variable_without_conflict_is_not_remembered() {
+        let domains = [DomainId::new(0), DomainId::new(1)];
+        let mut brancher = LastConflict::new(IndependentVariableValueBrancher::new(
+            InputOrder::new(domains.to_vec()),
+            InDomainMax,
+        ));
+
+        let _ = make_decision(&mut brancher, true, false);
+
+        assert_eq!(
+            make_decision(&mut brancher, false, false),
+            Some(predicate!(domains[0] >= 5))
+        );
+    }
+
+    /// Records the decisions of the wrapped [`Brancher`].
+    struct RecordingBrancher<WrappedBrancher> {
+        brancher: WrappedBrancher,
+        decisions: Vec<Predicate>,
+    }
+
+    impl<WrappedBrancher: Brancher> Brancher for RecordingBrancher<WrappedBrancher> {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            let decision = self.brancher.next_decision(context);
+            self.decisions.extend(decision);
+            decision
+        }
+
+        fn on_conflict(&mut self) {
+            self.brancher.on_conflict();
+        }
+
+        fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+            self.brancher
+                .on_decision_propagated(context, is_conflicting);
+        }
+    }
+
+    #[test]
+    fn failed_decision_is_retried_by_the_solver() {
+        let mut solver = Solver::default();
+        let b = solver.new_bounded_integer(0, 5);
+        let c = solver.new_bounded_integer(0, 5);
+
+        // Deciding [b >= 5] requires c to be both at most 0 and at least 5.
+        solver
+            .add_constraint(constraints::less_than_or_equals(
+                [b.scaled(1), c.scaled(1)],
+                5,
+            ))
+            .post()
+            .expect("no conflict");
+        solver
+            .add_constraint(constraints::less_than_or_equals(
+                [b.scaled(1), c.scaled(-1)],
+                0,
+            ))
+            .post()
+            .expect("no conflict");
+
+        let mut brancher = RecordingBrancher {
+            brancher: LastConflict::new(IndependentVariableValueBrancher::new(
+                InputOrder::new(vec![b, c]),
+                InDomainMax,
+            )),
+            decisions: vec![],
+        };
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        // The wrapped brancher would decide [b >= 4] after the conflict.
+        assert_eq!(
+            brancher.decisions[..2],
+            [predicate!(b >= 5), predicate!(b <= 0)]
+        );
+    }
+}
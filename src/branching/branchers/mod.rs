@@ -1 +1,4 @@
+pub mod alternating_brancher;
 pub mod independent_variable_value_brancher;
+pub mod last_conflict;
+pub mod sequential_brancher;
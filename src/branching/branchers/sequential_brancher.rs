@@ -0,0 +1,148 @@
+//! A [`Brancher`] which combines multiple [`Brancher`]s by asking them for a decision in a fixed
+//! order.
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::munchkin_assert_simple;
+#[cfg(doc)]
+use crate::Solver;
+
+/// An implementation of a [`Brancher`] which takes a list of [`Brancher`]s and returns the decision
+/// of the first [`Brancher`] in the list which can still make a decision; only when all of the
+/// [`Brancher`]s have fixed their variables does it return [`None`].
+///
+/// This allows, for example, first branching on a subset of the variables using a problem-specific
+/// strategy, after which the remaining variables are branched on using
+/// [`Solver::default_brancher_over_all_propositional_variables`].
+///
+/// Every event (e.g. [`Brancher::on_conflict`] or [`Brancher::on_solution`]) is forwarded to all
+/// of the [`Brancher`]s, regardless of which [`Brancher`] made the last decision. Note that a list
+/// containing different types of [`Brancher`]s can be created by using `Box<dyn Brancher>`.
+#[derive(Debug)]
+pub struct SequentialBrancher<ChildBrancher> {
+    branchers: Vec<ChildBrancher>,
+}
+
+impl<ChildBrancher: Brancher> SequentialBrancher<ChildBrancher> {
+    pub fn new(branchers: Vec<ChildBrancher>) -> Self {
+        munchkin_assert_simple!(
+            !branchers.is_empty(),
+            "The SequentialBrancher should be provided with at least one brancher"
+        );
+
+        SequentialBrancher { branchers }
+    }
+}
+
+impl<ChildBrancher: Brancher> Brancher for SequentialBrancher<ChildBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.branchers
+            .iter_mut()
+            .find_map(|brancher| brancher.next_decision(context))
+    }
+
+    fn on_conflict(&mut self) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_conflict());
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_unassign_literal(literal));
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_unassign_integer(variable, value));
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_appearance_in_conflict_literal(literal));
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_appearance_in_conflict_integer(variable));
+    }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        self.branchers
+            .iter()
+            .any(|brancher| brancher.uses_conflict_appearances())
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_propagator_conflict(propagator));
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_decision_propagated(context, is_conflicting));
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_solution(solution));
+    }
+
+    fn on_restart(&mut self) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_restart());
+    }
+
+    fn uses_hints(&self) -> bool {
+        self.branchers.iter().any(|brancher| brancher.uses_hints())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SequentialBrancher;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::Brancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::branching::SelectionContext;
+    use crate::predicate;
+
+    #[test]
+    fn next_brancher_is_used_when_first_brancher_is_done() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(3, 3), (0, 5)]));
+        let domains = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        let mut brancher = SequentialBrancher::new(vec![
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![domains[0]]), InDomainMin),
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![domains[1]]), InDomainMin),
+        ]);
+
+        assert_eq!(
+            brancher.next_decision(&mut context),
+            Some(predicate!(domains[1] <= 0))
+        );
+    }
+}
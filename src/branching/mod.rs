@@ -11,6 +11,13 @@
 //!   hooks into the solver); the main method of this trait is the [`ValueSelector::select_value`]
 //!   method.
 //!
+//! Multiple [`Brancher`]s can be combined using the [`SequentialBrancher`], which branches using
+//! its [`Brancher`]s in order, and the [`AlternatingBrancher`], which switches between a
+//! [`Brancher`] and the default [`Brancher`] during search.
+//!
+//! Variable selectors which select a variable based on a value associated with each variable (such
+//! as [`FirstFail`]) use a [`TieBreaker`] to decide between variables with the same value.
+//!
 //! A [`Brancher`] is expected to be passed to [`Solver::satisfy`], [`Solver::maximise`], and
 //! [`Solver::minimise`]:
 //! ```rust
@@ -77,19 +84,31 @@
 mod brancher;
 pub mod branchers;
 mod selection_context;
+pub mod tie_breaking;
 pub mod value_selection;
 pub mod variable_selection;
 
 pub use brancher::Brancher;
 pub use selection_context::SelectionContext;
+pub use tie_breaking::*;
 pub use value_selection::*;
 pub use variable_selection::*;
 
+pub use crate::engine::cp::propagation::propagator_id::PropagatorId;
+
+#[cfg(doc)]
+use crate::branching::branchers::alternating_brancher::AlternatingBrancher;
 #[cfg(doc)]
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
 #[cfg(doc)]
+use crate::branching::branchers::sequential_brancher::SequentialBrancher;
+#[cfg(doc)]
+use crate::branching::tie_breaking::TieBreaker;
+#[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
+use crate::branching::variable_selection::FirstFail;
+#[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 #[cfg(doc)]
 use crate::Solver;
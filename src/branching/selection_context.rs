@@ -5,10 +5,11 @@ use crate::basic_types::Random;
 use crate::branching::Brancher;
 #[cfg(doc)]
 use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::AssignmentsInteger;
+use crate::engine::cp::WatchListCP;
 use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::DomainGeneratorIterator;
-#[cfg(doc)]
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::PropositionalVariable;
@@ -21,18 +22,21 @@ use crate::engine::variables::PropositionalVariableGeneratorIterator;
 pub struct SelectionContext<'a> {
     assignments_integer: &'a AssignmentsInteger,
     assignments_propositional: &'a AssignmentsPropositional,
+    watch_list_cp: &'a WatchListCP,
     random_generator: &'a mut dyn Random,
 }
 
 impl<'a> SelectionContext<'a> {
-    pub fn new(
+    pub(crate) fn new(
         assignments_integer: &'a AssignmentsInteger,
         assignments_propositional: &'a AssignmentsPropositional,
+        watch_list_cp: &'a WatchListCP,
         rng: &'a mut dyn Random,
     ) -> Self {
         SelectionContext {
             assignments_integer,
             assignments_propositional,
+            watch_list_cp,
             random_generator: rng,
         }
     }
@@ -82,6 +86,31 @@ impl<'a> SelectionContext<'a> {
             .is_variable_assigned_true(var)
     }
 
+    /// Returns the propagators which are registered on the domain of the provided
+    /// [`IntegerVariable`]. Note that constraints which are decomposed into clauses do not have a
+    /// propagator.
+    pub fn get_propagators<Var: IntegerVariable>(&self, var: &Var) -> &[PropagatorId] {
+        self.watch_list_cp.get_watching_propagators(var.domain_id())
+    }
+
+    /// Returns the number of propagators which are registered on the domain of the provided
+    /// [`IntegerVariable`] (see [`SelectionContext::get_propagators`]).
+    pub fn get_degree<Var: IntegerVariable>(&self, var: &Var) -> usize {
+        self.get_propagators(var).len()
+    }
+
+    /// Returns the [`DomainId`]s of which the domain changed at the current decision level, in the
+    /// order in which the changes happened. A [`DomainId`] is returned once for every change to its
+    /// domain.
+    pub fn get_domains_changed_at_current_decision_level(
+        &self,
+    ) -> impl Iterator<Item = DomainId> + '_ {
+        self.assignments_integer
+            .get_entries_at_current_decision_level()
+            .iter()
+            .map(|entry| entry.predicate.get_domain())
+    }
+
     /// Returns all currently defined [`DomainId`]s.
     pub fn get_domains(&self) -> DomainGeneratorIterator {
         self.assignments_integer.get_domains()
@@ -95,14 +124,14 @@ impl<'a> SelectionContext<'a> {
     #[cfg(test)]
     /// A method for creating and returning `num_integer_variables` [`DomainId`]s and
     /// `num_prop_variables` [`PropositionalVariable`]s in addition to initialising (and
-    /// returning) the corresponding [`AssignmentsInteger`] and [`AssignmentsPropositional`].
-    pub fn create_for_testing(
+    /// returning) the corresponding [`AssignmentsInteger`], [`AssignmentsPropositional`] and
+    /// [`WatchListCP`].
+    pub(crate) fn create_for_testing(
         num_integer_variables: usize,
         num_propositional_variables: usize,
         domains: Option<Vec<(i32, i32)>>,
-    ) -> (AssignmentsInteger, AssignmentsPropositional) {
+    ) -> (AssignmentsInteger, AssignmentsPropositional, WatchListCP) {
         use crate::engine::cp::VariableLiteralMappings;
-        use crate::engine::cp::WatchListCP;
         use crate::engine::cp::WatchListPropositional;
         use crate::engine::sat::ClausalPropagator;
         use crate::engine::sat::ClauseAllocator;
@@ -176,6 +205,10 @@ impl<'a> SelectionContext<'a> {
             );
         }
 
-        (assignments_integer, assignments_propositional)
+        (
+            assignments_integer,
+            assignments_propositional,
+            watch_list_cp,
+        )
     }
 }
@@ -0,0 +1,72 @@
+use super::Direction;
+use super::TieBreaker;
+use crate::branching::SelectionContext;
+
+/// A [`TieBreaker`] which selects the first variable, in the order in which they were considered,
+/// among the variables with the best value.
+#[derive(Debug)]
+pub struct InOrderTieBreaker<Var, Value> {
+    selected: Option<(Var, Value)>,
+    direction: Direction,
+}
+
+impl<Var, Value> InOrderTieBreaker<Var, Value> {
+    pub fn new(direction: Direction) -> Self {
+        InOrderTieBreaker {
+            selected: None,
+            direction,
+        }
+    }
+}
+
+impl<Var, Value: PartialOrd> TieBreaker<Var, Value> for InOrderTieBreaker<Var, Value> {
+    fn consider(&mut self, variable: Var, value: Value) {
+        let is_better = self
+            .selected
+            .as_ref()
+            .is_none_or(|(_, best)| self.direction.is_better(&value, best));
+
+        if is_better {
+            self.selected = Some((variable, value));
+        }
+    }
+
+    fn select(&mut self, _context: &mut SelectionContext) -> Option<Var> {
+        self.selected.take().map(|(variable, _)| variable)
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::Direction;
+    use crate::branching::InOrderTieBreaker;
+    use crate::branching::SelectionContext;
+    use crate::branching::TieBreaker;
+
+    #[test]
+    fn first_variable_with_best_value_is_selected() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(0, 0, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        let mut tie_breaker = InOrderTieBreaker::new(Direction::Minimum);
+        tie_breaker.consider(0, 5);
+        tie_breaker.consider(1, 3);
+        tie_breaker.consider(2, 3);
+        tie_breaker.consider(3, 4);
+
+        assert_eq!(Some(1), tie_breaker.select(&mut context));
+        assert_eq!(None, tie_breaker.select(&mut context));
+    }
+}
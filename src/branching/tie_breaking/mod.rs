@@ -0,0 +1,23 @@
+//! Provides the [`TieBreaker`] trait which is used by [`VariableSelector`]s which select a variable
+//! based on a value associated with each variable (e.g. [`FirstFail`]); the [`TieBreaker`]
+//! determines which variable is selected when multiple variables have the best value.
+//!
+//! Two implementations are provided: [`InOrderTieBreaker`], which selects the first variable with
+//! the best value, and [`RandomTieBreaker`], which selects a variable with the best value uniformly
+//! at random using [`SelectionContext::random`].
+
+mod in_order_tie_breaker;
+mod random_tie_breaker;
+mod tie_breaker;
+
+pub use in_order_tie_breaker::InOrderTieBreaker;
+pub use random_tie_breaker::RandomTieBreaker;
+pub use tie_breaker::Direction;
+pub use tie_breaker::TieBreaker;
+
+#[cfg(doc)]
+use crate::branching::FirstFail;
+#[cfg(doc)]
+use crate::branching::SelectionContext;
+#[cfg(doc)]
+use crate::branching::VariableSelector;
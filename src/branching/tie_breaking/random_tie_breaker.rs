@@ -0,0 +1,94 @@
+use super::Direction;
+use super::TieBreaker;
+use crate::branching::SelectionContext;
+
+/// A [`TieBreaker`] which selects uniformly at random among the variables with the best value.
+///
+/// The random generator of the [`SelectionContext`] is used, which means that the selection can be
+/// made reproducible by seeding the random generator in the
+/// [`SolverOptions`](crate::options::SolverOptions).
+#[derive(Debug)]
+pub struct RandomTieBreaker<Var, Value> {
+    /// The variables which have the best value which has been considered so far.
+    candidates: Vec<Var>,
+    best_value: Option<Value>,
+    direction: Direction,
+}
+
+impl<Var, Value> RandomTieBreaker<Var, Value> {
+    pub fn new(direction: Direction) -> Self {
+        RandomTieBreaker {
+            candidates: vec![],
+            best_value: None,
+            direction,
+        }
+    }
+}
+
+impl<Var, Value: PartialOrd> TieBreaker<Var, Value> for RandomTieBreaker<Var, Value> {
+    fn consider(&mut self, variable: Var, value: Value) {
+        match &self.best_value {
+            Some(best) if *best == value => self.candidates.push(variable),
+            Some(best) if !self.direction.is_better(&value, best) => {}
+            _ => {
+                self.candidates.clear();
+                self.candidates.push(variable);
+                self.best_value = Some(value);
+            }
+        }
+    }
+
+    fn select(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        self.best_value = None;
+
+        if self.candidates.is_empty() {
+            return None;
+        }
+
+        let index = context
+            .random()
+            .generate_usize_in_range(0..self.candidates.len());
+        let selected = self.candidates.swap_remove(index);
+        self.candidates.clear();
+
+        Some(selected)
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::Direction;
+    use crate::branching::RandomTieBreaker;
+    use crate::branching::SelectionContext;
+    use crate::branching::TieBreaker;
+
+    #[test]
+    fn random_variable_with_best_value_is_selected() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(0, 0, None);
+        let mut test_rng = TestRandom {
+            usizes: vec![1],
+            bools: vec![],
+        };
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        let mut tie_breaker = RandomTieBreaker::new(Direction::Maximum);
+        tie_breaker.consider(0, 3);
+        tie_breaker.consider(1, 5);
+        tie_breaker.consider(2, 4);
+        tie_breaker.consider(3, 5);
+
+        assert_eq!(Some(3), tie_breaker.select(&mut context));
+        assert_eq!(None, tie_breaker.select(&mut context));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::branching::SelectionContext;
+
+/// Whether a [`TieBreaker`] selects the variable with the largest or the smallest value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Maximum,
+    Minimum,
+}
+
+impl Direction {
+    /// Returns true if `value` is strictly better than `best` according to this direction.
+    pub(crate) fn is_better<Value: PartialOrd>(self, value: &Value, best: &Value) -> bool {
+        match self {
+            Direction::Maximum => value > best,
+            Direction::Minimum => value < best,
+        }
+    }
+}
+
+/// A trait for selecting a variable among a number of variables which each have a value
+/// associated with them.
+///
+/// The variables are provided one by one using [`TieBreaker::consider`], after which
+/// [`TieBreaker::select`] returns the variable with the best value (according to the
+/// [`Direction`] of the tie-breaker). If multiple variables have the best value, the
+/// implementation decides which of them is selected.
+pub trait TieBreaker<Var, Value> {
+    /// Considers the provided `variable` with its associated `value`.
+    fn consider(&mut self, variable: Var, value: Value);
+
+    /// Returns the selected variable among the considered ones (or [`None`] if no variable was
+    /// considered) and resets the tie-breaker so it can be used for the next selection.
+    fn select(&mut self, context: &mut SelectionContext) -> Option<Var>;
+
+    /// Returns whether the tie-breaker selects the variable with the maximum or minimum value.
+    fn get_direction(&self) -> Direction;
+}
@@ -0,0 +1,66 @@
+use super::in_domain_split::get_middle;
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which branches on the intervals in the domain of the provided variable. If the
+/// domain consists of multiple intervals (i.e. it contains holes), the domain is restricted to the
+/// first interval; otherwise the domain is split in half and the lower half is chosen.
+///
+/// In both cases the decision is of the form `[x <= v]`.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainInterval;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainInterval {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let lower_bound = context.lower_bound(&decision_variable);
+        let upper_bound = context.upper_bound(&decision_variable);
+
+        let end_of_first_interval = (lower_bound..upper_bound)
+            .find(|&value| !context.contains(&decision_variable, value + 1))
+            .unwrap_or(upper_bound);
+
+        if end_of_first_interval < upper_bound {
+            predicate!(decision_variable <= end_of_first_interval)
+        } else {
+            predicate!(decision_variable <= get_middle(context, &decision_variable))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainInterval;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn first_interval_is_chosen() {
+        let (mut assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 6)]));
+        let domain = assignments_integer.get_domains().next().unwrap();
+        let _ = assignments_integer.remove_value_from_domain(domain, 4, None);
+        let _ = assignments_integer.remove_value_from_domain(domain, 5, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainInterval;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 3))
+    }
+}
@@ -0,0 +1,57 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which chooses to assign the provided variable to the median value in its
+/// domain; if the domain contains an even number of values, the smaller of the two middle values is
+/// chosen.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainMedian;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainMedian {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let values = (context.lower_bound(&decision_variable)
+            ..=context.upper_bound(&decision_variable))
+            .filter(|&value| context.contains(&decision_variable, value))
+            .collect::<Vec<_>>();
+
+        predicate!(decision_variable == values[(values.len() - 1) / 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainMedian;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn median_ignores_holes() {
+        let (mut assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 6)]));
+        let domain = assignments_integer.get_domains().next().unwrap();
+        let _ = assignments_integer.remove_value_from_domain(domain, 1, None);
+        let _ = assignments_integer.remove_value_from_domain(domain, 2, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainMedian;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] == 4))
+    }
+}
@@ -40,12 +40,13 @@ mod tests {
 
     #[test]
     fn test_returns_correct_literal() {
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
         let mut test_rng = TestRandom::default();
         let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
         let domain_ids = context.get_domains().collect::<Vec<_>>();
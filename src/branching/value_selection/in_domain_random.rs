@@ -0,0 +1,60 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which chooses to assign the provided variable to a value in its domain which
+/// is selected uniformly at random using [`SelectionContext::random`].
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainRandom;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainRandom {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let values = (context.lower_bound(&decision_variable)
+            ..=context.upper_bound(&decision_variable))
+            .filter(|&value| context.contains(&decision_variable, value))
+            .collect::<Vec<_>>();
+
+        let index = context.random().generate_usize_in_range(0..values.len());
+        predicate!(decision_variable == values[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainRandom;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn random_value_is_in_domain() {
+        let (mut assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 6)]));
+        let domain = assignments_integer.get_domains().next().unwrap();
+        let _ = assignments_integer.remove_value_from_domain(domain, 4, None);
+        let _ = assignments_integer.remove_value_from_domain(domain, 5, None);
+        let mut test_rng = TestRandom {
+            usizes: vec![4],
+            bools: vec![],
+        };
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainRandom;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] == 6))
+    }
+}
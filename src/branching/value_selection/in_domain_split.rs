@@ -0,0 +1,56 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which splits the domain of the provided variable in half and chooses the lower
+/// half; i.e. it creates the decision `[x <= (lb + ub) / 2]` (rounded down).
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainSplit;
+
+/// Returns the middle of the bounds of the variable, rounded down.
+pub(crate) fn get_middle<Var: IntegerVariable>(context: &SelectionContext, variable: &Var) -> i32 {
+    let lower_bound = context.lower_bound(variable);
+    let upper_bound = context.upper_bound(variable);
+
+    lower_bound + (upper_bound - lower_bound) / 2
+}
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainSplit {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        predicate!(decision_variable <= get_middle(context, &decision_variable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainSplit;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn lower_half_is_chosen() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(-3, 6)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainSplit;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 1))
+    }
+}
@@ -3,13 +3,25 @@
 //! [`ValueSelector::select_value`].
 //!
 //! Furthermore, it defines several implementations of the [`ValueSelector`] trait such as
-//! [`InDomainMin`], [`PhaseSaving`] and [`SolutionGuidedValueSelector`]. Any [`ValueSelector`]
+//! [`InDomainMin`], [`InDomainMax`], [`InDomainSplit`] and [`PhaseSaving`]. Any [`ValueSelector`]
 //! should only select values which are in the domain of the provided variable.
 
+mod in_domain_interval;
+mod in_domain_max;
+mod in_domain_median;
 mod in_domain_min;
+mod in_domain_random;
+mod in_domain_split;
 mod phase_saving;
+mod reverse_split;
 mod value_selector;
 
+pub use in_domain_interval::*;
+pub use in_domain_max::*;
+pub use in_domain_median::*;
 pub use in_domain_min::*;
+pub use in_domain_random::*;
+pub use in_domain_split::InDomainSplit;
 pub use phase_saving::*;
+pub use reverse_split::*;
 pub use value_selector::ValueSelector;
@@ -2,24 +2,37 @@ use log::warn;
 
 use super::ValueSelector;
 use crate::basic_types::KeyedVec;
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::SolutionReference;
 use crate::basic_types::StorageKey;
 use crate::branching::SelectionContext;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 use crate::munchkin_assert_moderate;
+#[cfg(doc)]
+use crate::Solver;
 
 /// A [`ValueSelector`] which implements [phase saving \[1\]](https://www.researchgate.net/profile/Thammanit-Pipatsrisawat/publication/220944633_A_Lightweight_Component_Caching_Scheme_for_Satisfiability_Solvers/links/0f31753c48ffead666000000/A-Lightweight-Component-Caching-Scheme-for-Satisfiability-Solvers.pdf).
 /// During the search process, values of variables are saved whenever they are assigned and the
 /// search process will attempt to assign to these values whenever possible. After a variable has
 /// been fixed, its value will be saved as the previous value and the search will continue.
 /// Values can be frozen meaning that they will not be updated with the previously assigned value
-/// during the search process, provided initial values will always be frozen.
+/// during the search process, provided initial values will always be frozen (e.g. see
+/// [`Solver::add_hint`]).
+///
+/// Whenever a solution is found, the saved values are replaced by the values in the solution
+/// (regardless of whether they are frozen); this steers the search towards the incumbent solution,
+/// similar to solution-guided search \[2\].
 ///
 /// # Bibliography
 /// \[1\] K. Pipatsrisawat and A. Darwiche, ‘A lightweight component caching scheme for
 /// satisfiability solvers’, in Theory and Applications of Satisfiability Testing--SAT 2007: 10th
 /// International Conference, Lisbon, Portugal, May 28-31, 2007. Proceedings 10, 2007, pp. 294–299.
+///
+/// \[2\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
+/// value-selection heuristic to simulate local search behavior in complete solvers’, in CP, 2018,
+/// pp. 99–108.
 #[derive(Debug)]
 pub struct PhaseSaving<Var, Value: PartialEq> {
     /// The saved values used by [`PhaseSaving`]
@@ -120,6 +133,15 @@ impl<Var: StorageKey + Copy + PartialEq, Value: Copy + PartialEq> PhaseSaving<Va
     pub fn freeze(&mut self, variable: Var, new_value: Value) {
         self.saved_values[variable] = StoredValue::Frozen(new_value)
     }
+
+    /// Replace the value of the variable with the provided value while keeping whether it is
+    /// frozen.
+    fn replace(&mut self, variable: Var, new_value: Value) {
+        self.saved_values[variable] = match self.saved_values[variable] {
+            StoredValue::Frozen(_) => StoredValue::Frozen(new_value),
+            StoredValue::Regular(_) => StoredValue::Regular(new_value),
+        }
+    }
 }
 
 impl ValueSelector<PropositionalVariable> for PhaseSaving<PropositionalVariable, bool> {
@@ -144,12 +166,28 @@ impl ValueSelector<PropositionalVariable> for PhaseSaving<PropositionalVariable,
         );
         self.update(lit.get_propositional_variable(), lit.is_positive())
     }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        let num_variables = self
+            .saved_values
+            .len()
+            .min(solution.num_propositional_variables());
+
+        for index in 0..num_variables {
+            let variable = PropositionalVariable::create_from_index(index);
+            self.replace(
+                variable,
+                solution.get_propositional_variable_value(variable),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::PhaseSaving;
     use crate::basic_types::tests::TestRandom;
+    use crate::basic_types::SolutionReference;
     use crate::basic_types::StorageKey;
     use crate::branching::value_selection::phase_saving::StoredValue;
     use crate::branching::value_selection::ValueSelector;
@@ -160,12 +198,13 @@ mod tests {
 
     #[test]
     fn saved_value_is_returned_prop() {
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(0, 1, None);
         let mut test_rng = TestRandom::default();
         let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
         let propositional_variables = context.get_propositional_variables().collect::<Vec<_>>();
@@ -201,12 +240,13 @@ mod tests {
     fn does_not_panic_with_unknown_selected_variable() {
         let mut phase_saving = PhaseSaving::new(&[]);
 
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(0, 0, None);
         let mut test_rng = TestRandom::default();
         let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
 
@@ -216,4 +256,32 @@ mod tests {
 
         assert_eq!(selected, Predicate::Literal(Literal::new(variable, false)));
     }
+
+    #[test]
+    fn solution_values_replace_saved_values() {
+        let (assignments_integer, mut assignments_propositional, _) =
+            SelectionContext::create_for_testing(0, 2, None);
+        let variables = assignments_propositional
+            .get_propositional_variables()
+            .collect::<Vec<_>>();
+        assignments_propositional.enqueue_decision_literal(Literal::new(variables[0], true));
+        assignments_propositional.enqueue_decision_literal(Literal::new(variables[1], true));
+
+        let mut phase_saving =
+            PhaseSaving::with_initial_values(&variables, vec![(variables[0], false)], false);
+
+        phase_saving.on_solution(SolutionReference::new(
+            &assignments_propositional,
+            &assignments_integer,
+        ));
+
+        assert_eq!(
+            phase_saving.saved_values[variables[0]],
+            StoredValue::Frozen(true)
+        );
+        assert_eq!(
+            phase_saving.saved_values[variables[1]],
+            StoredValue::Regular(true)
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use super::in_domain_split::get_middle;
+use super::ValueSelector;
+#[cfg(doc)]
+use crate::branching::InDomainSplit;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which splits the domain of the provided variable in half and chooses the upper
+/// half; i.e. it creates the decision `[x >= (lb + ub) / 2 + 1]` (where the division is rounded
+/// down). This is the opposite decision of [`InDomainSplit`].
+#[derive(Debug, Copy, Clone)]
+pub struct ReverseSplit;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for ReverseSplit {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        predicate!(decision_variable >= get_middle(context, &decision_variable) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::ReverseSplit;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn upper_half_is_chosen() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(-3, 6)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = ReverseSplit;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] >= 2))
+    }
+}
@@ -0,0 +1,168 @@
+use log::warn;
+
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::engine::variables::DomainId;
+use crate::munchkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+const DEFAULT_ABS_DECAY_FACTOR: f64 = 0.999;
+
+/// A [`VariableSelector`] which implements activity-based search \[1\]; it selects the unfixed
+/// variable with the largest ratio between its activity and the size of its domain.
+///
+/// The activity of a variable measures how often its domain is changed by propagation. After every
+/// decision has been propagated, the activity of every unfixed variable is multiplied by the decay
+/// factor, after which the activity of every variable of which the domain changed is increased by
+/// one. In contrast to \[1\], the activities are not initialised by probing; all variables start
+/// with an activity of zero.
+///
+/// The activity is kept for the domain of a variable, so views of the same domain share their
+/// activity. Ties are broken by the provided [`TieBreaker`]; by default the first variable (in the
+/// order of the provided list) with the largest ratio is selected.
+///
+/// # Bibliography
+/// \[1\] L. Michel and P. Van Hentenryck, ‘Activity-based search for black-box constraint
+/// programming solvers’, in CPAIOR, 2012, pp. 228–243.
+#[derive(Debug)]
+pub struct ActivityBasedSearch<Var, TieBreaking> {
+    variables: Vec<Var>,
+    /// The domains of the provided variables, each domain occurs once.
+    domains: Vec<DomainId>,
+    activities: KeyedVec<DomainId, f64>,
+    /// Whether the domain changed during the propagation of the last decision; only used while
+    /// updating the activities.
+    is_changed: KeyedVec<DomainId, bool>,
+    /// The factor by which the activities of unfixed variables are multiplied after every
+    /// decision. This value is constant.
+    decay_factor: f64,
+    tie_breaker: TieBreaking,
+}
+
+impl<Var: IntegerVariable> ActivityBasedSearch<Var, InOrderTieBreaker<Var, f64>> {
+    /// Creates a new instance of the [`ActivityBasedSearch`] [`VariableSelector`] with a decay
+    /// factor of `0.999`.
+    pub fn new(variables: &[Var]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Maximum))
+    }
+}
+
+impl<Var: IntegerVariable, TieBreaking: TieBreaker<Var, f64>>
+    ActivityBasedSearch<Var, TieBreaking>
+{
+    /// Creates an [`ActivityBasedSearch`] selector which breaks ties using `tie_breaker`; the
+    /// tie-breaker should select the variable with the maximum value.
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        if variables.is_empty() {
+            warn!("The ActivityBasedSearch variable selector was not provided with any variables");
+        }
+        munchkin_assert_simple!(
+            tie_breaker.get_direction() == Direction::Maximum,
+            "The tie-breaker of ActivityBasedSearch should select the maximum value"
+        );
+
+        let mut domains: Vec<DomainId> = variables.iter().map(|var| var.domain_id()).collect();
+        domains.sort_by_key(|domain| domain.id);
+        domains.dedup();
+
+        let mut activities = KeyedVec::default();
+        let mut is_changed = KeyedVec::default();
+        if let Some(&largest) = domains.last() {
+            activities.accomodate(largest, 0.0);
+            is_changed.accomodate(largest, false);
+        }
+
+        ActivityBasedSearch {
+            variables: variables.to_vec(),
+            domains,
+            activities,
+            is_changed,
+            decay_factor: DEFAULT_ABS_DECAY_FACTOR,
+            tie_breaker,
+        }
+    }
+}
+
+impl<Var, TieBreaking> VariableSelector<Var> for ActivityBasedSearch<Var, TieBreaking>
+where
+    Var: IntegerVariable,
+    TieBreaking: TieBreaker<Var, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        for variable in self.variables.iter() {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            let domain_size = context.get_size_of_domain(variable) + 1;
+            let activity = self.activities[variable.domain_id()];
+
+            self.tie_breaker
+                .consider(variable.clone(), activity / domain_size as f64);
+        }
+
+        self.tie_breaker.select(context)
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, _is_conflicting: bool) {
+        for domain in context.get_domains_changed_at_current_decision_level() {
+            if domain.index() < self.is_changed.len() {
+                self.is_changed[domain] = true;
+            }
+        }
+
+        for &domain in self.domains.iter() {
+            if !context.is_integer_fixed(&domain) {
+                self.activities[domain] *= self.decay_factor;
+            }
+
+            if self.is_changed[domain] {
+                self.activities[domain] += 1.0;
+                self.is_changed[domain] = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::ActivityBasedSearch;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn changed_variables_become_active() {
+        let (mut assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (0, 10), (0, 10)]));
+        let domains = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = ActivityBasedSearch::new(&domains);
+        let mut test_rng = TestRandom::default();
+
+        assignments_integer.increase_decision_level();
+        let _ = assignments_integer.tighten_lower_bound(domains[2], 5, None);
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &watch_list_cp,
+                &mut test_rng,
+            );
+            strategy.on_decision_propagated(&context, false);
+        }
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        assert_eq!(strategy.select_variable(&mut context), Some(domains[2]));
+    }
+}
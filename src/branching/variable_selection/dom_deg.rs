@@ -0,0 +1,119 @@
+use log::warn;
+
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::munchkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// A [`VariableSelector`] which selects the unfixed variable with the smallest ratio between the
+/// size of its domain and its degree, where the degree of a variable is the number of propagators
+/// which are registered on it (see [`SelectionContext::get_degree`]).
+///
+/// Intuitively, a variable with a small domain which is involved in many constraints is likely to
+/// lead to failure quickly. A variable which is not involved in any propagator is only selected
+/// when all other variables are fixed.
+///
+/// Ties are broken by the provided [`TieBreaker`]; by default the first variable (in the order of
+/// the provided list) with the smallest ratio is selected.
+#[derive(Debug)]
+pub struct DomDeg<Var, TieBreaking> {
+    variables: Vec<Var>,
+    tie_breaker: TieBreaking,
+}
+
+impl<Var: Clone> DomDeg<Var, InOrderTieBreaker<Var, f64>> {
+    pub fn new(variables: &[Var]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Minimum))
+    }
+}
+
+impl<Var: Clone, TieBreaking: TieBreaker<Var, f64>> DomDeg<Var, TieBreaking> {
+    /// Creates a [`DomDeg`] selector which breaks ties using `tie_breaker`; the tie-breaker
+    /// should select the variable with the minimum value.
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        if variables.is_empty() {
+            warn!("The DomDeg variable selector was not provided with any variables");
+        }
+        munchkin_assert_simple!(
+            tie_breaker.get_direction() == Direction::Minimum,
+            "The tie-breaker of DomDeg should select the minimum value"
+        );
+
+        DomDeg {
+            variables: variables.to_vec(),
+            tie_breaker,
+        }
+    }
+}
+
+impl<Var, TieBreaking> VariableSelector<Var> for DomDeg<Var, TieBreaking>
+where
+    Var: IntegerVariable,
+    TieBreaking: TieBreaker<Var, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        self.variables
+            .iter()
+            .filter(|variable| !context.is_integer_fixed(*variable))
+            .for_each(|variable| {
+                let domain_size = context.get_size_of_domain(variable) + 1;
+                let degree = context.get_degree(variable);
+
+                self.tie_breaker
+                    .consider(variable.clone(), domain_size as f64 / degree as f64)
+            });
+
+        self.tie_breaker.select(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::DomDeg;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+    use crate::engine::cp::domain_events::DomainEvents;
+    use crate::engine::cp::propagation::LocalId;
+    use crate::engine::cp::propagation::PropagatorId;
+    use crate::engine::cp::propagation::PropagatorVarId;
+    use crate::engine::cp::Watchers;
+
+    #[test]
+    fn variable_with_smallest_ratio_is_selected() {
+        let (assignments_integer, assignments_propositional, mut watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 3), (0, 5)]));
+        let domains = assignments_integer.get_domains().collect::<Vec<_>>();
+
+        // The second variable is watched by three propagators, giving it a ratio of 6 / 3 = 2,
+        // while the first variable has a ratio of 4 / 1 = 4.
+        for propagator in 0..3 {
+            let mut watchers = Watchers::new(
+                PropagatorVarId {
+                    propagator: PropagatorId(propagator),
+                    variable: LocalId::from(0),
+                },
+                &mut watch_list_cp,
+            );
+            watchers.watch_all(domains[1], DomainEvents::BOUNDS.get_int_events());
+            if propagator == 0 {
+                watchers.watch_all(domains[0], DomainEvents::BOUNDS.get_int_events());
+            }
+        }
+
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        let mut strategy = DomDeg::new(&domains);
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(domains[1]));
+    }
+}
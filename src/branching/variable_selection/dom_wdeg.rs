@@ -0,0 +1,162 @@
+use log::warn;
+
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::munchkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// The weight of a propagator which has not caused any conflicts.
+const DEFAULT_DOM_WDEG_WEIGHT: u64 = 1;
+
+/// A [`VariableSelector`] which implements dom/wdeg \[1\]; it selects the unfixed variable with the
+/// smallest ratio between the size of its domain and its weighted degree.
+///
+/// Every propagator has a weight which starts at 1 and is incremented whenever the propagator
+/// causes a conflict. The weighted degree of a variable is the sum of the weights of the
+/// propagators which are registered on it (see [`SelectionContext::get_propagators`]). In contrast
+/// to \[1\], the propagators are taken into account regardless of how many of their variables are
+/// unfixed.
+///
+/// Ties are broken by the provided [`TieBreaker`]; by default the first variable (in the order of
+/// the provided list) with the smallest ratio is selected.
+///
+/// # Bibliography
+/// \[1\] F. Boussemart, F. Hemery, C. Lecoutre, and L. Sais, ‘Boosting systematic search by
+/// weighting constraints’, in ECAI, 2004, pp. 146–150.
+#[derive(Debug)]
+pub struct DomWDeg<Var, TieBreaking> {
+    variables: Vec<Var>,
+    /// The weight of each propagator, indexed by [`PropagatorId`]; propagators which are not in
+    /// this list have the default weight.
+    weights: Vec<u64>,
+    tie_breaker: TieBreaking,
+}
+
+impl<Var: Clone> DomWDeg<Var, InOrderTieBreaker<Var, f64>> {
+    pub fn new(variables: &[Var]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Minimum))
+    }
+}
+
+impl<Var: Clone, TieBreaking: TieBreaker<Var, f64>> DomWDeg<Var, TieBreaking> {
+    /// Creates a [`DomWDeg`] selector which breaks ties using `tie_breaker`; the tie-breaker
+    /// should select the variable with the minimum value.
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        if variables.is_empty() {
+            warn!("The DomWDeg variable selector was not provided with any variables");
+        }
+        munchkin_assert_simple!(
+            tie_breaker.get_direction() == Direction::Minimum,
+            "The tie-breaker of DomWDeg should select the minimum value"
+        );
+
+        DomWDeg {
+            variables: variables.to_vec(),
+            weights: vec![],
+            tie_breaker,
+        }
+    }
+
+    fn get_weight(&self, propagator: PropagatorId) -> u64 {
+        self.weights
+            .get(propagator.0 as usize)
+            .copied()
+            .unwrap_or(DEFAULT_DOM_WDEG_WEIGHT)
+    }
+}
+
+impl<Var, TieBreaking> VariableSelector<Var> for DomWDeg<Var, TieBreaking>
+where
+    Var: IntegerVariable,
+    TieBreaking: TieBreaker<Var, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        for variable in self.variables.iter() {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            let domain_size = context.get_size_of_domain(variable) + 1;
+            let weighted_degree: u64 = context
+                .get_propagators(variable)
+                .iter()
+                .map(|&propagator| self.get_weight(propagator))
+                .sum();
+
+            self.tie_breaker.consider(
+                variable.clone(),
+                domain_size as f64 / weighted_degree as f64,
+            );
+        }
+
+        self.tie_breaker.select(context)
+    }
+
+    fn on_propagator_conflict(&mut self, propagator: PropagatorId) {
+        let index = propagator.0 as usize;
+        if self.weights.len() <= index {
+            self.weights.resize(index + 1, DEFAULT_DOM_WDEG_WEIGHT);
+        }
+
+        self.weights[propagator] += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::DomWDeg;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+    use crate::engine::cp::domain_events::DomainEvents;
+    use crate::engine::cp::propagation::LocalId;
+    use crate::engine::cp::propagation::PropagatorId;
+    use crate::engine::cp::propagation::PropagatorVarId;
+    use crate::engine::cp::Watchers;
+
+    #[test]
+    fn conflicts_increase_the_weight_of_variables() {
+        let (assignments_integer, assignments_propositional, mut watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 5), (0, 5)]));
+        let domains = assignments_integer.get_domains().collect::<Vec<_>>();
+
+        // Each variable is watched by its own propagator.
+        for (index, &domain) in domains.iter().enumerate() {
+            Watchers::new(
+                PropagatorVarId {
+                    propagator: PropagatorId(index as u32),
+                    variable: LocalId::from(0),
+                },
+                &mut watch_list_cp,
+            )
+            .watch_all(domain, DomainEvents::BOUNDS.get_int_events());
+        }
+
+        let mut strategy = DomWDeg::new(&domains);
+        let mut test_rng = TestRandom::default();
+
+        {
+            let mut context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &watch_list_cp,
+                &mut test_rng,
+            );
+            assert_eq!(strategy.select_variable(&mut context), Some(domains[0]));
+        }
+
+        strategy.on_propagator_conflict(PropagatorId(1));
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        assert_eq!(strategy.select_variable(&mut context), Some(domains[1]));
+    }
+}
@@ -0,0 +1,107 @@
+use log::warn;
+
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::munchkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// A [`VariableSelector`] which selects the unfixed variable with the smallest domain, where the
+/// size of the domain is determined by [`SelectionContext::get_size_of_domain`].
+///
+/// Ties are broken by the provided [`TieBreaker`]; by default the first variable (in the order of
+/// the provided list) with the smallest domain is selected.
+#[derive(Debug)]
+pub struct FirstFail<Var, TieBreaking> {
+    variables: Vec<Var>,
+    tie_breaker: TieBreaking,
+}
+
+impl<Var: Clone> FirstFail<Var, InOrderTieBreaker<Var, i32>> {
+    pub fn new(variables: &[Var]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Minimum))
+    }
+}
+
+impl<Var: Clone, TieBreaking: TieBreaker<Var, i32>> FirstFail<Var, TieBreaking> {
+    /// Creates a [`FirstFail`] selector which breaks ties using `tie_breaker`; the tie-breaker
+    /// should select the variable with the minimum value.
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        if variables.is_empty() {
+            warn!("The FirstFail variable selector was not provided with any variables");
+        }
+        munchkin_assert_simple!(
+            tie_breaker.get_direction() == Direction::Minimum,
+            "The tie-breaker of FirstFail should select the minimum value"
+        );
+
+        FirstFail {
+            variables: variables.to_vec(),
+            tie_breaker,
+        }
+    }
+}
+
+impl<Var, TieBreaking> VariableSelector<Var> for FirstFail<Var, TieBreaking>
+where
+    Var: IntegerVariable,
+    TieBreaking: TieBreaker<Var, i32>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        self.variables
+            .iter()
+            .filter(|variable| !context.is_integer_fixed(*variable))
+            .for_each(|variable| {
+                self.tie_breaker
+                    .consider(variable.clone(), context.get_size_of_domain(variable))
+            });
+
+        self.tie_breaker.select(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::FirstFail;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn variable_with_smallest_domain_is_selected() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (5, 7), (0, 2)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(&integer_variables);
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(10, 10), (0, 5)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(&integer_variables);
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+}
@@ -0,0 +1,152 @@
+use log::warn;
+
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::munchkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// A [`VariableSelector`] which implements impact-based search \[1\]; it selects the unfixed
+/// variable of which the decisions have had the largest impact on average.
+///
+/// The size of the search space is the product of the domain sizes of the provided variables. The
+/// impact of a decision is the relative reduction of the search space due to the decision and its
+/// propagation; a decision which leads to a conflict has an impact of 1. In contrast to \[1\], the
+/// impact is kept per variable rather than per assignment (since a decision does not necessarily
+/// assign a variable), and the impacts are not initialised by probing.
+///
+/// Ties are broken by the provided [`TieBreaker`], which is given the indices of the variables in
+/// the provided list; by default the first variable with the largest impact is selected.
+///
+/// # Bibliography
+/// \[1\] P. Refalo, ‘Impact-based search strategies for constraint programming’, in CP, 2004, pp.
+/// 557–571.
+#[derive(Debug)]
+pub struct ImpactBasedSearch<Var, TieBreaking> {
+    variables: Vec<Var>,
+    /// The average impact of the decisions on the variable at the same index in
+    /// [`ImpactBasedSearch::variables`].
+    impacts: Vec<f64>,
+    /// The number of decisions on the variable at the same index in
+    /// [`ImpactBasedSearch::variables`] which have been propagated.
+    num_decisions: Vec<u64>,
+    /// The index of the variable which was selected last, together with the logarithm of the size
+    /// of the search space at the moment it was selected.
+    last_selected: Option<(usize, f64)>,
+    tie_breaker: TieBreaking,
+}
+
+impl<Var: IntegerVariable> ImpactBasedSearch<Var, InOrderTieBreaker<usize, f64>> {
+    pub fn new(variables: &[Var]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Maximum))
+    }
+}
+
+impl<Var: IntegerVariable, TieBreaking: TieBreaker<usize, f64>>
+    ImpactBasedSearch<Var, TieBreaking>
+{
+    /// Creates an [`ImpactBasedSearch`] selector which breaks ties using `tie_breaker`; the
+    /// tie-breaker should select the variable with the maximum value.
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        if variables.is_empty() {
+            warn!("The ImpactBasedSearch variable selector was not provided with any variables");
+        }
+        munchkin_assert_simple!(
+            tie_breaker.get_direction() == Direction::Maximum,
+            "The tie-breaker of ImpactBasedSearch should select the maximum value"
+        );
+
+        ImpactBasedSearch {
+            variables: variables.to_vec(),
+            impacts: vec![0.0; variables.len()],
+            num_decisions: vec![0; variables.len()],
+            last_selected: None,
+            tie_breaker,
+        }
+    }
+
+    /// Returns the logarithm of the size of the search space spanned by the variables.
+    fn log_search_space_size(&self, context: &SelectionContext) -> f64 {
+        self.variables
+            .iter()
+            .map(|variable| ((context.get_size_of_domain(variable) + 1) as f64).ln())
+            .sum()
+    }
+}
+
+impl<Var, TieBreaking> VariableSelector<Var> for ImpactBasedSearch<Var, TieBreaking>
+where
+    Var: IntegerVariable,
+    TieBreaking: TieBreaker<usize, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
+        for (index, variable) in self.variables.iter().enumerate() {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            self.tie_breaker.consider(index, self.impacts[index]);
+        }
+
+        let selected = self.tie_breaker.select(context)?;
+        self.last_selected = Some((selected, self.log_search_space_size(context)));
+
+        Some(self.variables[selected].clone())
+    }
+
+    fn on_decision_propagated(&mut self, context: &SelectionContext, is_conflicting: bool) {
+        let Some((index, log_size_before)) = self.last_selected.take() else {
+            return;
+        };
+
+        let impact = if is_conflicting {
+            1.0
+        } else {
+            1.0 - (self.log_search_space_size(context) - log_size_before).exp()
+        };
+
+        self.num_decisions[index] += 1;
+        self.impacts[index] += (impact - self.impacts[index]) / self.num_decisions[index] as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::Direction;
+    use crate::branching::ImpactBasedSearch;
+    use crate::branching::RandomTieBreaker;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn variable_with_conflicting_decision_is_selected() {
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (0, 10)]));
+        let domains = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = ImpactBasedSearch::with_tie_breaker(
+            &domains,
+            RandomTieBreaker::new(Direction::Maximum),
+        );
+        let mut test_rng = TestRandom {
+            usizes: vec![1, 0],
+            bools: vec![],
+        };
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &watch_list_cp,
+            &mut test_rng,
+        );
+
+        // Initially all impacts are zero, so the tie is broken randomly; the decision on the
+        // selected variable leads to a conflict, which gives it the maximum impact.
+        assert_eq!(strategy.select_variable(&mut context), Some(domains[1]));
+        strategy.on_decision_propagated(&context, true);
+
+        assert_eq!(strategy.select_variable(&mut context), Some(domains[1]));
+    }
+}
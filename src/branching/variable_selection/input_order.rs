@@ -23,7 +23,7 @@ impl<Var> InputOrder<Var> {
 }
 
 impl<Var: IntegerVariable> VariableSelector<Var> for InputOrder<Var> {
-    fn select_variable(&mut self, context: &SelectionContext) -> Option<Var> {
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var> {
         self.variables
             .iter()
             .find(|&variable| !context.is_integer_fixed(variable))
@@ -32,7 +32,7 @@ impl<Var: IntegerVariable> VariableSelector<Var> for InputOrder<Var> {
 }
 
 impl VariableSelector<PropositionalVariable> for InputOrder<PropositionalVariable> {
-    fn select_variable(&mut self, context: &SelectionContext) -> Option<PropositionalVariable> {
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<PropositionalVariable> {
         self.variables
             .iter()
             .find(|variable| !context.is_propositional_variable_fixed(**variable))
@@ -49,51 +49,54 @@ mod tests {
 
     #[test]
     fn test_correctly_selected() {
-        let (mut assignments_integer, assignments_propositional) =
+        let (mut assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (5, 20)]));
         let mut test_rng = TestRandom::default();
         let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
         let mut strategy = InputOrder::new(integer_variables.clone());
 
         {
-            let context = SelectionContext::new(
+            let mut context = SelectionContext::new(
                 &assignments_integer,
                 &assignments_propositional,
+                &watch_list_cp,
                 &mut test_rng,
             );
 
-            let selected = strategy.select_variable(&context);
+            let selected = strategy.select_variable(&mut context);
             assert!(selected.is_some());
             assert_eq!(selected.unwrap(), integer_variables[0]);
         }
 
         let _ = assignments_integer.make_assignment(integer_variables[0], 0, None);
 
-        let context = SelectionContext::new(
+        let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
 
-        let selected = strategy.select_variable(&context);
+        let selected = strategy.select_variable(&mut context);
         assert!(selected.is_some());
         assert_eq!(selected.unwrap(), integer_variables[1]);
     }
 
     #[test]
     fn fixed_variables_are_not_selected() {
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(2, 0, Some(vec![(10, 10), (20, 20)]));
         let mut test_rng = TestRandom::default();
-        let context = SelectionContext::new(
+        let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
         let integer_variables = context.get_domains().collect::<Vec<_>>();
 
         let mut strategy = InputOrder::new(integer_variables);
-        let selected = strategy.select_variable(&context);
+        let selected = strategy.select_variable(&mut context);
         assert!(selected.is_none());
     }
 }
@@ -3,11 +3,24 @@
 //! [`VariableSelector::select_variable`].
 //!
 //! Furthermore, it defines several implementations of the [`VariableSelector`] trait such as
-//! [`Vsids`]. Any [`VariableSelector`] should only select variables which have a domain of size 2
-//! or larger.
+//! [`Vsids`], [`FirstFail`], [`DomDeg`], [`DomWDeg`], [`ActivityBasedSearch`] and
+//! [`ImpactBasedSearch`]. Any [`VariableSelector`] should only select variables which have a
+//! domain of size 2 or larger.
 
+mod activity_based_search;
+mod dom_deg;
+mod dom_wdeg;
+mod first_fail;
+mod impact_based_search;
 mod input_order;
 mod variable_selector;
+mod vsids;
 
+pub use activity_based_search::*;
+pub use dom_deg::*;
+pub use dom_wdeg::*;
+pub use first_fail::*;
+pub use impact_based_search::*;
 pub use input_order::*;
 pub use variable_selector::VariableSelector;
+pub use vsids::*;
@@ -1,4 +1,5 @@
 use crate::branching::SelectionContext;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
@@ -12,7 +13,7 @@ pub trait VariableSelector<Var> {
     /// Should only return [`None`] when all variables which have been passed to the
     /// [`VariableSelector`] have been assigned. Otherwise it should return the variable to
     /// branch on next.
-    fn select_variable(&mut self, context: &SelectionContext) -> Option<Var>;
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<Var>;
 
     /// A function which is called after a conflict has been found and processed but (currently)
     /// does not provide any additional information.
@@ -36,4 +37,23 @@ pub trait VariableSelector<Var> {
 
     /// A function which is called when a variable appears in a conflict during conflict analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
+
+    /// Returns whether the [`VariableSelector`] makes use of the variables which appear in
+    /// conflicts (see [`VariableSelector::on_appearance_in_conflict_literal`] and
+    /// [`VariableSelector::on_appearance_in_conflict_integer`]).
+    ///
+    /// By default, this returns `false`.
+    fn uses_conflict_appearances(&self) -> bool {
+        false
+    }
+
+    /// A function which is called when a conflict is found which was caused by a propagator,
+    /// before the conflict is analysed; `propagator` is the [`PropagatorId`] of that propagator.
+    fn on_propagator_conflict(&mut self, _propagator: PropagatorId) {}
+
+    /// A function which is called after the solver has propagated the decision on the variable
+    /// which was last selected by [`VariableSelector::select_variable`]; `is_conflicting`
+    /// indicates whether propagation resulted in a conflict. The [`SelectionContext`] reflects the
+    /// domains after propagation.
+    fn on_decision_propagated(&mut self, _context: &SelectionContext, _is_conflicting: bool) {}
 }
@@ -160,12 +160,12 @@ impl<Var: StorageKey + Clone + Copy> Vsids<Var> {
 }
 
 impl VariableSelector<DomainId> for Vsids<DomainId> {
-    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
         loop {
             // We peek the first variable, note that we do not pop since we do not (yet) want to
             // remove the value from the heap
             if let Some((candidate, _)) = self.heap.peek_max() {
-                if context.is_integer_fixed(*candidate) {
+                if context.is_integer_fixed(candidate) {
                     let _ = self.heap.pop_max();
                 } else {
                     return Some(*candidate);
@@ -187,10 +187,14 @@ impl VariableSelector<DomainId> for Vsids<DomainId> {
     fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
         self.bump_activity(variable)
     }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        true
+    }
 }
 
 impl VariableSelector<PropositionalVariable> for Vsids<PropositionalVariable> {
-    fn select_variable(&mut self, context: &SelectionContext) -> Option<PropositionalVariable> {
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<PropositionalVariable> {
         loop {
             if let Some((candidate, _)) = self.heap.peek_max() {
                 if context.is_propositional_variable_fixed(*candidate) {
@@ -215,6 +219,10 @@ impl VariableSelector<PropositionalVariable> for Vsids<PropositionalVariable> {
     fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
         self.bump_activity(literal.get_propositional_variable())
     }
+
+    fn uses_conflict_appearances(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -229,12 +237,13 @@ mod tests {
 
     #[test]
     fn vsids_bumped_var_is_max() {
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(2, 0, None);
         let mut test_rng = TestRandom::default();
-        let context = SelectionContext::new(
+        let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
         let domains = context.get_domains().collect::<Vec<_>>();
@@ -242,7 +251,7 @@ mod tests {
         let mut vsids = Vsids::new(&domains);
         vsids.bump_activity(domains[1]);
 
-        let chosen = vsids.select_variable(&context);
+        let chosen = vsids.select_variable(&mut context);
 
         assert!(chosen.is_some());
         assert_eq!(chosen.unwrap(), domains[1]);
@@ -252,15 +261,16 @@ mod tests {
     fn vsids_no_variables_will_return_none() {
         let mut vsids: Vsids<PropositionalVariable> = Vsids::new(&Vec::new());
 
-        let (assignments_integer, assignments_propositional) =
+        let (assignments_integer, assignments_propositional, watch_list_cp) =
             SelectionContext::create_for_testing(0, 0, None);
         let mut test_rng = TestRandom::default();
-        let context = SelectionContext::new(
+        let mut context = SelectionContext::new(
             &assignments_integer,
             &assignments_propositional,
+            &watch_list_cp,
             &mut test_rng,
         );
-        let chosen = vsids.select_variable(&context);
+        let chosen = vsids.select_variable(&mut context);
 
         assert!(chosen.is_none());
     }
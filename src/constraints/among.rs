@@ -0,0 +1,120 @@
+use super::equals;
+use super::Constraint;
+use crate::predicate;
+use crate::propagators::counting::among::AmongPropagator;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [among](https://sofdem.github.io/gccat/gccat/Camong.html) [`Constraint`] which
+/// states that exactly `count` of the `variables` take a value in `values`.
+pub fn among<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    values: impl Into<Box<[i32]>>,
+    count: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    AmongPropagator::new(variables.into(), values.into(), count)
+}
+
+/// Creates the [`Constraint`] which states that exactly `count` of the `variables` are equal to
+/// `value`.
+pub fn count_eq<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    value: i32,
+    count: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    among(variables, [value], count)
+}
+
+/// Creates a [`Constraint`] equivalent to [`among`], but using a decomposition rather than a
+/// global propagator.
+///
+/// For every variable a 0-1 variable is introduced which is 1 if and only if the variable takes a
+/// value in `values`, and `count` is constrained to be the sum of those.
+pub fn among_decomposition<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    values: impl Into<Box<[i32]>>,
+    count: AffineView<DomainId>,
+) -> impl Constraint {
+    AmongDecomposition {
+        variables: variables.into(),
+        values: values.into(),
+        count,
+    }
+}
+
+/// Creates a [`Constraint`] equivalent to [`count_eq`], but using a decomposition rather than a
+/// global propagator.
+pub fn count_eq_decomposition<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    value: i32,
+    count: AffineView<DomainId>,
+) -> impl Constraint {
+    among_decomposition(variables, [value], count)
+}
+
+struct AmongDecomposition<Var> {
+    variables: Box<[Var]>,
+    values: Box<[i32]>,
+    count: AffineView<DomainId>,
+}
+
+impl<Var: IntegerVariable + 'static> AmongDecomposition<Var> {
+    /// Creates the 0-1 variables which indicate whether the corresponding variable takes a value
+    /// in `values`, followed by `-count`.
+    fn create_terms(
+        &self,
+        solver: &mut Solver,
+    ) -> Result<Vec<AffineView<DomainId>>, ConstraintOperationError> {
+        let mut terms = Vec::with_capacity(self.variables.len() + 1);
+
+        for variable in self.variables.iter() {
+            let is_counted = solver.new_bounded_integer(0, 1);
+            let is_counted_literal = solver.get_literal(predicate![is_counted >= 1]);
+
+            let value_literals: Vec<Literal> = self
+                .values
+                .iter()
+                .map(|&value| solver.get_literal(predicate![variable == value]))
+                .collect();
+
+            // [is_counted = 1] -> \/ [variable = value]
+            solver.add_clause(
+                std::iter::once(!is_counted_literal).chain(value_literals.iter().copied()),
+            )?;
+
+            // [variable = value] -> [is_counted = 1]
+            for value_literal in value_literals {
+                solver.add_clause([!value_literal, is_counted_literal])?;
+            }
+
+            terms.push(is_counted.scaled(1));
+        }
+
+        terms.push(self.count.scaled(-1));
+
+        Ok(terms)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for AmongDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        let terms = self.create_terms(solver)?;
+
+        equals(terms, 0).post(solver)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        let terms = self.create_terms(solver)?;
+
+        equals(terms, 0).implied_by(solver, reification_literal)
+    }
+}
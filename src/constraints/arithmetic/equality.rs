@@ -1,7 +1,9 @@
 use super::less_than_or_equals;
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
+use crate::propagators::arithmetic::linear_equal::LinearEqualPropagator;
 use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+use crate::propagators::ReifiedPropagator;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
@@ -14,8 +16,10 @@ pub fn equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
     rhs: i32,
 ) -> impl NegatableConstraint {
+    let terms = terms.into();
     EqualConstraint {
-        terms: terms.into(),
+        weights: vec![1; terms.len()].into(),
+        terms,
         rhs,
     }
 }
@@ -27,7 +31,11 @@ pub fn binary_equals<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
 ) -> impl NegatableConstraint {
-    equals([lhs.scaled(1), rhs.scaled(-1)], 0)
+    EqualConstraint {
+        terms: [lhs, rhs].into(),
+        weights: [1, -1].into(),
+        rhs: 0,
+    }
 }
 
 /// Create the [`NegatableConstraint`] `\sum terms_i != rhs`.
@@ -47,27 +55,34 @@ pub fn binary_not_equals<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
 ) -> impl NegatableConstraint {
-    not_equals([lhs.scaled(1), rhs.scaled(-1)], 0)
+    binary_equals(lhs, rhs).negation()
 }
 
+/// The constraint `\sum weights_i * terms_i = rhs`.
 struct EqualConstraint<Var> {
     terms: Box<[Var]>,
+    weights: Box<[i32]>,
     rhs: i32,
 }
 
+impl<Var: IntegerVariable> EqualConstraint<Var> {
+    /// The terms scaled by their weights, and by `sign`.
+    fn scaled_terms(&self, sign: i32) -> Box<[Var::AffineView]> {
+        self.terms
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(term, &weight)| term.scaled(sign * weight))
+            .collect()
+    }
+}
+
 impl<Var> Constraint for EqualConstraint<Var>
 where
     Var: IntegerVariable + Clone + 'static,
 {
     fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
-        less_than_or_equals(self.terms.clone(), self.rhs).post(solver)?;
-
-        let negated = self
-            .terms
-            .iter()
-            .map(|var| var.scaled(-1))
-            .collect::<Box<[_]>>();
-        less_than_or_equals(negated, -self.rhs).post(solver)?;
+        less_than_or_equals(self.scaled_terms(1), self.rhs).post(solver)?;
+        less_than_or_equals(self.scaled_terms(-1), -self.rhs).post(solver)?;
 
         Ok(())
     }
@@ -77,15 +92,10 @@ where
         solver: &mut Solver,
         reification_literal: Literal,
     ) -> Result<(), ConstraintOperationError> {
-        less_than_or_equals(self.terms.clone(), self.rhs)
+        less_than_or_equals(self.scaled_terms(1), self.rhs)
+            .implied_by(solver, reification_literal)?;
+        less_than_or_equals(self.scaled_terms(-1), -self.rhs)
             .implied_by(solver, reification_literal)?;
-
-        let negated = self
-            .terms
-            .iter()
-            .map(|var| var.scaled(-1))
-            .collect::<Box<[_]>>();
-        less_than_or_equals(negated, -self.rhs).implied_by(solver, reification_literal)?;
 
         Ok(())
     }
@@ -100,13 +110,24 @@ where
     fn negation(&self) -> Self::NegatedConstraint {
         NotEqualConstraint {
             terms: self.terms.clone(),
+            weights: self.weights.clone(),
             rhs: self.rhs,
         }
     }
+    fn reify(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        // `r <-> \sum terms_i = rhs` is the same as `!r <-> \sum terms_i != rhs`.
+        self.negation().reify(solver, !reification_literal)
+    }
 }
 
+/// The constraint `\sum weights_i * terms_i != rhs`.
 struct NotEqualConstraint<Var> {
     terms: Box<[Var]>,
+    weights: Box<[i32]>,
     rhs: i32,
 }
 
@@ -115,7 +136,7 @@ where
     Var: IntegerVariable + Clone + 'static,
 {
     fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
-        LinearNotEqualPropagator::new(self.terms, self.rhs).post(solver)
+        LinearNotEqualPropagator::new(self.terms, self.weights, self.rhs).post(solver)
     }
 
     fn implied_by(
@@ -123,7 +144,8 @@ where
         solver: &mut Solver,
         reification_literal: Literal,
     ) -> Result<(), ConstraintOperationError> {
-        LinearNotEqualPropagator::new(self.terms, self.rhs).implied_by(solver, reification_literal)
+        LinearNotEqualPropagator::new(self.terms, self.weights, self.rhs)
+            .implied_by(solver, reification_literal)
     }
 }
 
@@ -136,7 +158,25 @@ where
     fn negation(&self) -> Self::NegatedConstraint {
         EqualConstraint {
             terms: self.terms.clone(),
+            weights: self.weights.clone(),
             rhs: self.rhs,
         }
     }
+
+    fn reify(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        let negation = self.negation();
+
+        // A single propagator handles both directions, such that the entailment of the
+        // disequality (e.g. when `rhs` is removed from the domain of the only term) sets the
+        // reification literal to true.
+        solver.add_propagator(ReifiedPropagator::with_negation(
+            LinearNotEqualPropagator::new(self.terms, self.weights, self.rhs),
+            LinearEqualPropagator::new(negation.scaled_terms(1), negation.rhs),
+            reification_literal,
+        ))
+    }
 }
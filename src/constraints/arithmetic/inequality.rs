@@ -1,7 +1,9 @@
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
 use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::propagators::ReifiedPropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
 
@@ -51,7 +53,7 @@ impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
     fn implied_by(
         self,
         solver: &mut Solver,
-        reification_literal: crate::variables::Literal,
+        reification_literal: Literal,
     ) -> Result<(), ConstraintOperationError> {
         LinearLessOrEqualPropagator::new(self.terms, self.rhs)
             .implied_by(solver, reification_literal)
@@ -67,4 +69,20 @@ impl<Var: IntegerVariable + 'static> NegatableConstraint for Inequality<Var> {
             rhs: -self.rhs - 1,
         }
     }
+
+    fn reify(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        let negation = self.negation();
+
+        // Both directions are handled by a single propagator, as the linear inequality detects its
+        // own inconsistency and entailment.
+        solver.add_propagator(ReifiedPropagator::with_negation(
+            LinearLessOrEqualPropagator::new(self.terms, self.rhs),
+            LinearLessOrEqualPropagator::new(negation.terms, negation.rhs),
+            reification_literal,
+        ))
+    }
 }
@@ -0,0 +1,125 @@
+use super::boolean_equals;
+use super::equals;
+use super::Constraint;
+use crate::predicate;
+use crate::propagators::bin_packing::BinPackingPropagator;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [bin-packing](https://sofdem.github.io/gccat/gccat/Cbin_packing_capa.html)
+/// [`Constraint`] which states that `loads[j]` is the sum of the sizes of the items `i` for which
+/// `bins[i] = j`.
+///
+/// Similar to [`super::inverse`], the bins are 1-indexed, meaning that the variables in `bins`
+/// take values in `1..=m` where `m` is the number of loads. The capacity of a bin is given by the
+/// upper bound of its load.
+pub fn bin_packing<Var: IntegerVariable + 'static, Load: IntegerVariable + 'static>(
+    bins: impl Into<Box<[Var]>>,
+    sizes: impl Into<Box<[u32]>>,
+    loads: impl Into<Box<[Load]>>,
+) -> impl Constraint {
+    let bins: Box<[Var::AffineView]> = bins.into().iter().map(|bin| bin.offset(-1)).collect();
+
+    BinPackingPropagator::new(bins, sizes.into(), loads.into())
+}
+
+/// Creates a [`Constraint`] equivalent to [`bin_packing`], but using a decomposition rather than
+/// a global propagator.
+///
+/// For every bin `j`, the load is constrained to be the sum of the sizes of the items weighted by
+/// the literals `[bins[i] = j]`.
+pub fn bin_packing_decomposition<Var: IntegerVariable + 'static>(
+    bins: impl Into<Box<[Var]>>,
+    sizes: impl Into<Box<[u32]>>,
+    loads: impl Into<Box<[AffineView<DomainId>]>>,
+) -> impl Constraint {
+    BinPackingDecomposition {
+        bins: bins.into(),
+        sizes: sizes.into(),
+        loads: loads.into(),
+    }
+}
+
+struct BinPackingDecomposition<Var> {
+    bins: Box<[Var]>,
+    sizes: Box<[u32]>,
+    loads: Box<[AffineView<DomainId>]>,
+}
+
+impl<Var: IntegerVariable + 'static> BinPackingDecomposition<Var> {
+    /// Posts the decomposition; if a `reification_literal` is given, the items only have to be
+    /// packed when it is true.
+    fn decompose(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        assert_eq!(
+            self.bins.len(),
+            self.sizes.len(),
+            "Every item of a bin-packing constraint should have a size"
+        );
+
+        let number_of_bins = self.loads.len() as i32;
+        let weights: Vec<i32> = self.sizes.iter().map(|&size| size as i32).collect();
+        let total_size: i32 = weights.iter().sum();
+
+        let not_reified = reification_literal.map(|literal| !literal);
+        for variable in self.bins.iter() {
+            solver.add_clause(
+                std::iter::once(solver.get_literal(predicate![variable >= 1])).chain(not_reified),
+            )?;
+            solver.add_clause(
+                std::iter::once(solver.get_literal(predicate![variable <= number_of_bins]))
+                    .chain(not_reified),
+            )?;
+        }
+
+        for (index, load) in self.loads.iter().enumerate() {
+            let bin = index as i32 + 1;
+
+            // Items without a size do not contribute to the load.
+            let (weights, is_packed): (Vec<i32>, Vec<Literal>) = self
+                .bins
+                .iter()
+                .zip(weights.iter())
+                .filter(|&(_, &weight)| weight > 0)
+                .map(|(variable, &weight)| {
+                    (weight, solver.get_literal(predicate![variable == bin]))
+                })
+                .unzip();
+
+            // `boolean_equals` requires a domain as its right-hand side. As this domain is fresh,
+            // it can always be defined, even if the decomposition is reified.
+            let bin_load = solver.new_bounded_integer(0, total_size);
+            boolean_equals(weights, is_packed, bin_load).post(solver)?;
+
+            let load_is_bin_load = equals([bin_load.scaled(1), load.scaled(-1)], 0);
+            match reification_literal {
+                Some(literal) => load_is_bin_load.implied_by(solver, literal)?,
+                None => load_is_bin_load.post(solver)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for BinPackingDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, None)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, Some(reification_literal))
+    }
+}
@@ -4,6 +4,7 @@ use super::Constraint;
 use crate::predicate;
 use crate::variables::AffineView;
 use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::variables::TransformableVariable;
 use crate::ConstraintOperationError;
@@ -35,6 +36,15 @@ pub fn boolean_equals(
     }
 }
 
+/// Creates the [`Constraint`] `integer = bool2int(literal)`; i.e. `integer` is 1 if `literal` is
+/// true and 0 otherwise.
+///
+/// The literal is channelled to the predicates `[integer >= 1]` and `[integer <= 0]` of the given
+/// variable, so no additional domain is created.
+pub fn bool2int<Var: IntegerVariable + 'static>(literal: Literal, integer: Var) -> impl Constraint {
+    BoolToInt { literal, integer }
+}
+
 struct BooleanLessThanOrEqual {
     weights: Box<[i32]>,
     bools: Box<[Literal]>,
@@ -131,3 +141,47 @@ impl BooleanEqual {
             .collect()
     }
 }
+
+struct BoolToInt<Var> {
+    literal: Literal,
+    integer: Var,
+}
+
+impl<Var: IntegerVariable> BoolToInt<Var> {
+    fn clauses(&self, solver: &Solver) -> [Vec<Literal>; 4] {
+        let integer = &self.integer;
+        let is_one = solver.get_literal(predicate![integer >= 1]);
+        let is_zero = solver.get_literal(predicate![integer <= 0]);
+
+        [
+            vec![solver.get_literal(predicate![integer >= 0])],
+            vec![solver.get_literal(predicate![integer <= 1])],
+            // literal -> [integer = 1]
+            vec![!self.literal, is_one],
+            // !literal -> [integer = 0]
+            vec![self.literal, is_zero],
+        ]
+    }
+}
+
+impl<Var: IntegerVariable> Constraint for BoolToInt<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.clauses(solver)
+            .into_iter()
+            .try_for_each(|clause| solver.add_clause(clause))
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.clauses(solver).into_iter().try_for_each(|clause| {
+            solver.add_clause(
+                clause
+                    .into_iter()
+                    .chain(std::iter::once(!reification_literal)),
+            )
+        })
+    }
+}
@@ -22,6 +22,29 @@ pub fn circuit(
 ) -> impl Constraint {
     DecomposedCircuit {
         successors: successor.into(),
+        predecessors: None,
+        sub_circuit_elimination,
+        use_all_different_decomposition,
+        use_element_decomposition,
+    }
+}
+
+/// Creates a [`Constraint`] equivalent to [`circuit`] over the `successors`, which additionally
+/// channels the `successors` to the `predecessors` through an [`constraints::inverse`]
+/// constraint; i.e. `successors[i] = j <-> predecessors[j] = i`.
+///
+/// Like the `successors`, the `predecessors` are 1-indexed.
+pub fn circuit_with_predecessors(
+    successors: impl Into<Box<[AffineView<DomainId>]>>,
+    predecessors: impl Into<Box<[AffineView<DomainId>]>>,
+    sub_circuit_elimination: SubCircuitElimination,
+    use_all_different_decomposition: bool,
+    use_element_decomposition: bool,
+    use_inverse_decomposition: bool,
+) -> impl Constraint {
+    DecomposedCircuit {
+        successors: successors.into(),
+        predecessors: Some((predecessors.into(), use_inverse_decomposition)),
         sub_circuit_elimination,
         use_all_different_decomposition,
         use_element_decomposition,
@@ -37,6 +60,9 @@ pub enum SubCircuitElimination {
 
 struct DecomposedCircuit {
     successors: Box<[AffineView<DomainId>]>,
+    /// The predecessor array, and whether the inverse constraint linking it to the successors
+    /// should be decomposed.
+    predecessors: Option<(Box<[AffineView<DomainId>]>, bool)>,
     sub_circuit_elimination: SubCircuitElimination,
     use_all_different_decomposition: bool,
     use_element_decomposition: bool,
@@ -46,6 +72,7 @@ impl Constraint for DecomposedCircuit {
     fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
         let DecomposedCircuit {
             successors,
+            predecessors,
             sub_circuit_elimination,
             use_all_different_decomposition,
             use_element_decomposition,
@@ -76,6 +103,18 @@ impl Constraint for DecomposedCircuit {
                 .post()?;
         }
 
+        if let Some((predecessors, use_inverse_decomposition)) = predecessors {
+            if use_inverse_decomposition {
+                solver
+                    .add_constraint(constraints::inverse_decomposition(successors, predecessors))
+                    .post()?;
+            } else {
+                solver
+                    .add_constraint(constraints::inverse(successors, predecessors))
+                    .post()?;
+            }
+        }
+
         Ok(())
     }
 
@@ -18,6 +18,24 @@ pub fn conjunction(literals: impl Into<Vec<Literal>>) -> impl NegatableConstrain
     Conjunction(literals.into())
 }
 
+/// Creates the [`Constraint`] `r <-> /\ literal`.
+pub fn array_bool_and(literals: impl Into<Vec<Literal>>, r: Literal) -> impl Constraint {
+    ReifiedArray {
+        literals: literals.into(),
+        r,
+        is_conjunction: true,
+    }
+}
+
+/// Creates the [`Constraint`] `r <-> \/ literal`.
+pub fn array_bool_or(literals: impl Into<Vec<Literal>>, r: Literal) -> impl Constraint {
+    ReifiedArray {
+        literals: literals.into(),
+        r,
+        is_conjunction: false,
+    }
+}
+
 struct Clause(Vec<Literal>);
 
 impl Constraint for Clause {
@@ -73,3 +91,62 @@ impl NegatableConstraint for Conjunction {
         Clause(self.0.iter().map(|&lit| !lit).collect())
     }
 }
+
+/// The equivalence between `r` and either the conjunction or the disjunction of `literals`.
+struct ReifiedArray {
+    literals: Vec<Literal>,
+    r: Literal,
+    is_conjunction: bool,
+}
+
+impl ReifiedArray {
+    /// The clauses which encode the equivalence. A disjunction is encoded as the negation of the
+    /// conjunction of the negated literals.
+    fn clauses(&self) -> Vec<Vec<Literal>> {
+        let (r, literals): (Literal, Vec<Literal>) = if self.is_conjunction {
+            (self.r, self.literals.clone())
+        } else {
+            (
+                !self.r,
+                self.literals.iter().map(|&literal| !literal).collect(),
+            )
+        };
+
+        // r -> literal
+        let mut clauses: Vec<Vec<Literal>> =
+            literals.iter().map(|&literal| vec![!r, literal]).collect();
+
+        // (/\ literal) -> r
+        clauses.push(
+            literals
+                .iter()
+                .map(|&literal| !literal)
+                .chain(std::iter::once(r))
+                .collect(),
+        );
+
+        clauses
+    }
+}
+
+impl Constraint for ReifiedArray {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.clauses()
+            .into_iter()
+            .try_for_each(|clause| solver.add_clause(clause))
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.clauses().into_iter().try_for_each(|clause| {
+            solver.add_clause(
+                clause
+                    .into_iter()
+                    .chain(std::iter::once(!reification_literal)),
+            )
+        })
+    }
+}
@@ -3,8 +3,10 @@ use std::fmt::Debug;
 use super::Constraint;
 use crate::constraints;
 use crate::propagators::cumulative::EnergeticReasoningPropagator;
+use crate::propagators::cumulative::OptionalTimeTablePropagator;
 use crate::propagators::cumulative::TimeTablePropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::IntervalVariable;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
@@ -35,6 +37,24 @@ pub fn cumulative<Var: IntegerVariable + 'static + Debug>(
     }
 }
 
+/// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) [`Constraint`]
+/// over (optional) [`IntervalVariable`]s. This constraint ensures that at no point in time, the
+/// cumulative resource usage of the present intervals exceeds `resource_capacity`; absent intervals
+/// do not use the resource.
+///
+/// Unlike [`cumulative`], the durations of the intervals do not need to be fixed.
+pub fn optional_cumulative(
+    intervals: impl Into<Box<[IntervalVariable]>>,
+    resource_requirements: impl Into<Box<[u32]>>,
+    resource_capacity: u32,
+) -> impl Constraint {
+    OptionalTimeTablePropagator::new(
+        intervals.into(),
+        resource_requirements.into(),
+        resource_capacity,
+    )
+}
+
 struct CumulativeConstraint<Var> {
     impl_strategy: CumulativeImpl,
     start_times: Box<[Var]>,
@@ -0,0 +1,217 @@
+use std::fmt::Debug;
+
+use super::cumulative;
+use super::less_than_or_equals;
+use super::Constraint;
+use super::CumulativeImpl;
+use crate::constraints;
+use crate::propagators::diffn::DiffnPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html) [`Constraint`] which
+/// ensures that no two rectangles overlap. Rectangle `i` has its origin at `(xs[i], ys[i])`, and
+/// has width `widths[i]` and height `heights[i]`. Rectangles with a width or height of 0 do not
+/// overlap with any other rectangle.
+pub fn diffn<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+    widths: impl Into<Box<[u32]>>,
+    heights: impl Into<Box<[u32]>>,
+) -> impl Constraint {
+    DiffnPropagator::new(xs.into(), ys.into(), widths.into(), heights.into())
+}
+
+/// Creates a [`Constraint`] equivalent to [`diffn`], but using a decomposition rather than a
+/// global propagator.
+///
+/// For every pair of rectangles, a literal is introduced for each of the four relative positions
+/// the rectangles can have, and at least one of them has to hold.
+pub fn diffn_decomposition<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+    widths: impl Into<Box<[u32]>>,
+    heights: impl Into<Box<[u32]>>,
+) -> impl Constraint {
+    DiffnDecomposition {
+        xs: xs.into(),
+        ys: ys.into(),
+        widths: widths.into(),
+        heights: heights.into(),
+    }
+}
+
+/// Creates the redundant [`Constraint`]s which project the rectangles of [`diffn`] onto both
+/// axes, which can be posted next to [`diffn`] to strengthen the propagation.
+///
+/// The projection onto the x-axis is a [`cumulative`] constraint in which every rectangle is a
+/// task starting at `xs[i]` with duration `widths[i]` and requirement `heights[i]`. The capacity
+/// is the height of the area in which the rectangles can be placed, which is determined by the
+/// bounds of the origins when the constraint is posted. The projection onto the y-axis is defined
+/// analogously.
+///
+/// Note that [`CumulativeImpl::Decomposition`] only considers the time points up to the sum of
+/// the durations, so with that implementation the rectangles should be placed in the area
+/// starting at the origin and spanning the sum of their widths and heights.
+pub fn diffn_cumulative_projections<Var: IntegerVariable + 'static + Debug>(
+    impl_strategy: CumulativeImpl,
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+    widths: impl Into<Box<[u32]>>,
+    heights: impl Into<Box<[u32]>>,
+) -> impl Constraint {
+    DiffnCumulativeProjections {
+        impl_strategy,
+        xs: xs.into(),
+        ys: ys.into(),
+        widths: widths.into(),
+        heights: heights.into(),
+    }
+}
+
+struct DiffnDecomposition<Var> {
+    xs: Box<[Var]>,
+    ys: Box<[Var]>,
+    widths: Box<[u32]>,
+    heights: Box<[u32]>,
+}
+
+impl<Var: IntegerVariable + 'static> DiffnDecomposition<Var> {
+    /// Creates for every pair of rectangles a literal for each of the ways in which they can be
+    /// separated, and returns the clauses which state that every pair has to be separated.
+    fn separation_clauses(
+        &self,
+        solver: &mut Solver,
+    ) -> Result<Vec<Vec<Literal>>, ConstraintOperationError> {
+        let has_area = |rectangle: usize| self.widths[rectangle] > 0 && self.heights[rectangle] > 0;
+
+        let mut clauses = Vec::new();
+        for i in 0..self.xs.len() {
+            for j in i + 1..self.xs.len() {
+                if !has_area(i) || !has_area(j) {
+                    continue;
+                }
+
+                let relations = [
+                    (&self.xs[i], &self.xs[j], self.widths[i]),
+                    (&self.xs[j], &self.xs[i], self.widths[j]),
+                    (&self.ys[i], &self.ys[j], self.heights[i]),
+                    (&self.ys[j], &self.ys[i], self.heights[j]),
+                ];
+
+                let mut literals = Vec::with_capacity(relations.len());
+                for (before, after, size) in relations {
+                    let literal = solver.new_literal();
+
+                    // literal -> before + size <= after
+                    solver
+                        .add_constraint(less_than_or_equals(
+                            [before.scaled(1), after.scaled(-1)],
+                            -(size as i32),
+                        ))
+                        .implied_by(literal)?;
+
+                    literals.push(literal);
+                }
+
+                clauses.push(literals);
+            }
+        }
+
+        Ok(clauses)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for DiffnDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        for literals in self.separation_clauses(solver)? {
+            solver
+                .add_constraint(constraints::clause(literals))
+                .post()?;
+        }
+
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        for literals in self.separation_clauses(solver)? {
+            solver
+                .add_constraint(constraints::clause(literals))
+                .implied_by(reification_literal)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct DiffnCumulativeProjections<Var> {
+    impl_strategy: CumulativeImpl,
+    xs: Box<[Var]>,
+    ys: Box<[Var]>,
+    widths: Box<[u32]>,
+    heights: Box<[u32]>,
+}
+
+/// The size of the area spanned by the rectangles in one dimension.
+fn span<Var: IntegerVariable>(solver: &Solver, origins: &[Var], sizes: &[u32]) -> u32 {
+    let start = origins
+        .iter()
+        .map(|origin| solver.lower_bound(origin))
+        .min();
+    let end = origins
+        .iter()
+        .zip(sizes)
+        .map(|(origin, &size)| solver.upper_bound(origin) + size as i32)
+        .max();
+
+    match (start, end) {
+        (Some(start), Some(end)) => (end - start).max(0) as u32,
+        _ => 0,
+    }
+}
+
+impl<Var: IntegerVariable + 'static + Debug> Constraint for DiffnCumulativeProjections<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        let DiffnCumulativeProjections {
+            impl_strategy,
+            xs,
+            ys,
+            widths,
+            heights,
+        } = self;
+
+        let height = span(solver, &ys, &heights);
+        let width = span(solver, &xs, &widths);
+
+        cumulative(impl_strategy, xs, widths.clone(), heights.clone(), height).post(solver)?;
+        cumulative(impl_strategy, ys, heights, widths, width).post(solver)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        let DiffnCumulativeProjections {
+            impl_strategy,
+            xs,
+            ys,
+            widths,
+            heights,
+        } = self;
+
+        let height = span(solver, &ys, &heights);
+        let width = span(solver, &xs, &widths);
+
+        cumulative(impl_strategy, xs, widths.clone(), heights.clone(), height)
+            .implied_by(solver, reification_literal)?;
+        cumulative(impl_strategy, ys, heights, widths, width)
+            .implied_by(solver, reification_literal)
+    }
+}
@@ -0,0 +1,117 @@
+use super::less_than_or_equals;
+use super::Constraint;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::propagators::disjunctive::OptionalDisjunctivePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::IntervalVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`], also known as the unary resource constraint. This constraint ensures that no
+/// two tasks are executed at the same time; i.e. for every pair of tasks `i` and `j`, either
+/// `s_i + p_i <= s_j` or `s_j + p_j <= s_i`.
+pub fn disjunctive<Var: IntegerVariable + 'static>(
+    start_times: impl Into<Box<[Var]>>,
+    durations: impl Into<Box<[u32]>>,
+) -> impl Constraint {
+    DisjunctivePropagator::new(start_times.into(), durations.into())
+}
+
+/// Creates the disjunctive [`Constraint`] over (optional) [`IntervalVariable`]s. This constraint
+/// ensures that no two present intervals are executed at the same time; absent intervals are
+/// ignored.
+///
+/// Unlike [`disjunctive`], the durations of the intervals do not need to be fixed.
+pub fn optional_disjunctive(intervals: impl Into<Box<[IntervalVariable]>>) -> impl Constraint {
+    OptionalDisjunctivePropagator::new(intervals.into())
+}
+
+/// Creates a [`Constraint`] equivalent to [`disjunctive`], but using a decomposition rather than a
+/// global propagator.
+///
+/// For every pair of tasks a literal is introduced which decides the order of the two tasks.
+pub fn disjunctive_decomposition<Var: IntegerVariable + 'static>(
+    start_times: impl Into<Box<[Var]>>,
+    durations: impl Into<Box<[u32]>>,
+) -> impl Constraint {
+    DisjunctiveDecomposition {
+        start_times: start_times.into(),
+        durations: durations.into(),
+    }
+}
+
+struct DisjunctiveDecomposition<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[u32]>,
+}
+
+impl<Var: IntegerVariable + 'static> DisjunctiveDecomposition<Var> {
+    /// Posts the decomposition; if a `reification_literal` is given, every pair of tasks only has
+    /// to be ordered when it is true.
+    fn decompose(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        for i in 0..self.start_times.len() {
+            for j in i + 1..self.start_times.len() {
+                if self.durations[i] == 0 || self.durations[j] == 0 {
+                    continue;
+                }
+
+                let i_before_j = solver.new_literal();
+                let j_before_i = match reification_literal {
+                    Some(reification_literal) => {
+                        let j_before_i = solver.new_literal();
+
+                        // reification_literal -> i_before_j \/ j_before_i
+                        solver.add_clause([!reification_literal, i_before_j, j_before_i])?;
+
+                        j_before_i
+                    }
+                    None => !i_before_j,
+                };
+
+                // i_before_j -> s_i + p_i <= s_j
+                solver
+                    .add_constraint(less_than_or_equals(
+                        [
+                            self.start_times[i].scaled(1),
+                            self.start_times[j].scaled(-1),
+                        ],
+                        -(self.durations[i] as i32),
+                    ))
+                    .implied_by(i_before_j)?;
+
+                // j_before_i -> s_j + p_j <= s_i
+                solver
+                    .add_constraint(less_than_or_equals(
+                        [
+                            self.start_times[j].scaled(1),
+                            self.start_times[i].scaled(-1),
+                        ],
+                        -(self.durations[j] as i32),
+                    ))
+                    .implied_by(j_before_i)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for DisjunctiveDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, None)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, Some(reification_literal))
+    }
+}
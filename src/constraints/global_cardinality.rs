@@ -0,0 +1,92 @@
+use super::among_decomposition;
+use super::Constraint;
+use crate::munchkin_assert_simple;
+use crate::propagators::counting::global_cardinality::GlobalCardinalityPropagator;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [global cardinality](https://sofdem.github.io/gccat/gccat/Cglobal_cardinality.html)
+/// [`Constraint`] which states that for every `k`, exactly `counts[k]` of the `variables` are
+/// equal to `values[k]`.
+///
+/// The `values` should be distinct. Variables are allowed to take values which are not in
+/// `values`.
+pub fn global_cardinality<Var: IntegerVariable + 'static, Count: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    values: impl Into<Box<[i32]>>,
+    counts: impl Into<Box<[Count]>>,
+) -> impl Constraint {
+    let values = values.into();
+    let counts = counts.into();
+    assert_distinct_values(&values, counts.len());
+
+    GlobalCardinalityPropagator::new(variables.into(), values, counts)
+}
+
+/// Creates a [`Constraint`] equivalent to [`global_cardinality`], but using a decomposition into
+/// an [`among_decomposition`] for every value rather than a global propagator.
+pub fn global_cardinality_decomposition<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    values: impl Into<Box<[i32]>>,
+    counts: impl Into<Box<[AffineView<DomainId>]>>,
+) -> impl Constraint {
+    let values = values.into();
+    let counts = counts.into();
+    assert_distinct_values(&values, counts.len());
+
+    GlobalCardinalityDecomposition {
+        variables: variables.into(),
+        values,
+        counts,
+    }
+}
+
+fn assert_distinct_values(values: &[i32], num_counts: usize) {
+    munchkin_assert_simple!(
+        values.len() == num_counts,
+        "There should be exactly one count for every value"
+    );
+    munchkin_assert_simple!(
+        values
+            .iter()
+            .enumerate()
+            .all(|(i, value)| !values[i + 1..].contains(value)),
+        "The values of a global cardinality constraint should be distinct"
+    );
+}
+
+struct GlobalCardinalityDecomposition<Var> {
+    variables: Box<[Var]>,
+    values: Box<[i32]>,
+    counts: Box<[AffineView<DomainId>]>,
+}
+
+impl<Var: IntegerVariable + 'static> GlobalCardinalityDecomposition<Var> {
+    fn decompose(self) -> Vec<impl Constraint> {
+        self.values
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(&value, count)| {
+                among_decomposition(self.variables.clone(), [value], count.clone())
+            })
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for GlobalCardinalityDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.decompose().post(solver)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.decompose().implied_by(solver, reification_literal)
+    }
+}
@@ -0,0 +1,101 @@
+use super::Constraint;
+use crate::predicate;
+use crate::propagators::inverse::InversePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [inverse](https://sofdem.github.io/gccat/gccat/Cinverse.html) [`Constraint`] which
+/// states that `f[i] = j <-> g[j] = i`.
+///
+/// Similar to [`super::circuit`] and [`super::element`], the arrays are 1-indexed, meaning the
+/// variables take values in `1..=n` where `n` is the length of the arrays. For example, `f` can be
+/// the successor array of a tour and `g` the corresponding predecessor array.
+pub fn inverse<Var: IntegerVariable + 'static>(
+    f: impl Into<Box<[Var]>>,
+    g: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    InversePropagator::new(to_zero_indexed(&f.into()), to_zero_indexed(&g.into()))
+}
+
+/// Creates a [`Constraint`] equivalent to [`inverse`], but using a decomposition rather than a
+/// global propagator.
+///
+/// The decomposition states `[f[i] = j] <-> [g[j] = i]` for every pair `i` and `j`, and is
+/// therefore quadratic in the length of the arrays.
+pub fn inverse_decomposition<Var: IntegerVariable + 'static>(
+    f: impl Into<Box<[Var]>>,
+    g: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    InverseDecomposition {
+        f: to_zero_indexed(&f.into()),
+        g: to_zero_indexed(&g.into()),
+    }
+}
+
+fn to_zero_indexed<Var: IntegerVariable>(array: &[Var]) -> Box<[Var::AffineView]> {
+    array.iter().map(|variable| variable.offset(-1)).collect()
+}
+
+struct InverseDecomposition<Var> {
+    f: Box<[Var]>,
+    g: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> InverseDecomposition<Var> {
+    /// The clauses which make up the decomposition.
+    fn clauses(&self, solver: &Solver) -> Vec<Vec<Literal>> {
+        assert_eq!(
+            self.f.len(),
+            self.g.len(),
+            "The arrays of an inverse constraint should have the same length"
+        );
+
+        let n = self.f.len() as i32;
+        let mut clauses = Vec::new();
+
+        for variable in self.f.iter().chain(self.g.iter()) {
+            clauses.push(vec![solver.get_literal(predicate![variable >= 0])]);
+            clauses.push(vec![solver.get_literal(predicate![variable <= n - 1])]);
+        }
+
+        for (i, f_i) in self.f.iter().enumerate() {
+            for (j, g_j) in self.g.iter().enumerate() {
+                let f_i_eq_j = solver.get_literal(predicate![f_i == j as i32]);
+                let g_j_eq_i = solver.get_literal(predicate![g_j == i as i32]);
+
+                clauses.push(vec![!f_i_eq_j, g_j_eq_i]);
+                clauses.push(vec![f_i_eq_j, !g_j_eq_i]);
+            }
+        }
+
+        clauses
+    }
+}
+
+impl<Var: IntegerVariable> Constraint for InverseDecomposition<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        for clause in self.clauses(solver) {
+            solver.add_clause(clause)?;
+        }
+
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        for clause in self.clauses(solver) {
+            solver.add_clause(
+                clause
+                    .into_iter()
+                    .chain(std::iter::once(!reification_literal)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use super::Constraint;
+use crate::propagators::lexicographic::LexicographicPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [lex_lesseq](https://sofdem.github.io/gccat/gccat/Clex_lesseq.html) [`Constraint`]
+/// which states that `xs` is lexicographically smaller than or equal to `ys`.
+///
+/// Together with [`lex_less`], this is commonly used to break symmetries between interchangeable
+/// arrays of variables, e.g. the rows of a matrix.
+pub fn lex_less_eq<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    LexicographicPropagator::new(xs.into(), ys.into(), false)
+}
+
+/// Creates the [lex_less](https://sofdem.github.io/gccat/gccat/Clex_less.html) [`Constraint`]
+/// which states that `xs` is lexicographically strictly smaller than `ys`.
+pub fn lex_less<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    LexicographicPropagator::new(xs.into(), ys.into(), true)
+}
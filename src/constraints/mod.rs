@@ -23,22 +23,44 @@
 //! propagator API is stabilized, it will become part of the public API.
 
 mod all_different;
+mod among;
 mod arithmetic;
+mod bin_packing;
 mod boolean;
 mod circuit;
 mod clause;
 mod constraint_poster;
 mod cumulative;
+mod diffn;
+mod disjunctive;
 mod element;
+mod global_cardinality;
+mod inverse;
+mod lexicographic;
+mod regular;
+mod subcircuit;
+mod value_precede_chain;
+mod xor;
 
 pub use all_different::*;
+pub use among::*;
 pub use arithmetic::*;
+pub use bin_packing::*;
 pub use boolean::*;
 pub use circuit::*;
 pub use clause::*;
 pub use constraint_poster::*;
 pub use cumulative::*;
+pub use diffn::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use global_cardinality::*;
+pub use inverse::*;
+pub use lexicographic::*;
+pub use regular::*;
+pub use subcircuit::*;
+pub use value_precede_chain::*;
+pub use xor::*;
 
 use crate::engine::cp::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
@@ -116,6 +138,10 @@ pub trait NegatableConstraint: Constraint {
     /// Add the reified version of the [`Constraint`] to the [`Solver`]; i.e. post the constraint
     /// `r <-> constraint` where `r` is a reification literal.
     ///
+    /// By default, this posts `r -> constraint` and `!r -> negation`. Constraints which are
+    /// implemented by a single propagator can instead post one reified propagator with the
+    /// negated propagator, which propagates `r` in both directions.
+    ///
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
     fn reify(
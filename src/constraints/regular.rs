@@ -0,0 +1,104 @@
+use super::Constraint;
+use crate::munchkin_assert_simple;
+use crate::propagators::regular::RegularPropagator;
+use crate::variables::IntegerVariable;
+
+/// A deterministic finite automaton over integer values, used by the [`regular`] constraint.
+///
+/// The states of the automaton are numbered `0..num_states`. Every transition is a triple
+/// `(from, value, to)`, meaning that reading `value` in state `from` moves the automaton to state
+/// `to`. A value for which there is no transition leads to an (implicit) failing state.
+#[derive(Clone, Debug)]
+pub struct Automaton {
+    initial_state: usize,
+    accepting_states: Box<[bool]>,
+    /// For every state, the outgoing transitions as `(value, to)` pairs, sorted by value.
+    transitions: Vec<Vec<(i32, usize)>>,
+}
+
+impl Automaton {
+    /// Create a new automaton with `num_states` states.
+    ///
+    /// Panics if a state is out of range, or if there are two transitions from the same state on
+    /// the same value (i.e. the automaton is not deterministic).
+    pub fn new(
+        num_states: usize,
+        initial_state: usize,
+        accepting_states: impl IntoIterator<Item = usize>,
+        transitions: impl IntoIterator<Item = (usize, i32, usize)>,
+    ) -> Automaton {
+        munchkin_assert_simple!(
+            initial_state < num_states,
+            "The initial state should be one of the states of the automaton"
+        );
+
+        let mut is_accepting = vec![false; num_states];
+        for state in accepting_states {
+            munchkin_assert_simple!(state < num_states, "Accepting state {state} does not exist");
+            is_accepting[state] = true;
+        }
+
+        let mut outgoing = vec![Vec::new(); num_states];
+        for (from, value, to) in transitions {
+            munchkin_assert_simple!(
+                from < num_states && to < num_states,
+                "Transition ({from}, {value}, {to}) refers to a state which does not exist"
+            );
+            outgoing[from].push((value, to));
+        }
+
+        for transitions in outgoing.iter_mut() {
+            transitions.sort_unstable();
+            munchkin_assert_simple!(
+                transitions.windows(2).all(|pair| pair[0].0 != pair[1].0),
+                "The automaton should be deterministic"
+            );
+        }
+
+        Automaton {
+            initial_state,
+            accepting_states: is_accepting.into(),
+            transitions: outgoing,
+        }
+    }
+
+    /// The number of states in the automaton.
+    pub fn num_states(&self) -> usize {
+        self.accepting_states.len()
+    }
+
+    /// The state in which the automaton starts.
+    pub fn initial_state(&self) -> usize {
+        self.initial_state
+    }
+
+    /// Whether `state` is an accepting state.
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.accepting_states[state]
+    }
+
+    /// The state reached when reading `value` in `state`, or [`None`] if there is no such
+    /// transition.
+    pub fn next_state(&self, state: usize, value: i32) -> Option<usize> {
+        let transitions = &self.transitions[state];
+
+        transitions
+            .binary_search_by_key(&value, |&(transition_value, _)| transition_value)
+            .ok()
+            .map(|index| transitions[index].1)
+    }
+
+    /// The outgoing transitions of `state` as `(value, to)` pairs.
+    pub(crate) fn transitions_from(&self, state: usize) -> &[(i32, usize)] {
+        &self.transitions[state]
+    }
+}
+
+/// Creates the [regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html) [`Constraint`]
+/// which states that the sequence of values taken by `variables` is accepted by `automaton`.
+pub fn regular<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    automaton: Automaton,
+) -> impl Constraint {
+    RegularPropagator::new(variables.into(), automaton)
+}
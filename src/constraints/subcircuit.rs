@@ -0,0 +1,192 @@
+use super::Constraint;
+use crate::constraints;
+use crate::predicate;
+use crate::propagators::circuit::SubCircuitPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [subcircuit](https://sofdem.github.io/gccat/gccat/Ccircuit.html) [`Constraint`],
+/// which states that the nodes `i` with `successors[i] != i` form a single cycle in which
+/// `successors[i]` is the node visited after `i`. A node with `successors[i] = i` is excluded from
+/// the circuit. If all nodes are excluded, the circuit is empty.
+///
+/// Similar to [`super::circuit`], the successors are 1-indexed, meaning the variables take values
+/// in `1..=n` where `n` is the number of nodes.
+pub fn subcircuit<Var: IntegerVariable + 'static>(
+    successors: impl Into<Box<[Var]>>,
+    use_all_different_decomposition: bool,
+) -> impl Constraint {
+    SubCircuit {
+        successors: successors.into(),
+        use_all_different_decomposition,
+        use_decomposition: false,
+    }
+}
+
+/// Creates a [`Constraint`] equivalent to [`subcircuit`], but using a decomposition rather than a
+/// global propagator.
+///
+/// Every node gets a position in the circuit. The included node with the smallest index is the
+/// first node of the circuit, and every other included node has the position of its predecessor
+/// plus one. As the positions increase along every cycle, every cycle has to contain the first
+/// node. The decomposition is quadratic in the number of nodes.
+pub fn subcircuit_decomposition<Var: IntegerVariable + 'static>(
+    successors: impl Into<Box<[Var]>>,
+    use_all_different_decomposition: bool,
+) -> impl Constraint {
+    SubCircuit {
+        successors: successors.into(),
+        use_all_different_decomposition,
+        use_decomposition: true,
+    }
+}
+
+struct SubCircuit<Var> {
+    successors: Box<[Var]>,
+    use_all_different_decomposition: bool,
+    use_decomposition: bool,
+}
+
+impl<Var: IntegerVariable + 'static> SubCircuit<Var> {
+    /// Posts the subcircuit constraint; if a `reification_literal` is given, the nodes only have
+    /// to form a subcircuit when it is true.
+    fn decompose(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        let successors: Box<[Var::AffineView]> = self
+            .successors
+            .iter()
+            .map(|successor| successor.offset(-1))
+            .collect();
+
+        if self.use_decomposition {
+            post_single_cycle_decomposition(solver, &successors, reification_literal)?;
+        } else {
+            post_or_imply(
+                SubCircuitPropagator::new(successors.clone()),
+                solver,
+                reification_literal,
+            )?;
+        }
+
+        if self.use_all_different_decomposition {
+            post_or_imply(
+                constraints::all_different_decomposition(successors),
+                solver,
+                reification_literal,
+            )
+        } else {
+            post_or_imply(
+                constraints::all_different(successors),
+                solver,
+                reification_literal,
+            )
+        }
+    }
+}
+
+/// Posts `constraint`, or `reification_literal -> constraint` if a reification literal is given.
+fn post_or_imply(
+    constraint: impl Constraint,
+    solver: &mut Solver,
+    reification_literal: Option<Literal>,
+) -> Result<(), ConstraintOperationError> {
+    match reification_literal {
+        Some(literal) => constraint.implied_by(solver, literal),
+        None => constraint.post(solver),
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for SubCircuit<Var> {
+    fn post(self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, None)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.decompose(solver, Some(reification_literal))
+    }
+}
+
+/// Posts the constraints which ensure that the included nodes form a single cycle, given the
+/// 0-indexed successors. If a `reification_literal` is given, the constraints are only enforced
+/// when it is true.
+fn post_single_cycle_decomposition<Var: IntegerVariable + 'static>(
+    solver: &mut Solver,
+    successors: &[Var],
+    reification_literal: Option<Literal>,
+) -> Result<(), ConstraintOperationError> {
+    let n = successors.len() as i32;
+    let not_reified = reification_literal.map(|literal| !literal);
+
+    for successor in successors.iter() {
+        solver.add_clause(
+            std::iter::once(solver.get_literal(predicate![successor >= 0])).chain(not_reified),
+        )?;
+        solver.add_clause(
+            std::iter::once(solver.get_literal(predicate![successor <= n - 1])).chain(not_reified),
+        )?;
+    }
+
+    let is_included: Vec<Literal> = successors
+        .iter()
+        .enumerate()
+        .map(|(node, successor)| solver.get_literal(predicate![successor != node as i32]))
+        .collect();
+
+    // is_first[i] <-> is_included[i] /\ !is_included[k] for all k < i
+    //
+    // This only defines the fresh literals in is_first, so it does not need to be reified.
+    let is_first: Vec<Literal> = (0..successors.len())
+        .map(|_| solver.new_literal())
+        .collect();
+    for node in 0..successors.len() {
+        solver.add_clause([!is_first[node], is_included[node]])?;
+        for &earlier in is_included[..node].iter() {
+            solver.add_clause([!is_first[node], !earlier])?;
+        }
+
+        let mut clause: Vec<Literal> = is_included[..node].to_vec();
+        clause.push(!is_included[node]);
+        clause.push(is_first[node]);
+        solver.add_clause(clause)?;
+    }
+
+    let positions: Vec<_> = (0..successors.len())
+        .map(|_| solver.new_bounded_integer(1, n))
+        .collect();
+
+    for (node, successor) in successors.iter().enumerate() {
+        for next in 0..successors.len() {
+            if next == node {
+                continue;
+            }
+
+            // [successor = next] /\ !is_first[next] -> position[next] = position[node] + 1
+            let is_consecutive = solver.new_literal();
+            let is_arc = solver.get_literal(predicate![successor == next as i32]);
+            solver.add_clause(
+                [!is_arc, is_first[next], is_consecutive]
+                    .into_iter()
+                    .chain(not_reified),
+            )?;
+
+            solver
+                .add_constraint(constraints::equals(
+                    [positions[next].scaled(1), positions[node].scaled(-1)],
+                    1,
+                ))
+                .implied_by(is_consecutive)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use super::Constraint;
+use crate::propagators::value_precede_chain::ValuePrecedeChainPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [precede](https://sofdem.github.io/gccat/gccat/Cprecede.html) [`Constraint`]
+/// which states that for every pair of consecutive values `s` and `t` in `values`, the first
+/// occurrence of `s` in `variables` is before the first occurrence of `t` (if `t` occurs at all).
+///
+/// This breaks the symmetry between interchangeable values; e.g. in graph colouring, where the
+/// colours can be permuted, `value_precede_chain([1, 2, ..., k], colours)` ensures that the colours
+/// are used in increasing order.
+pub fn value_precede_chain<Var: IntegerVariable + 'static>(
+    values: impl Into<Box<[i32]>>,
+    variables: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    ValuePrecedeChainPropagator::new(values.into(), variables.into())
+}
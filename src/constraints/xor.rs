@@ -0,0 +1,18 @@
+use super::Constraint;
+use crate::propagators::xor::XorPropagator;
+use crate::variables::Literal;
+
+/// Creates the [`Constraint`] which states that the number of true `literals` is odd if `parity`
+/// is true, and even otherwise; i.e. `literals[0] xor ... xor literals[n - 1] = parity`.
+pub fn xor(literals: impl Into<Vec<Literal>>, parity: bool) -> impl Constraint {
+    XorPropagator::new([(literals.into(), parity)])
+}
+
+/// Creates the [`Constraint`] which states that every `(literals, parity)` in `system` holds as an
+/// [`xor`] constraint.
+///
+/// Unlike posting the [`xor`] constraints separately, the system is propagated as a whole through
+/// Gaussian elimination, which detects implications that follow from combining the equations.
+pub fn xor_system(system: impl IntoIterator<Item = (Vec<Literal>, bool)>) -> impl Constraint {
+    XorPropagator::new(system)
+}
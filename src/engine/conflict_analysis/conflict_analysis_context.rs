@@ -151,7 +151,7 @@ impl ConflictAnalysisContext<'_> {
             .get_variable_reason_constraint(propagated_literal.get_propositional_variable());
 
         // Case 1: the literal was propagated by the clausal propagator
-        if constraint_reference.is_clause() {
+        let clause_reference = if constraint_reference.is_clause() {
             self.clausal_propagator
                 .get_literal_propagation_clause_reference(
                     propagated_literal,
@@ -165,7 +165,10 @@ impl ConflictAnalysisContext<'_> {
                 propagated_literal,
                 constraint_reference.get_reason_ref(),
             )
-        }
+        };
+
+        self.notify_brancher_of_clause(clause_reference);
+        clause_reference
     }
 
     /// Returns a clause reference of the clause that explains the current conflict in the solver.
@@ -174,9 +177,8 @@ impl ConflictAnalysisContext<'_> {
     /// constructed based on the explanation given by the propagator.
     ///
     /// Note that the solver will panic in case the solver is not in conflicting state.
-    #[allow(unused, reason = "will be used in an assignment")]
     pub(crate) fn get_conflict_reason_clause_reference(&mut self) -> ClauseReference {
-        match self.solver_state.get_conflict_info() {
+        let clause_reference = match self.solver_state.get_conflict_info() {
             StoredConflictInfo::VirtualBinaryClause { lit1, lit2 } => self
                 .explanation_clause_manager
                 .add_explanation_clause_unchecked(vec![*lit1, *lit2], self.clause_allocator),
@@ -212,9 +214,32 @@ impl ConflictAnalysisContext<'_> {
                     })
                     .collect();
 
+                conjunction
+                    .iter()
+                    .filter_map(|predicate| predicate.get_domain())
+                    .for_each(|domain| self.brancher.on_appearance_in_conflict_integer(domain));
+
                 self.explanation_clause_manager
                     .add_explanation_clause_unchecked(explanation_literals, self.clause_allocator)
             }
+        };
+
+        self.notify_brancher_of_clause(clause_reference);
+        clause_reference
+    }
+
+    /// Informs the brancher that the literals of the clause appear in the conflict which is being
+    /// analysed.
+    fn notify_brancher_of_clause(&mut self, clause_reference: ClauseReference) {
+        if clause_reference.is_virtual_binary_clause() {
+            self.brancher.on_appearance_in_conflict_literal(
+                clause_reference.get_virtual_binary_clause_literal(),
+            );
+            return;
+        }
+
+        for &literal in self.clause_allocator[clause_reference].get_literal_slice() {
+            self.brancher.on_appearance_in_conflict_literal(literal);
         }
     }
 
@@ -227,13 +252,22 @@ impl ConflictAnalysisContext<'_> {
     ) -> ClauseReference {
         let propagation_context =
             PropagationContext::new(self.assignments_integer, self.assignments_propositional);
+        let propagator = self.reason_store.get_propagator(reason_ref);
         let reason = self
             .reason_store
             .get_or_compute(reason_ref, &propagation_context)
             .expect("reason reference should not be stale");
+        self.counters.propagator_statistics[propagator]
+            .average_explanation_size
+            .add_term(reason.num_predicates() as u64);
         // create the explanation clause
         //  allocate a fresh vector each time might be a performance bottleneck
         //  todo better ways
+        reason
+            .iter()
+            .filter_map(|predicate| predicate.get_domain())
+            .for_each(|domain| self.brancher.on_appearance_in_conflict_integer(domain));
+
         // important to keep propagated literal at the zero-th position
         let explanation_literals: Vec<Literal> = std::iter::once(propagated_literal)
             .chain(reason.iter().map(|&predicate| {
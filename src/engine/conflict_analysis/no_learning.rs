@@ -5,8 +5,15 @@ use super::ConflictResolver;
 pub(crate) struct NoLearning;
 
 impl ConflictResolver for NoLearning {
-    fn resolve_conflict(&mut self, _context: &mut ConflictAnalysisContext) {
-        // In the case of no learning, this method does not do anything
+    fn resolve_conflict(&mut self, context: &mut ConflictAnalysisContext) {
+        // In the case of no learning, the conflict is only explained if the brancher should be
+        // informed of the literals which appear in it
+        if context.brancher.uses_conflict_appearances() {
+            let _ = context.get_conflict_reason_clause_reference();
+            context
+                .explanation_clause_manager
+                .clean_up_explanation_clauses(context.clause_allocator);
+        }
     }
 
     fn process(&mut self, context: &mut ConflictAnalysisContext) -> Result<(), ()> {
@@ -2,8 +2,10 @@
 //! using a Lazy Clause Generation approach.
 
 use std::cmp::min;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::time::Duration;
 use std::time::Instant;
 
 use rand::rngs::SmallRng;
@@ -29,6 +31,7 @@ use crate::basic_types::StoredConflictInfo;
 use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 use crate::engine::conflict_analysis::ConflictAnalysisContext;
+use crate::engine::cp::propagation::propagator::NUM_PROPAGATOR_PRIORITIES;
 use crate::engine::cp::propagation::PropagationContextMut;
 use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorId;
@@ -191,6 +194,15 @@ pub struct SatisfactionSolverOptions {
 
     /// The strategy to use when the solver reaches a conflicting state.
     pub conflict_resolver: ConflictResolutionStrategy,
+
+    /// Whether the statistics of the propagators (see [`Solver::log_statistics`]) are logged as a
+    /// single JSON object rather than as a separate statistic per propagator and counter.
+    pub log_propagator_statistics_as_json: bool,
+
+    /// Whether the time spent in every propagator is measured and logged with the statistics of
+    /// the propagators. Measuring the time adds overhead to every call of a propagator, which is
+    /// why it is disabled by default.
+    pub measure_propagator_time: bool,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -213,6 +225,8 @@ impl Default for SatisfactionSolverOptions {
         SatisfactionSolverOptions {
             random_generator: SmallRng::seed_from_u64(42),
             conflict_resolver: ConflictResolutionStrategy::NoLearning,
+            log_propagator_statistics_as_json: false,
+            measure_propagator_time: false,
         }
     }
 }
@@ -236,8 +250,9 @@ impl ConstraintSatisfactionSolver {
 
             for (event, domain) in self.event_drain.drain(..) {
                 for propagator_var in self.watch_list_cp.get_affected_propagators(event, domain) {
+                    let propagator = propagator_var.propagator;
                     self.propagator_queue
-                        .enqueue_propagator(propagator_var.propagator, 0);
+                        .enqueue_propagator(propagator, self.cp_propagators[propagator].priority());
                 }
             }
         }
@@ -253,8 +268,11 @@ impl ConstraintSatisfactionSolver {
                         .watch_list_propositional
                         .get_affected_propagators(event, affected_literal)
                     {
-                        self.propagator_queue
-                            .enqueue_propagator(propagator_var.propagator, 0);
+                        let propagator = propagator_var.propagator;
+                        self.propagator_queue.enqueue_propagator(
+                            propagator,
+                            self.cp_propagators[propagator].priority(),
+                        );
                     }
                 }
             }
@@ -309,7 +327,7 @@ impl ConstraintSatisfactionSolver {
             assignments_integer: AssignmentsInteger::default(),
             watch_list_cp: WatchListCP::default(),
             watch_list_propositional: WatchListPropositional::default(),
-            propagator_queue: PropagatorQueue::new(5),
+            propagator_queue: PropagatorQueue::new(NUM_PROPAGATOR_PRIORITIES),
             reason_store: ReasonStore::default(),
             propositional_trail_index: 0,
             event_drain: vec![],
@@ -375,7 +393,7 @@ impl ConstraintSatisfactionSolver {
     }
 
     pub fn log_statistics(&self) {
-        self.counters.log_statistics()
+        self.counters.log_statistics(&self.internal_parameters)
     }
 
     /// Create a new integer variable. Its domain will have the given lower and upper bounds.
@@ -678,9 +696,26 @@ impl ConstraintSatisfactionSolver {
         termination: &mut impl TerminationCondition,
         brancher: &mut impl Brancher,
     ) -> CSPSolverExecutionFlag {
+        // Whether the last enqueued decision was made by the brancher, in which case the brancher is
+        // notified once the decision has been propagated.
+        let mut is_propagating_decision = false;
+
         loop {
             self.propagate_enqueued(termination);
 
+            if is_propagating_decision {
+                is_propagating_decision = false;
+                brancher.on_decision_propagated(
+                    &SelectionContext::new(
+                        &self.assignments_integer,
+                        &self.assignments_propositional,
+                        &self.watch_list_cp,
+                        &mut self.internal_parameters.random_generator,
+                    ),
+                    self.state.conflicting(),
+                );
+            }
+
             if termination.should_stop() {
                 self.state.declare_timeout();
                 return CSPSolverExecutionFlag::Timeout;
@@ -689,10 +724,15 @@ impl ConstraintSatisfactionSolver {
             if self.state.no_conflict() {
                 self.declare_new_decision_level();
 
+                let is_brancher_decision = self.peek_next_assumption_literal().is_none();
                 let branching_result = self.enqueue_next_decision(brancher);
                 if let Err(flag) = branching_result {
                     return flag;
                 }
+                if is_brancher_decision {
+                    termination.decision_has_been_made();
+                }
+                is_propagating_decision = is_brancher_decision;
             } else {
                 // Conflict has occured
 
@@ -702,6 +742,10 @@ impl ConstraintSatisfactionSolver {
                     return CSPSolverExecutionFlag::Infeasible;
                 }
 
+                if let Some(propagator) = self.state.get_conflicting_propagator() {
+                    brancher.on_propagator_conflict(propagator);
+                }
+
                 // Otherwise we resolve the conflict (and potentially learn a new clause)
                 self.resolve_conflict(brancher);
 
@@ -728,6 +772,7 @@ impl ConstraintSatisfactionSolver {
             let decided_predicate = brancher.next_decision(&mut SelectionContext::new(
                 &self.assignments_integer,
                 &self.assignments_propositional,
+                &self.watch_list_cp,
                 &mut self.internal_parameters.random_generator,
             ));
             if let Some(predicate) = decided_predicate {
@@ -905,6 +950,9 @@ impl ConstraintSatisfactionSolver {
     /// Main propagation loop.
     pub(crate) fn propagate_enqueued(&mut self, termination: &mut impl TerminationCondition) {
         let num_assigned_variables_old = self.assignments_integer.num_trail_entries();
+        // The propagator which emptied a domain in the previous step, if any; it is the cause of
+        // the conflict which is subsequently found in the propositional representation.
+        let mut emptying_propagator = None;
 
         loop {
             if termination.should_stop() {
@@ -916,7 +964,7 @@ impl ConstraintSatisfactionSolver {
             if let Some(conflict_info) = conflict_info {
                 // The previous propagation triggered an empty domain.
                 self.state
-                    .declare_conflict(conflict_info.try_into().unwrap());
+                    .declare_conflict(conflict_info.try_into().unwrap(), emptying_propagator);
                 break;
             }
 
@@ -927,7 +975,7 @@ impl ConstraintSatisfactionSolver {
 
             if let Err(conflict_info) = clausal_propagation_status {
                 self.state
-                    .declare_conflict(conflict_info.try_into().unwrap());
+                    .declare_conflict(conflict_info.try_into().unwrap(), emptying_propagator);
                 break;
             }
 
@@ -937,7 +985,12 @@ impl ConstraintSatisfactionSolver {
             // ask propagators to propagate
             let propagation_status_one_step_cp = self.propagate_cp_one_step();
 
+            emptying_propagator = None;
+
             match propagation_status_one_step_cp {
+                PropagationStatusOneStepCP::EmptyDomain { propagator } => {
+                    emptying_propagator = Some(propagator);
+                }
                 PropagationStatusOneStepCP::PropagationHappened => {
                     // do nothing, the result will be that the clausal propagator will go next
                     //  recall that the idea is to always propagate simpler propagators before more
@@ -947,7 +1000,10 @@ impl ConstraintSatisfactionSolver {
                 PropagationStatusOneStepCP::FixedPoint => {
                     break;
                 }
-                PropagationStatusOneStepCP::ConflictDetected { conflict_info } => {
+                PropagationStatusOneStepCP::ConflictDetected {
+                    conflict_info,
+                    propagator,
+                } => {
                     let result = self.synchronise_propositional_trail_based_on_integer_trail();
 
                     // If the clausal propagator found a conflict during synchronisation then we
@@ -962,6 +1018,7 @@ impl ConstraintSatisfactionSolver {
                                     .expect("this is not a ConflictInfo::Explanation")
                             })
                             .unwrap_or(conflict_info),
+                        Some(propagator),
                     );
                     break;
                 }
@@ -969,9 +1026,14 @@ impl ConstraintSatisfactionSolver {
         }
 
         self.counters.num_conflicts += self.state.conflicting() as u64;
+        if self.state.conflicting() {
+            termination.conflict_has_been_found();
+        }
 
-        self.counters.num_propagations +=
+        let num_propagations =
             self.assignments_integer.num_trail_entries() as u64 - num_assigned_variables_old as u64;
+        self.counters.num_propagations += num_propagations;
+        termination.propagations_have_been_made(num_propagations);
 
         // Only check fixed point propagation if there was no reported conflict.
         munchkin_assert_extreme!(
@@ -997,20 +1059,54 @@ impl ConstraintSatisfactionSolver {
 
         let propagator_id = self.propagator_queue.pop();
         let propagator = &mut self.cp_propagators[propagator_id.0 as usize];
+        let priority = propagator.priority() as usize;
+        self.counters.num_propagator_calls_per_priority[priority] += 1;
+
+        let num_trail_entries_before = self.assignments_integer.num_trail_entries()
+            + self.assignments_propositional.num_trail_entries();
+        let start_time = self
+            .internal_parameters
+            .measure_propagator_time
+            .then(Instant::now);
+
         let context = PropagationContextMut::new(
             &mut self.assignments_integer,
             &mut self.reason_store,
             &mut self.assignments_propositional,
             propagator_id,
         );
+        let propagation_status = propagator.propagate(context);
+
+        let statistics = &mut self.counters.propagator_statistics[propagator_id];
+        statistics.num_calls += 1;
+        if let Some(start_time) = start_time {
+            statistics.time_spent += start_time.elapsed();
+        }
+        statistics.num_domain_reductions += (self.assignments_integer.num_trail_entries()
+            + self.assignments_propositional.num_trail_entries()
+            - num_trail_entries_before) as u64;
 
-        match propagator.propagate(context) {
+        match propagation_status {
             // An empty domain conflict will be caught by the clausal propagator.
-            Err(Inconsistency::EmptyDomain) => PropagationStatusOneStepCP::PropagationHappened,
+            Err(Inconsistency::EmptyDomain) => {
+                self.counters.num_conflicts_per_priority[priority] += 1;
+                self.counters.propagator_statistics[propagator_id].num_conflicts += 1;
+                PropagationStatusOneStepCP::EmptyDomain {
+                    propagator: propagator_id,
+                }
+            }
 
             // A propagator-specific reason for the current conflict.
             Err(Inconsistency::Other(conflict_info)) => {
+                self.counters.num_conflicts_per_priority[priority] += 1;
+                let statistics = &mut self.counters.propagator_statistics[propagator_id];
+                statistics.num_conflicts += 1;
+
                 if let ConflictInfo::Explanation(ref propositional_conjunction) = conflict_info {
+                    statistics
+                        .average_explanation_size
+                        .add_term(propositional_conjunction.num_predicates() as u64);
+
                     munchkin_assert_advanced!(DebugHelper::debug_reported_failure(
                         &self.assignments_integer,
                         &self.assignments_propositional,
@@ -1023,6 +1119,7 @@ impl ConstraintSatisfactionSolver {
 
                 PropagationStatusOneStepCP::ConflictDetected {
                     conflict_info: conflict_info.into_stored(propagator_id),
+                    propagator: propagator_id,
                 }
             }
 
@@ -1100,8 +1197,17 @@ impl ConstraintSatisfactionSolver {
             return Err(ConstraintOperationError::InfeasiblePropagator);
         }
 
+        munchkin_assert_simple!(
+            propagator_to_add.priority() < NUM_PROPAGATOR_PRIORITIES,
+            "The priority of propagator {} is out of range",
+            propagator_to_add.name()
+        );
+
         let new_propagator_id = PropagatorId(self.cp_propagators.len() as u32);
 
+        self.counters
+            .propagator_statistics
+            .push(PropagatorStatistics::new(propagator_to_add.name()));
         self.cp_propagators.push(Box::new(propagator_to_add));
 
         let new_propagator = &mut self.cp_propagators[new_propagator_id];
@@ -1121,7 +1227,7 @@ impl ConstraintSatisfactionSolver {
             Err(ConstraintOperationError::InfeasiblePropagator)
         } else {
             self.propagator_queue
-                .enqueue_propagator(new_propagator_id, 0);
+                .enqueue_propagator(new_propagator_id, new_propagator.priority());
 
             self.propagate_enqueued(&mut Indefinite);
 
@@ -1203,6 +1309,12 @@ impl CumulativeMovingAverage {
         self.num_terms += 1
     }
 
+    /// Adds all of the terms of `other` to this average.
+    fn merge(&mut self, other: CumulativeMovingAverage) {
+        self.sum += other.sum;
+        self.num_terms += other.num_terms;
+    }
+
     pub(crate) fn value(&self) -> f64 {
         if self.num_terms > 0 {
             (self.sum as f64) / (self.num_terms as f64)
@@ -1214,7 +1326,7 @@ impl CumulativeMovingAverage {
 
 /// Structure responsible for storing several statistics of the solving process of the
 /// [`ConstraintSatisfactionSolver`].
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Clone)]
 pub(crate) struct Counters {
     pub(crate) num_decisions: u64,
     pub(crate) num_conflicts: u64,
@@ -1224,10 +1336,16 @@ pub(crate) struct Counters {
     average_learned_clause_length: CumulativeMovingAverage,
     time_spent_in_solver: u64,
     average_backtrack_amount: CumulativeMovingAverage,
+    /// The number of times a propagator with a given priority was called.
+    num_propagator_calls_per_priority: [u64; NUM_PROPAGATOR_PRIORITIES as usize],
+    /// The number of conflicts found by a propagator with a given priority.
+    num_conflicts_per_priority: [u64; NUM_PROPAGATOR_PRIORITIES as usize],
+    /// The statistics of every propagator, indexed by [`PropagatorId`].
+    pub(crate) propagator_statistics: Vec<PropagatorStatistics>,
 }
 
 impl Counters {
-    fn log_statistics(&self) {
+    fn log_statistics(&self, options: &SatisfactionSolverOptions) {
         log_statistic("numberOfDecisions", self.num_decisions);
         log_statistic("numberOfConflicts", self.num_conflicts);
         log_statistic(
@@ -1245,6 +1363,115 @@ impl Counters {
             "averageBacktrackAmount",
             self.average_backtrack_amount.value(),
         );
+
+        for priority in 0..NUM_PROPAGATOR_PRIORITIES as usize {
+            log_statistic(
+                format!("numberOfPropagatorCallsWithPriority{priority}"),
+                self.num_propagator_calls_per_priority[priority],
+            );
+            log_statistic(
+                format!("numberOfConflictsWithPriority{priority}"),
+                self.num_conflicts_per_priority[priority],
+            );
+        }
+
+        self.log_propagator_statistics(options);
+    }
+
+    /// Logs the statistics of the propagators, where the statistics of propagators with the same
+    /// name are combined. The time spent in the propagators is only logged if it is measured (see
+    /// [`SatisfactionSolverOptions::measure_propagator_time`]).
+    fn log_propagator_statistics(&self, options: &SatisfactionSolverOptions) {
+        let mut statistics_per_name: BTreeMap<&str, PropagatorStatistics> = BTreeMap::new();
+        for statistics in self.propagator_statistics.iter() {
+            statistics_per_name
+                .entry(&statistics.name)
+                .or_insert_with(|| PropagatorStatistics::new(&statistics.name))
+                .merge(statistics);
+        }
+
+        if options.log_propagator_statistics_as_json {
+            let json = statistics_per_name
+                .values()
+                .map(|statistics| statistics.to_json(options.measure_propagator_time))
+                .collect::<Vec<_>>();
+            log_statistic("propagatorStatistics", serde_json::Value::Array(json));
+            return;
+        }
+
+        for (name, statistics) in statistics_per_name {
+            log_statistic(format!("{name}_numberOfCalls"), statistics.num_calls);
+            if options.measure_propagator_time {
+                log_statistic(
+                    format!("{name}_timeSpentInMilliseconds"),
+                    statistics.time_spent.as_millis(),
+                );
+            }
+            log_statistic(
+                format!("{name}_numberOfDomainReductions"),
+                statistics.num_domain_reductions,
+            );
+            log_statistic(
+                format!("{name}_numberOfConflicts"),
+                statistics.num_conflicts,
+            );
+            log_statistic(
+                format!("{name}_averageExplanationSize"),
+                statistics.average_explanation_size.value(),
+            );
+        }
+    }
+}
+
+/// Structure responsible for storing the statistics of a single propagator (or of all propagators
+/// with the same name).
+#[derive(Default, Debug, Clone)]
+pub(crate) struct PropagatorStatistics {
+    /// The name of the propagator; see [`Propagator::name`].
+    name: String,
+    num_calls: u64,
+    time_spent: Duration,
+    /// The number of changes made to the domains of variables (including literals).
+    num_domain_reductions: u64,
+    num_conflicts: u64,
+    /// The average size of the conflict explanations and the propagation reasons which are
+    /// computed during conflict analysis.
+    pub(crate) average_explanation_size: CumulativeMovingAverage,
+}
+
+impl PropagatorStatistics {
+    fn new(name: &str) -> Self {
+        PropagatorStatistics {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn merge(&mut self, other: &PropagatorStatistics) {
+        self.num_calls += other.num_calls;
+        self.time_spent += other.time_spent;
+        self.num_domain_reductions += other.num_domain_reductions;
+        self.num_conflicts += other.num_conflicts;
+        self.average_explanation_size
+            .merge(other.average_explanation_size);
+    }
+
+    /// Converts the statistics to a JSON object; the time spent is only included if
+    /// `include_time_spent` is `true`.
+    fn to_json(&self, include_time_spent: bool) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "name": self.name,
+            "numberOfCalls": self.num_calls,
+            "numberOfDomainReductions": self.num_domain_reductions,
+            "numberOfConflicts": self.num_conflicts,
+            "averageExplanationSize": self.average_explanation_size.value(),
+        });
+
+        if include_time_spent {
+            json["timeSpentInMilliseconds"] = serde_json::json!(self.time_spent.as_millis());
+        }
+
+        json
     }
 }
 
@@ -1257,6 +1484,8 @@ enum CSPSolverStateInternal {
     Conflict {
         #[allow(unused)]
         conflict_info: StoredConflictInfo,
+        /// The propagator which caused the conflict, if the conflict was caused by a propagator.
+        propagator: Option<PropagatorId>,
     },
     Infeasible,
     InfeasibleUnderAssumptions {
@@ -1281,10 +1510,7 @@ impl CSPSolverState {
     }
 
     pub(crate) fn conflicting(&self) -> bool {
-        matches!(
-            self.internal_state,
-            CSPSolverStateInternal::Conflict { conflict_info: _ }
-        )
+        matches!(self.internal_state, CSPSolverStateInternal::Conflict { .. })
         // self.is_clausal_conflict() || self.is_cp_conflict()
     }
 
@@ -1324,13 +1550,23 @@ impl CSPSolverState {
 
     #[allow(unused)]
     pub(crate) fn get_conflict_info(&self) -> &StoredConflictInfo {
-        if let CSPSolverStateInternal::Conflict { conflict_info } = &self.internal_state {
+        if let CSPSolverStateInternal::Conflict { conflict_info, .. } = &self.internal_state {
             conflict_info
         } else {
             panic!("Cannot extract conflict clause if solver is not in a clausal conflict.");
         }
     }
 
+    /// Returns the propagator which caused the current conflict, or [`None`] if the solver is not
+    /// in a conflict or the conflict was not caused by a propagator.
+    pub(crate) fn get_conflicting_propagator(&self) -> Option<PropagatorId> {
+        if let CSPSolverStateInternal::Conflict { propagator, .. } = &self.internal_state {
+            *propagator
+        } else {
+            None
+        }
+    }
+
     #[allow(unused)]
     pub(crate) fn timeout(&self) -> bool {
         matches!(self.internal_state, CSPSolverStateInternal::Timeout)
@@ -1357,9 +1593,16 @@ impl CSPSolverState {
         self.internal_state = CSPSolverStateInternal::Infeasible;
     }
 
-    fn declare_conflict(&mut self, conflict_info: StoredConflictInfo) {
+    fn declare_conflict(
+        &mut self,
+        conflict_info: StoredConflictInfo,
+        propagator: Option<PropagatorId>,
+    ) {
         munchkin_assert_simple!(!self.conflicting());
-        self.internal_state = CSPSolverStateInternal::Conflict { conflict_info };
+        self.internal_state = CSPSolverStateInternal::Conflict {
+            conflict_info,
+            propagator,
+        };
     }
 
     fn declare_solution_found(&mut self) {
@@ -1383,8 +1626,10 @@ impl CSPSolverState {
 #[cfg(test)]
 mod tests {
     use super::ConstraintSatisfactionSolver;
+    use crate::engine::cp::propagation::PropagatorId;
     use crate::engine::cp::reason::ReasonRef;
     use crate::predicate;
+    use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
 
     #[test]
     fn negative_upper_bound() {
@@ -1463,6 +1708,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn propagator_statistics_are_kept_per_propagator() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(LinearLessOrEqualPropagator::new([x, y].into(), 5));
+        assert!(result.is_ok());
+
+        let statistics = &solver.counters.propagator_statistics[PropagatorId(0)];
+        assert_eq!(statistics.name, "LinearLeq");
+        assert!(statistics.num_calls >= 1);
+        // The upper bounds of both variables are tightened from 10 to 5.
+        assert!(statistics.num_domain_reductions >= 2);
+        assert_eq!(statistics.num_conflicts, 0);
+    }
+
     #[test]
     fn clausal_propagation_is_synced_until_right_before_conflict() {
         let mut solver = ConstraintSatisfactionSolver::default();
@@ -60,6 +60,10 @@ impl AssignmentsInteger {
         *self.trail.last().unwrap()
     }
 
+    pub fn get_entries_at_current_decision_level(&self) -> &[ConstraintProgrammingTrailEntry] {
+        self.trail.get_current_decision_level_entries()
+    }
+
     pub fn get_last_predicates_on_trail(
         &self,
         num_predicates: usize,
@@ -60,7 +60,7 @@
 //! 5. Implement the remaining functions, i.e., [`Propagator::propagate`],
 //!    [`Propagator::synchronise`], and [`Propagator::initialise_at_root`]. These are all
 //!    interdependent.
-//! 6. Decide on the priortiy of the propagator, i.e., implement [`Propagator::priority`].
+//! 6. Decide on the priority of the propagator, i.e., implement [`Propagator::priority`].
 //! 7. Make sure to write new tests and run all tests throughout the process.
 //! 8. The propagator implementation is now done!
 //!
@@ -14,6 +14,9 @@ use crate::engine::sat::ClausalPropagator;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::predicates::PropositionalConjunction;
 
+/// The number of priority levels of propagators; see [`Propagator::priority`].
+pub(crate) const NUM_PROPAGATOR_PRIORITIES: u32 = 5;
+
 /// All propagators implement the [`Propagator`] trait, with the exception of the
 /// clausal propagator. Structs implementing the trait defines the main propagator logic with
 /// regards to propagation, detecting conflicts, and providing explanations.
@@ -74,4 +77,28 @@ pub trait Propagator {
     ) -> Option<PropositionalConjunction> {
         None
     }
+
+    /// A check whether the constraint of this propagator is entailed; i.e. whether it is
+    /// satisfied by every assignment which is still possible. If so, the returned conjunction
+    /// explains why.
+    ///
+    /// By implementing this function, if the propagator is fully reified, it can propagate the
+    /// reification literal to true based on the detected entailment. As with
+    /// [`Propagator::detect_inconsistency`], an implementation is not needed for correctness.
+    fn detect_entailment(&self, _context: PropagationContext) -> Option<PropositionalConjunction> {
+        None
+    }
+
+    /// Returns the priority of the propagator, which should be in the range `0..=4`. Whenever
+    /// multiple propagators are enqueued, the propagators with a lower value are called before
+    /// those with a higher value; the clausal propagator is always called before all of them.
+    ///
+    /// It is custom to give cheap propagators (e.g. linear inequalities) a low value and expensive
+    /// propagators (e.g. energetic reasoning) a high value, such that the expensive propagators are
+    /// only called once the cheap propagators have reached a fixed point.
+    ///
+    /// By default, the priority is 3.
+    fn priority(&self) -> u32 {
+        3
+    }
 }
@@ -69,3 +69,31 @@ impl PropagatorQueue {
         self.present_propagators.contains(&propagator_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PropagatorQueue;
+    use crate::engine::cp::propagation::PropagatorId;
+
+    #[test]
+    fn propagators_are_popped_in_order_of_priority() {
+        let mut queue = PropagatorQueue::new(3);
+
+        queue.enqueue_propagator(PropagatorId(0), 2);
+        queue.enqueue_propagator(PropagatorId(1), 0);
+        queue.enqueue_propagator(PropagatorId(2), 1);
+        queue.enqueue_propagator(PropagatorId(3), 0);
+
+        let popped = (0..4).map(|_| queue.pop()).collect::<Vec<_>>();
+        assert_eq!(
+            popped,
+            vec![
+                PropagatorId(1),
+                PropagatorId(3),
+                PropagatorId(2),
+                PropagatorId(0)
+            ]
+        );
+        assert!(queue.is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::propagation::PropagatorVarId;
 use crate::engine::variables::DomainId;
 
@@ -58,6 +59,12 @@ impl WatchListCP {
             IntDomainEvent::Removal => &watcher.forward_watcher.removal_watchers,
         }
     }
+
+    /// Returns the propagators which watch any event of the given domain, each propagator is
+    /// returned once.
+    pub(crate) fn get_watching_propagators(&self, domain: DomainId) -> &[PropagatorId] {
+        &self.watchers[domain].propagators
+    }
 }
 
 impl<'a> Watchers<'a> {
@@ -72,6 +79,13 @@ impl<'a> Watchers<'a> {
         self.watch_list.is_watching_anything = true;
         let watcher = &mut self.watch_list.watchers[domain];
 
+        if !watcher
+            .propagators
+            .contains(&self.propagator_var.propagator)
+        {
+            watcher.propagators.push(self.propagator_var.propagator);
+        }
+
         for event in events {
             let event_watcher = match event {
                 IntDomainEvent::LowerBound => &mut watcher.forward_watcher.lower_bound_watchers,
@@ -90,6 +104,8 @@ impl<'a> Watchers<'a> {
 #[derive(Default, Debug)]
 struct WatcherCP {
     forward_watcher: Watcher,
+    /// The propagators which watch at least one of the events of the domain.
+    propagators: Vec<PropagatorId>,
 }
 
 #[derive(Debug, Default)]
@@ -374,10 +374,7 @@ impl ClausalPropagator {
         assert!(
             self.watch_lists.iter().flatten().all(|x| {
                 let clause = clause_allocator.get_clause(x.clause_reference);
-                clause
-                    .get_literal_slice()
-                    .iter()
-                    .any(|lit| *lit == x.cached_literal)
+                clause.get_literal_slice().contains(&x.cached_literal)
             }),
             "There is a watcher with a cached literal that is not present in the clause."
         );
@@ -1,24 +1,75 @@
 use super::TerminationCondition;
 
-/// A [`TerminationCondition`] which triggers when one of two given [`TerminationCondition`]s
-/// triggers.
-#[derive(Clone, Copy, Debug)]
-pub struct Combinator<T1, T2> {
-    t1: T1,
-    t2: T2,
+/// A [`TerminationCondition`] which triggers when one of any number of given
+/// [`TerminationCondition`]s triggers.
+///
+/// To combine [`TerminationCondition`]s of different types, they can be boxed; i.e. using a
+/// `Combinator<Box<dyn TerminationCondition>>`.
+#[derive(Clone, Debug)]
+pub struct Combinator<T> {
+    conditions: Vec<T>,
 }
 
-impl<T1, T2> Combinator<T1, T2> {
-    /// Combine two [`TerminationCondition`]s into one.
-    pub fn new(t1: T1, t2: T2) -> Self {
-        Combinator { t1, t2 }
+impl<T> Combinator<T> {
+    /// Combine the provided [`TerminationCondition`]s into one; if no conditions are provided,
+    /// then the combined condition never triggers.
+    pub fn new(conditions: Vec<T>) -> Self {
+        Combinator { conditions }
+    }
+
+    /// Adds another [`TerminationCondition`] to the combined condition.
+    pub fn add(&mut self, condition: T) {
+        self.conditions.push(condition);
     }
 }
 
-impl<T1: TerminationCondition, T2: TerminationCondition> TerminationCondition
-    for Combinator<T1, T2>
-{
+impl<T: TerminationCondition> TerminationCondition for Combinator<T> {
     fn should_stop(&mut self) -> bool {
-        self.t1.should_stop() || self.t2.should_stop()
+        self.conditions
+            .iter_mut()
+            .any(|condition| condition.should_stop())
+    }
+
+    fn decision_has_been_made(&mut self) {
+        self.conditions
+            .iter_mut()
+            .for_each(|condition| condition.decision_has_been_made());
+    }
+
+    fn conflict_has_been_found(&mut self) {
+        self.conditions
+            .iter_mut()
+            .for_each(|condition| condition.conflict_has_been_found());
+    }
+
+    fn propagations_have_been_made(&mut self, num_propagations: u64) {
+        self.conditions
+            .iter_mut()
+            .for_each(|condition| condition.propagations_have_been_made(num_propagations));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Combinator;
+    use crate::termination::ConflictBudget;
+    use crate::termination::DecisionBudget;
+    use crate::termination::Indefinite;
+    use crate::termination::TerminationCondition;
+
+    #[test]
+    fn triggers_when_any_condition_triggers() {
+        let mut termination = Combinator::<Box<dyn TerminationCondition>>::new(vec![
+            Box::new(Indefinite),
+            Box::new(DecisionBudget::new(3)),
+            Box::new(ConflictBudget::new(1)),
+        ]);
+
+        termination.decision_has_been_made();
+        termination.decision_has_been_made();
+        assert!(!termination.should_stop());
+
+        termination.conflict_has_been_found();
+        assert!(termination.should_stop());
     }
 }
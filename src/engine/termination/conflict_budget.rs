@@ -0,0 +1,33 @@
+use super::TerminationCondition;
+#[cfg(doc)]
+use crate::termination::TimeBudget;
+
+/// A [`TerminationCondition`] which triggers when the solver has found the specified number of
+/// conflicts. In contrast to [`TimeBudget`], this condition is deterministic.
+#[derive(Clone, Copy, Debug)]
+pub struct ConflictBudget {
+    /// The number of conflicts found since the budget was created.
+    num_conflicts: u64,
+    /// The number of conflicts before [`ConflictBudget::should_stop()`] becomes true.
+    budget: u64,
+}
+
+impl ConflictBudget {
+    /// Give the solver a budget of `budget` conflicts.
+    pub fn new(budget: u64) -> ConflictBudget {
+        ConflictBudget {
+            num_conflicts: 0,
+            budget,
+        }
+    }
+}
+
+impl TerminationCondition for ConflictBudget {
+    fn should_stop(&mut self) -> bool {
+        self.num_conflicts >= self.budget
+    }
+
+    fn conflict_has_been_found(&mut self) {
+        self.num_conflicts += 1;
+    }
+}
@@ -0,0 +1,33 @@
+use super::TerminationCondition;
+#[cfg(doc)]
+use crate::termination::TimeBudget;
+
+/// A [`TerminationCondition`] which triggers when the solver has made the specified number of
+/// decisions. In contrast to [`TimeBudget`], this condition is deterministic.
+#[derive(Clone, Copy, Debug)]
+pub struct DecisionBudget {
+    /// The number of decisions made since the budget was created.
+    num_decisions: u64,
+    /// The number of decisions before [`DecisionBudget::should_stop()`] becomes true.
+    budget: u64,
+}
+
+impl DecisionBudget {
+    /// Give the solver a budget of `budget` decisions.
+    pub fn new(budget: u64) -> DecisionBudget {
+        DecisionBudget {
+            num_decisions: 0,
+            budget,
+        }
+    }
+}
+
+impl TerminationCondition for DecisionBudget {
+    fn should_stop(&mut self) -> bool {
+        self.num_decisions >= self.budget
+    }
+
+    fn decision_has_been_made(&mut self) {
+        self.num_decisions += 1;
+    }
+}
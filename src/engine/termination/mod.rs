@@ -2,10 +2,19 @@
 //! process. It indicates when the solver should stop, even if no definitive conclusions have been
 //! made. The most common example would be [`time_budget::TimeBudget`], which gives the solver a
 //! certain time budget to complete its search.
+//!
+//! Since a time budget depends on the machine on which the solver runs, there are also
+//! deterministic termination conditions which limit the amount of work done by the solver; e.g.
+//! [`conflict_budget::ConflictBudget`] gives the solver a budget on the number of conflicts. The
+//! solver informs the termination condition of the work it does through the hooks of
+//! [`TerminationCondition`] (e.g. [`TerminationCondition::conflict_has_been_found`]).
 
 pub(crate) mod combinator;
+pub(crate) mod conflict_budget;
+pub(crate) mod decision_budget;
 pub(crate) mod indefinite;
 pub(crate) mod os_signal;
+pub(crate) mod propagation_budget;
 pub(crate) mod time_budget;
 
 /// The central trait that defines a termination condition. A termination condition determines when
@@ -13,6 +22,16 @@ pub(crate) mod time_budget;
 pub trait TerminationCondition {
     /// Returns `true` when the solver should stop, `false` otherwise.
     fn should_stop(&mut self) -> bool;
+
+    /// Called by the solver whenever it has made a decision (excluding assumptions).
+    fn decision_has_been_made(&mut self) {}
+
+    /// Called by the solver whenever it has found a conflict.
+    fn conflict_has_been_found(&mut self) {}
+
+    /// Called by the solver after propagating, where `num_propagations` is the number of changes
+    /// to the domains of integer variables made by the propagation.
+    fn propagations_have_been_made(&mut self, _num_propagations: u64) {}
 }
 
 impl<T: TerminationCondition> TerminationCondition for Option<T> {
@@ -22,4 +41,40 @@ impl<T: TerminationCondition> TerminationCondition for Option<T> {
             None => false,
         }
     }
+
+    fn decision_has_been_made(&mut self) {
+        if let Some(t) = self {
+            t.decision_has_been_made();
+        }
+    }
+
+    fn conflict_has_been_found(&mut self) {
+        if let Some(t) = self {
+            t.conflict_has_been_found();
+        }
+    }
+
+    fn propagations_have_been_made(&mut self, num_propagations: u64) {
+        if let Some(t) = self {
+            t.propagations_have_been_made(num_propagations);
+        }
+    }
+}
+
+impl<T: TerminationCondition + ?Sized> TerminationCondition for Box<T> {
+    fn should_stop(&mut self) -> bool {
+        self.as_mut().should_stop()
+    }
+
+    fn decision_has_been_made(&mut self) {
+        self.as_mut().decision_has_been_made();
+    }
+
+    fn conflict_has_been_found(&mut self) {
+        self.as_mut().conflict_has_been_found();
+    }
+
+    fn propagations_have_been_made(&mut self, num_propagations: u64) {
+        self.as_mut().propagations_have_been_made(num_propagations);
+    }
 }
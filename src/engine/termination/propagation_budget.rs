@@ -0,0 +1,34 @@
+use super::TerminationCondition;
+#[cfg(doc)]
+use crate::termination::TimeBudget;
+
+/// A [`TerminationCondition`] which triggers when the solver has made the specified number of
+/// propagations, i.e. changes to the domains of integer variables due to propagation. In contrast
+/// to [`TimeBudget`], this condition is deterministic.
+#[derive(Clone, Copy, Debug)]
+pub struct PropagationBudget {
+    /// The number of propagations made since the budget was created.
+    num_propagations: u64,
+    /// The number of propagations before [`PropagationBudget::should_stop()`] becomes true.
+    budget: u64,
+}
+
+impl PropagationBudget {
+    /// Give the solver a budget of `budget` propagations.
+    pub fn new(budget: u64) -> PropagationBudget {
+        PropagationBudget {
+            num_propagations: 0,
+            budget,
+        }
+    }
+}
+
+impl TerminationCondition for PropagationBudget {
+    fn should_stop(&mut self) -> bool {
+        self.num_propagations >= self.budget
+    }
+
+    fn propagations_have_been_made(&mut self, num_propagations: u64) {
+        self.num_propagations += num_propagations;
+    }
+}
@@ -21,6 +21,7 @@ use crate::engine::predicates::integer_predicate::IntegerPredicate;
 use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::IntervalVariable;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 
@@ -76,6 +77,24 @@ impl TestSolver {
         Literal::new(PropositionalVariable::new(new_variable_index), true)
     }
 
+    /// Creates an interval whose presence is given by a new (unassigned) literal. Unlike
+    /// [`crate::Solver::new_optional_interval_variable`], `start + duration = end` is not
+    /// enforced.
+    pub(crate) fn new_interval_variable(
+        &mut self,
+        earliest_start: i32,
+        latest_end: i32,
+        min_duration: i32,
+        max_duration: i32,
+    ) -> IntervalVariable {
+        let start = self.new_variable(earliest_start, latest_end - min_duration);
+        let duration = self.new_variable(min_duration, max_duration);
+        let end = self.new_variable(earliest_start + min_duration, latest_end);
+        let presence = self.new_literal();
+
+        IntervalVariable::new(start, duration, end, presence)
+    }
+
     pub(crate) fn new_propagator(
         &mut self,
         propagator: impl Propagator + 'static,
@@ -141,6 +160,10 @@ impl TestSolver {
             .enqueue_decision_literal(if val { var } else { !var });
     }
 
+    pub(crate) fn is_literal_true(&self, var: Literal) -> bool {
+        self.assignments_propositional.is_literal_assigned_true(var)
+    }
+
     pub(crate) fn is_literal_false(&self, var: Literal) -> bool {
         self.assignments_propositional
             .is_literal_assigned_false(var)
@@ -194,6 +217,16 @@ impl TestSolver {
         Ok(())
     }
 
+    /// Asks the propagator whether its constraint is entailed by the current assignment.
+    pub(crate) fn detect_entailment(
+        &self,
+        propagator: &impl Propagator,
+    ) -> Option<PropositionalConjunction> {
+        let context =
+            PropagationContext::new(&self.assignments_integer, &self.assignments_propositional);
+        propagator.detect_entailment(context)
+    }
+
     pub(crate) fn get_reason_int(
         &mut self,
         predicate: IntegerPredicate,
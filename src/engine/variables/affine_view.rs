@@ -136,6 +136,10 @@ where
         }
         self.inner.watch_all(watchers, events);
     }
+
+    fn domain_id(&self) -> DomainId {
+        self.inner.domain_id()
+    }
 }
 
 impl<View> TransformableVariable<AffineView<View>> for AffineView<View>
@@ -31,6 +31,10 @@ impl IntegerVariable for DomainId {
         assignment.get_lower_bound(*self)
     }
 
+    fn domain_id(&self) -> DomainId {
+        *self
+    }
+
     fn upper_bound(&self, assignment: &AssignmentsInteger) -> i32 {
         assignment.get_upper_bound(*self)
     }
@@ -1,5 +1,6 @@
 use enumset::EnumSet;
 
+use super::DomainId;
 use super::TransformableVariable;
 use crate::engine::cp::reason::ReasonRef;
 use crate::engine::cp::AssignmentsInteger;
@@ -65,4 +66,7 @@ pub trait IntegerVariable:
 
     /// Register a watch for this variable on the given domain events.
     fn watch_all(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>);
+
+    /// Get the [`DomainId`] of the domain which this variable is a view of.
+    fn domain_id(&self) -> DomainId;
 }
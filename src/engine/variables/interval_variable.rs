@@ -0,0 +1,54 @@
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A (possibly optional) task in a schedule. The interval consists of a start time, a duration and
+/// an end time, which satisfy `start + duration = end` whenever the interval is present; the
+/// presence of the interval is given by a [`Literal`].
+///
+/// When the interval is absent, its start, duration and end are unconstrained and the interval is
+/// ignored by the scheduling constraints. A mandatory interval has the true literal as its
+/// presence literal.
+///
+/// Intervals are created through [`Solver::new_interval_variable`] and
+/// [`Solver::new_optional_interval_variable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalVariable {
+    start: DomainId,
+    duration: DomainId,
+    end: DomainId,
+    presence: Literal,
+}
+
+impl IntervalVariable {
+    pub(crate) fn new(
+        start: DomainId,
+        duration: DomainId,
+        end: DomainId,
+        presence: Literal,
+    ) -> IntervalVariable {
+        IntervalVariable {
+            start,
+            duration,
+            end,
+            presence,
+        }
+    }
+
+    pub fn start(&self) -> DomainId {
+        self.start
+    }
+
+    pub fn duration(&self) -> DomainId {
+        self.duration
+    }
+
+    pub fn end(&self) -> DomainId {
+        self.end
+    }
+
+    pub fn presence(&self) -> Literal {
+        self.presence
+    }
+}
@@ -6,6 +6,7 @@ mod affine_view;
 mod domain_generator_iterator;
 mod domain_id;
 mod integer_variable;
+mod interval_variable;
 mod literal;
 mod propositional_variable;
 mod propositional_variable_generator_iterator;
@@ -15,6 +16,7 @@ pub use affine_view::AffineView;
 pub(crate) use domain_generator_iterator::DomainGeneratorIterator;
 pub use domain_id::DomainId;
 pub use integer_variable::IntegerVariable;
+pub use interval_variable::IntervalVariable;
 pub use literal::Literal;
 pub use propositional_variable::PropositionalVariable;
 pub(crate) use propositional_variable_generator_iterator::PropositionalVariableGeneratorIterator;
@@ -309,6 +309,7 @@ mod api;
 
 pub use api::*;
 
+pub use crate::api::solver::DefaultBrancher;
 pub use crate::api::solver::Solver;
 pub use crate::basic_types::ConstraintOperationError;
 pub use crate::basic_types::Random;
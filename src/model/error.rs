@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+use super::Constraint;
+use super::Globals;
+use crate::ConstraintOperationError;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Errors which occur when a [`Model`](super::Model) is turned into a [`Solver`].
+#[derive(Error, Debug, Clone)]
+pub enum ModelError {
+    /// The constraint is infeasible at the root, given the constraints which were added before it.
+    #[error("the model is infeasible at the root when adding {constraint:?}")]
+    Infeasible {
+        constraint: Box<Constraint>,
+        #[source]
+        error: ConstraintOperationError,
+    },
+    /// Two globals were enabled which select different implementations of the same constraint.
+    #[error("the globals {0:?} and {1:?} cannot be enabled at the same time")]
+    IncompatibleGlobals(Globals, Globals),
+    /// A constraint refers to a variable which was not created by the model.
+    #[error("a constraint uses a variable which was not created by this model")]
+    ForeignVariable,
+    /// The domain of the variable does not contain any values.
+    #[error("the domain of '{0}' is empty")]
+    EmptyDomain(String),
+    /// Only constraints which have a negation can be fully reified.
+    #[error("{0:?} cannot be fully reified")]
+    NotReifiable(Box<Constraint>),
+    /// The constraint is posted with a decomposition which does not support half-reification.
+    #[error("{0:?} cannot be half-reified with the enabled globals")]
+    NotHalfReifiable(Box<Constraint>),
+    /// A reified constraint cannot be reified again.
+    #[error("{0:?} is reified more than once")]
+    NestedReification(Box<Constraint>),
+}
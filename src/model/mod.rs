@@ -1,29 +1,68 @@
+mod error;
+
 use std::fmt::Display;
+use std::ops::Not;
 use std::ops::Range;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use clap::ValueEnum;
 
 use crate::constraints;
+use crate::constraints::ConstraintPoster;
 use crate::constraints::CumulativeImpl;
+use crate::constraints::NegatableConstraint;
 use crate::constraints::SubCircuitElimination;
 use crate::options::SolverOptions;
 use crate::variables::AffineView;
 use crate::variables::DomainId;
+use crate::variables::Literal;
 use crate::variables::TransformableVariable;
 use crate::ConstraintOperationError;
 use crate::Solver;
 
+pub use error::ModelError;
+
 /// Builds up the model, from which a solver can be constructed.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Model {
-    /// Every element denotes the bounds of the variable.
-    variables: Vec<(String, i32, i32)>,
+    /// The identifier of the model, which is stored in its variables to detect variables from
+    /// other models.
+    id: ModelId,
+    /// Every element denotes the domain of the variable.
+    variables: Vec<(String, Domain)>,
     /// Arrays of variables.
     arrays: Vec<(String, Range<usize>)>,
+    /// The names of the Boolean variables.
+    booleans: Vec<String>,
     /// The constraints in the model.
     constraints: Vec<Constraint>,
 }
 
+impl Default for Model {
+    fn default() -> Self {
+        Model {
+            id: ModelId::next(),
+            variables: vec![],
+            arrays: vec![],
+            booleans: vec![],
+            constraints: vec![],
+        }
+    }
+}
+
+/// Identifies a [`Model`]; every model created with [`Model::default`] has a distinct identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModelId(usize);
+
+impl ModelId {
+    fn next() -> ModelId {
+        static NEXT_MODEL_ID: AtomicUsize = AtomicUsize::new(0);
+
+        ModelId(NEXT_MODEL_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
 impl Model {
     /// Create a new interval variable.
     pub fn new_interval_variable(
@@ -32,12 +71,38 @@ impl Model {
         lower_bound: i32,
         upper_bound: i32,
     ) -> IntVariable {
+        self.new_variable(name, Domain::Interval(lower_bound, upper_bound))
+    }
+
+    /// Create a new variable which can take the given values.
+    pub fn new_sparse_variable(&mut self, name: impl Display, values: Vec<i32>) -> IntVariable {
+        self.new_variable(name, Domain::Sparse(values))
+    }
+
+    /// Create a variable which is fixed to the given value.
+    pub fn new_constant(&mut self, value: i32) -> IntVariable {
+        self.new_interval_variable(value, value, value)
+    }
+
+    /// Create a new Boolean variable.
+    pub fn new_boolean_variable(&mut self, name: impl Display) -> BoolVariable {
+        let id = self.booleans.len();
+        self.booleans.push(name.to_string());
+
+        BoolVariable {
+            model: self.id,
+            id,
+            negated: false,
+        }
+    }
+
+    fn new_variable(&mut self, name: impl Display, domain: Domain) -> IntVariable {
         let id = self.variables.len();
 
-        self.variables
-            .push((name.to_string(), lower_bound, upper_bound));
+        self.variables.push((name.to_string(), domain));
 
         IntVariable {
+            model: self.id,
             scale: 1,
             offset: 0,
             id,
@@ -66,6 +131,23 @@ impl Model {
         IntVariableArray(id)
     }
 
+    /// Create a new array of variables which are fixed to the given values. This allows constants
+    /// to be used in constraints which take variables, such as [`Constraint::AllDifferent`].
+    pub fn new_constant_array(&mut self, name: impl Display, values: &[i32]) -> IntVariableArray {
+        let id = self.arrays.len();
+
+        let start = self.variables.len();
+        values.iter().enumerate().for_each(|(i, &value)| {
+            let _ = self.new_interval_variable(format!("{name}[{i}]"), value, value);
+        });
+
+        let end = self.variables.len();
+
+        self.arrays.push((name.to_string(), start..end));
+
+        IntVariableArray(id)
+    }
+
     /// Add a constraint to the model.
     ///
     /// It is important to only use constraints with variables created on the same instance of
@@ -74,43 +156,84 @@ impl Model {
         self.constraints.push(constraint);
     }
 
+    /// Break the symmetry between interchangeable arrays of variables, such as the rows of a
+    /// matrix in which the rows can be permuted. Consecutive arrays are required to be
+    /// lexicographically ordered, which only keeps the lex-leader of every class of symmetric
+    /// solutions.
+    ///
+    /// If the arrays cannot be equal in any solution (e.g. when they represent distinct objects),
+    /// `strict` can be set to require a strict ordering.
+    pub fn add_lex_leader_constraints(
+        &mut self,
+        arrays: impl IntoIterator<Item = Vec<IntVariable>>,
+        strict: bool,
+    ) {
+        let arrays: Vec<Vec<IntVariable>> = arrays.into_iter().collect();
+
+        for pair in arrays.windows(2) {
+            let xs = pair[0].clone();
+            let ys = pair[1].clone();
+
+            self.add_constraint(if strict {
+                Constraint::LexLess { xs, ys }
+            } else {
+                Constraint::LexLessEq { xs, ys }
+            });
+        }
+    }
+
     /// Create a solver instance from this model.
+    ///
+    /// Fails if the model is inconsistent, for example when a constraint uses a variable from
+    /// another model, or if a constraint is infeasible at the root.
     pub fn into_solver(
         self,
         solver_options: SolverOptions,
         use_global_propagator: impl Fn(Globals) -> bool,
-    ) -> (Solver, VariableMap) {
+    ) -> Result<(Solver, VariableMap), ModelError> {
         let mut solver = Solver::with_options(solver_options);
 
-        let (variables, names): (Vec<_>, Vec<_>) = self
-            .variables
-            .into_iter()
-            .map(|(name, lower_bound, upper_bound)| {
-                (
-                    AffineView::from(solver.new_named_bounded_integer(
-                        lower_bound,
-                        upper_bound,
-                        name.clone(),
-                    )),
-                    name,
-                )
-            })
-            .unzip();
+        let mut variables = Vec::with_capacity(self.variables.len());
+        let mut names = Vec::with_capacity(self.variables.len());
+
+        for (name, domain) in self.variables {
+            let domain_id = match domain {
+                Domain::Interval(lower_bound, upper_bound) if lower_bound <= upper_bound => {
+                    solver.new_named_bounded_integer(lower_bound, upper_bound, name.clone())
+                }
+                Domain::Sparse(values) if !values.is_empty() => {
+                    solver.new_named_sparse_integer(values, name.clone())
+                }
+                _ => return Err(ModelError::EmptyDomain(name)),
+            };
+
+            variables.push(AffineView::from(domain_id));
+            names.push(name);
+        }
+
+        let literals = self
+            .booleans
+            .iter()
+            .map(|name| solver.new_named_literal(name.clone()))
+            .collect();
 
         let solver_variables = VariableMap {
+            model: self.id,
             variables,
             names,
             arrays: self.arrays,
+            literals,
+            boolean_names: self.booleans,
         };
 
-        let _ = add_constraints(
+        add_constraints(
             self.constraints,
             &solver_variables,
             use_global_propagator,
             &mut solver,
-        );
+        )?;
 
-        (solver, solver_variables)
+        Ok((solver, solver_variables))
     }
 }
 
@@ -119,123 +242,588 @@ fn add_constraints(
     solver_variables: &VariableMap,
     use_global_propagator: impl Fn(Globals) -> bool,
     solver: &mut Solver,
-) -> Result<(), ConstraintOperationError> {
-    let to_solver_variable = |int_var: IntVariable| solver_variables.to_solver_variable(int_var);
-
+) -> Result<(), ModelError> {
     for constraint in constraints {
-        match constraint {
-            Constraint::Circuit(variables) => {
-                let variables: Vec<_> = variables.into_iter().map(to_solver_variable).collect();
-
-                let use_dfs = use_global_propagator(Globals::DfsCircuit);
-                let use_forward_checking = use_global_propagator(Globals::ForwardCheckingCircuit);
-
-                let sub_circuit_elimination = match (use_dfs, use_forward_checking) {
-                    (true, true) => {
-                        panic!("cannot use foward checking and dfs for sub-circuit elimination")
-                    }
-                    (false, true) => SubCircuitElimination::ForwardChecking,
-                    (true, false) => SubCircuitElimination::Dfs,
-                    (false, false) => SubCircuitElimination::Decomposition,
-                };
-
-                solver
-                    .add_constraint(constraints::circuit(
+        add_constraint(
+            constraint.clone(),
+            Reification::None,
+            solver_variables,
+            &use_global_propagator,
+            solver,
+        )
+        .map_err(|error| match error {
+            AddConstraintError::Model(error) => error,
+            AddConstraintError::Infeasible(error) => ModelError::Infeasible {
+                constraint: Box::new(constraint),
+                error,
+            },
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The ways adding a single constraint can fail. Infeasibility is tied to the constraint which
+/// caused it in [`add_constraints`].
+#[derive(Debug)]
+enum AddConstraintError {
+    Model(ModelError),
+    Infeasible(ConstraintOperationError),
+}
+
+impl From<ModelError> for AddConstraintError {
+    fn from(error: ModelError) -> Self {
+        AddConstraintError::Model(error)
+    }
+}
+
+impl From<ConstraintOperationError> for AddConstraintError {
+    fn from(error: ConstraintOperationError) -> Self {
+        AddConstraintError::Infeasible(error)
+    }
+}
+
+/// How a constraint is added to the solver.
+#[derive(Clone, Copy, Debug)]
+enum Reification {
+    /// The constraint is posted as is.
+    None,
+    /// The constraint is implied by the literal.
+    Half(Literal),
+    /// The constraint holds if and only if the literal is true.
+    Full(Literal),
+}
+
+/// Posts a constraint which does not have a negation, and can therefore not be fully reified.
+fn post(
+    poster: ConstraintPoster<'_, impl constraints::Constraint>,
+    reification: Reification,
+) -> Result<(), AddConstraintError> {
+    match reification {
+        Reification::None => poster.post()?,
+        Reification::Half(literal) => poster.implied_by(literal)?,
+        Reification::Full(_) => unreachable!("only negatable constraints are fully reified"),
+    }
+
+    Ok(())
+}
+
+fn post_negatable(
+    poster: ConstraintPoster<'_, impl NegatableConstraint>,
+    reification: Reification,
+) -> Result<(), AddConstraintError> {
+    match reification {
+        Reification::None => poster.post()?,
+        Reification::Half(literal) => poster.implied_by(literal)?,
+        Reification::Full(literal) => poster.reify(literal)?,
+    }
+
+    Ok(())
+}
+
+fn add_constraint(
+    constraint: Constraint,
+    reification: Reification,
+    solver_variables: &VariableMap,
+    use_global_propagator: &impl Fn(Globals) -> bool,
+    solver: &mut Solver,
+) -> Result<(), AddConstraintError> {
+    let to_solver_variable =
+        |int_var: IntVariable| solver_variables.try_to_solver_variable(int_var);
+
+    match constraint {
+        Constraint::Circuit(variables) => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post(
+                solver.add_constraint(constraints::circuit(
+                    variables,
+                    sub_circuit_elimination(use_global_propagator)?,
+                    !use_global_propagator(Globals::AllDifferent),
+                    !use_global_propagator(Globals::Element),
+                )),
+                reification,
+            )
+        }
+        Constraint::SubCircuit(variables) => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let use_all_different_decomposition = !use_global_propagator(Globals::AllDifferent);
+
+            if use_global_propagator(Globals::SubCircuit) {
+                post(
+                    solver.add_constraint(constraints::subcircuit(
                         variables,
-                        sub_circuit_elimination,
-                        !use_global_propagator(Globals::AllDifferent),
-                        !use_global_propagator(Globals::Element),
-                    ))
-                    .post()?;
+                        use_all_different_decomposition,
+                    )),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::subcircuit_decomposition(
+                        variables,
+                        use_all_different_decomposition,
+                    )),
+                    reification,
+                )
             }
-            Constraint::Element { array, index, rhs } => {
-                let index = to_solver_variable(index);
-                let rhs = to_solver_variable(rhs);
+        }
+        Constraint::AllDifferent(variables) => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            if use_global_propagator(Globals::AllDifferent) {
+                post(
+                    solver.add_constraint(constraints::all_different(variables)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::all_different_decomposition(variables)),
+                    reification,
+                )
+            }
+        }
+        Constraint::Element { array, index, rhs } => {
+            let index = to_solver_variable(index)?;
+            let rhs = to_solver_variable(rhs)?;
+
+            let array: Vec<_> = array
+                .into_iter()
+                .map(|element| AffineView::from(solver.new_bounded_integer(element, element)))
+                .collect();
 
-                let array: Vec<_> = array
+            if use_global_propagator(Globals::Element) {
+                post(
+                    solver.add_constraint(constraints::element(index, array, rhs)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::element_decomposition(index, array, rhs)),
+                    reification,
+                )
+            }
+        }
+        Constraint::LexLessEq { xs, ys } => {
+            let xs: Vec<_> = xs
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let ys: Vec<_> = ys
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post(
+                solver.add_constraint(constraints::lex_less_eq(xs, ys)),
+                reification,
+            )
+        }
+        Constraint::LexLess { xs, ys } => {
+            let xs: Vec<_> = xs
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let ys: Vec<_> = ys
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post(
+                solver.add_constraint(constraints::lex_less(xs, ys)),
+                reification,
+            )
+        }
+        Constraint::ValuePrecedeChain { values, variables } => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post(
+                solver.add_constraint(constraints::value_precede_chain(values, variables)),
+                reification,
+            )
+        }
+        Constraint::LinearEqual { terms, rhs } => {
+            let terms: Vec<_> = terms
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post_negatable(
+                solver.add_constraint(constraints::equals(terms, rhs)),
+                reification,
+            )
+        }
+        Constraint::LinearNotEqual { terms, rhs } => {
+            let terms: Vec<_> = terms
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post_negatable(
+                solver.add_constraint(constraints::not_equals(terms, rhs)),
+                reification,
+            )
+        }
+        Constraint::LinearLessEqual { terms, rhs } => {
+            let terms: Vec<_> = terms
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            post_negatable(
+                solver.add_constraint(constraints::less_than_or_equals(terms, rhs)),
+                reification,
+            )
+        }
+        Constraint::Clause(literals) => {
+            let literals: Vec<_> = literals
+                .into_iter()
+                .map(|literal| solver_variables.try_to_solver_literal(literal))
+                .collect::<Result<_, _>>()?;
+
+            post_negatable(
+                solver.add_constraint(constraints::clause(literals)),
+                reification,
+            )
+        }
+        Constraint::Cumulative {
+            start_times,
+            durations,
+            resource_requirements,
+            resource_capacity,
+        } => {
+            let start_times: Vec<_> = start_times
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            if use_global_propagator(Globals::Disjunctive)
+                && is_disjunctive(&resource_requirements, resource_capacity)
+            {
+                // Tasks which do not require the resource are not constrained.
+                let (start_times, durations): (Vec<_>, Vec<_>) = start_times
                     .into_iter()
-                    .map(|element| AffineView::from(solver.new_bounded_integer(element, element)))
-                    .collect();
+                    .zip(durations)
+                    .zip(resource_requirements)
+                    .filter(|&(_, requirement)| requirement > 0)
+                    .map(|(task, _)| task)
+                    .unzip();
 
-                if use_global_propagator(Globals::Element) {
+                post(
+                    solver.add_constraint(constraints::disjunctive(start_times, durations)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::cumulative(
+                        cumulative_impl(use_global_propagator)?,
+                        start_times,
+                        durations,
+                        resource_requirements,
+                        resource_capacity,
+                    )),
+                    reification,
+                )
+            }
+        }
+        Constraint::Among {
+            variables,
+            values,
+            count,
+        } => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let count = to_solver_variable(count)?;
+
+            if use_global_propagator(Globals::Among) {
+                post(
+                    solver.add_constraint(constraints::among(variables, values, count)),
+                    reification,
+                )
+            } else {
+                post(
                     solver
-                        .add_constraint(constraints::element(index, array, rhs))
-                        .post()?;
-                } else {
+                        .add_constraint(constraints::among_decomposition(variables, values, count)),
+                    reification,
+                )
+            }
+        }
+        Constraint::Count {
+            variables,
+            value,
+            count,
+        } => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let count = to_solver_variable(count)?;
+
+            if use_global_propagator(Globals::Count) {
+                post(
+                    solver.add_constraint(constraints::count_eq(variables, value, count)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::count_eq_decomposition(
+                        variables, value, count,
+                    )),
+                    reification,
+                )
+            }
+        }
+        Constraint::GlobalCardinality {
+            variables,
+            values,
+            counts,
+        } => {
+            let variables: Vec<_> = variables
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let counts: Vec<_> = counts
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            if use_global_propagator(Globals::GlobalCardinality) {
+                post(
                     solver
-                        .add_constraint(constraints::element_decomposition(index, array, rhs))
-                        .post()?;
-                }
+                        .add_constraint(constraints::global_cardinality(variables, values, counts)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::global_cardinality_decomposition(
+                        variables, values, counts,
+                    )),
+                    reification,
+                )
             }
-            Constraint::LinearEqual { terms, rhs } => {
-                let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
+        }
+        Constraint::Inverse { f, g } => {
+            let f: Vec<_> = f
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let g: Vec<_> = g
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
 
-                solver
-                    .add_constraint(constraints::equals(terms, rhs))
-                    .post()?;
+            if use_global_propagator(Globals::Inverse) {
+                post(
+                    solver.add_constraint(constraints::inverse(f, g)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::inverse_decomposition(f, g)),
+                    reification,
+                )
             }
-            Constraint::LinearLessEqual { terms, rhs } => {
-                let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
+        }
+        Constraint::BinPacking { bins, sizes, loads } => {
+            let bins: Vec<_> = bins
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let loads: Vec<_> = loads
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
 
-                solver
-                    .add_constraint(constraints::less_than_or_equals(terms, rhs))
-                    .post()?;
+            if use_global_propagator(Globals::BinPacking) {
+                post(
+                    solver.add_constraint(constraints::bin_packing(bins, sizes, loads)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver
+                        .add_constraint(constraints::bin_packing_decomposition(bins, sizes, loads)),
+                    reification,
+                )
             }
-            Constraint::Cumulative {
-                start_times,
-                durations,
-                resource_requirements,
-                resource_capacity,
-            } => {
-                let start_times: Vec<_> = start_times.into_iter().map(to_solver_variable).collect();
-
-                let use_time_table = use_global_propagator(Globals::TimeTableCumulative);
-                let use_energetic_reasoning =
-                    use_global_propagator(Globals::EnergeticReasoningCumulative);
-
-                let cumulative_impl = match (use_time_table, use_energetic_reasoning) {
-                    (true, true) => panic!(
-                        "cannot use energetic reasoning and time-table reasoning at the same time"
-                    ),
-                    (true, false) => CumulativeImpl::TimeTable,
-                    (false, true) => CumulativeImpl::EnergeticReasoning,
-                    (false, false) => CumulativeImpl::Decomposition,
-                };
-
-                solver
-                    .add_constraint(constraints::cumulative(
-                        cumulative_impl,
-                        start_times,
-                        durations,
-                        resource_requirements,
-                        resource_capacity,
-                    ))
-                    .post()?;
+        }
+        Constraint::Diffn {
+            xs,
+            ys,
+            widths,
+            heights,
+        } => {
+            let xs: Vec<_> = xs
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let ys: Vec<_> = ys
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+
+            if use_global_propagator(Globals::DiffnCumulativeProjections) {
+                post(
+                    solver.add_constraint(constraints::diffn_cumulative_projections(
+                        cumulative_impl(use_global_propagator)?,
+                        xs.clone(),
+                        ys.clone(),
+                        widths.clone(),
+                        heights.clone(),
+                    )),
+                    reification,
+                )?;
             }
-            Constraint::Maximum { terms, rhs } => {
-                let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
-                let rhs = to_solver_variable(rhs);
-
-                if use_global_propagator(Globals::Maximum) {
-                    let _ = solver
-                        .add_constraint(constraints::maximum(terms, rhs))
-                        .post();
-                } else {
-                    let _ = solver
-                        .add_constraint(constraints::maximum_decomposition(terms, rhs))
-                        .post();
-                }
+
+            if use_global_propagator(Globals::Diffn) {
+                post(
+                    solver.add_constraint(constraints::diffn(xs, ys, widths, heights)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver
+                        .add_constraint(constraints::diffn_decomposition(xs, ys, widths, heights)),
+                    reification,
+                )
             }
         }
+        Constraint::Maximum { terms, rhs } => {
+            let terms: Vec<_> = terms
+                .into_iter()
+                .map(to_solver_variable)
+                .collect::<Result<_, _>>()?;
+            let rhs = to_solver_variable(rhs)?;
+
+            if use_global_propagator(Globals::Maximum) {
+                post(
+                    solver.add_constraint(constraints::maximum(terms, rhs)),
+                    reification,
+                )
+            } else {
+                post(
+                    solver.add_constraint(constraints::maximum_decomposition(terms, rhs)),
+                    reification,
+                )
+            }
+        }
+        Constraint::Reified {
+            constraint,
+            reification: literal,
+        } => {
+            if !matches!(reification, Reification::None) {
+                return Err(ModelError::NestedReification(constraint).into());
+            }
+            if !constraint.is_negatable() {
+                return Err(ModelError::NotReifiable(constraint).into());
+            }
+
+            let literal = solver_variables.try_to_solver_literal(literal)?;
+            add_constraint(
+                *constraint,
+                Reification::Full(literal),
+                solver_variables,
+                use_global_propagator,
+                solver,
+            )
+        }
+        Constraint::HalfReified {
+            constraint,
+            reification: literal,
+        } => {
+            if !matches!(reification, Reification::None) {
+                return Err(ModelError::NestedReification(constraint).into());
+            }
+            if !constraint.supports_half_reification(use_global_propagator) {
+                return Err(ModelError::NotHalfReifiable(constraint).into());
+            }
+
+            let literal = solver_variables.try_to_solver_literal(literal)?;
+            add_constraint(
+                *constraint,
+                Reification::Half(literal),
+                solver_variables,
+                use_global_propagator,
+                solver,
+            )
+        }
     }
+}
 
-    Ok(())
+/// Selects the sub-circuit elimination of the circuit constraint based on the enabled globals.
+pub fn sub_circuit_elimination(
+    use_global_propagator: &impl Fn(Globals) -> bool,
+) -> Result<SubCircuitElimination, ModelError> {
+    let use_dfs = use_global_propagator(Globals::DfsCircuit);
+    let use_forward_checking = use_global_propagator(Globals::ForwardCheckingCircuit);
+
+    match (use_dfs, use_forward_checking) {
+        (true, true) => Err(ModelError::IncompatibleGlobals(
+            Globals::DfsCircuit,
+            Globals::ForwardCheckingCircuit,
+        )),
+        (false, true) => Ok(SubCircuitElimination::ForwardChecking),
+        (true, false) => Ok(SubCircuitElimination::Dfs),
+        (false, false) => Ok(SubCircuitElimination::Decomposition),
+    }
+}
+
+/// Selects the implementation of the cumulative constraint based on the enabled globals.
+pub fn cumulative_impl(
+    use_global_propagator: &impl Fn(Globals) -> bool,
+) -> Result<CumulativeImpl, ModelError> {
+    let use_time_table = use_global_propagator(Globals::TimeTableCumulative);
+    let use_energetic_reasoning = use_global_propagator(Globals::EnergeticReasoningCumulative);
+
+    match (use_time_table, use_energetic_reasoning) {
+        (true, true) => Err(ModelError::IncompatibleGlobals(
+            Globals::TimeTableCumulative,
+            Globals::EnergeticReasoningCumulative,
+        )),
+        (true, false) => Ok(CumulativeImpl::TimeTable),
+        (false, true) => Ok(CumulativeImpl::EnergeticReasoning),
+        (false, false) => Ok(CumulativeImpl::Decomposition),
+    }
+}
+
+/// Whether no two tasks which require the resource can be executed at the same time, in which case
+/// the cumulative constraint is a disjunctive constraint.
+pub fn is_disjunctive(resource_requirements: &[u32], resource_capacity: u32) -> bool {
+    let mut requirements: Vec<u32> = resource_requirements
+        .iter()
+        .copied()
+        .filter(|&requirement| requirement > 0)
+        .collect();
+    requirements.sort_unstable();
+
+    requirements
+        .iter()
+        .all(|&requirement| requirement <= resource_capacity)
+        && (requirements.len() < 2 || requirements[0] + requirements[1] > resource_capacity)
 }
 
 /// The constraints which can be used in [`Model`].
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Constraint {
     Circuit(Vec<IntVariable>),
+    /// The variables take pairwise distinct values.
+    AllDifferent(Vec<IntVariable>),
+    /// The 1-indexed successors of the nodes which are not excluded (`successors[i] = i`) form a
+    /// single cycle.
+    SubCircuit(Vec<IntVariable>),
     Element {
         array: Vec<i32>,
         index: IntVariable,
@@ -245,10 +833,17 @@ pub enum Constraint {
         terms: Vec<IntVariable>,
         rhs: i32,
     },
+    /// The sum of the terms is not equal to `rhs`.
+    LinearNotEqual {
+        terms: Vec<IntVariable>,
+        rhs: i32,
+    },
     LinearLessEqual {
         terms: Vec<IntVariable>,
         rhs: i32,
     },
+    /// At least one of the Boolean variables is true.
+    Clause(Vec<BoolVariable>),
     Cumulative {
         start_times: Vec<IntVariable>,
         durations: Vec<u32>,
@@ -259,10 +854,141 @@ pub enum Constraint {
         terms: Vec<IntVariable>,
         rhs: IntVariable,
     },
+    /// Exactly `count` of the `variables` take a value in `values`.
+    Among {
+        variables: Vec<IntVariable>,
+        values: Vec<i32>,
+        count: IntVariable,
+    },
+    /// Exactly `count` of the `variables` are equal to `value`.
+    Count {
+        variables: Vec<IntVariable>,
+        value: i32,
+        count: IntVariable,
+    },
+    /// The 1-indexed arrays `f` and `g` are each other's inverse; i.e. `f[i] = j <-> g[j] = i`.
+    Inverse {
+        f: Vec<IntVariable>,
+        g: Vec<IntVariable>,
+    },
+    /// For every `k`, exactly `counts[k]` of the `variables` are equal to `values[k]`.
+    GlobalCardinality {
+        variables: Vec<IntVariable>,
+        values: Vec<i32>,
+        counts: Vec<IntVariable>,
+    },
+    /// The rectangles with origins `(xs[i], ys[i])`, widths `widths[i]` and heights `heights[i]`
+    /// do not overlap.
+    Diffn {
+        xs: Vec<IntVariable>,
+        ys: Vec<IntVariable>,
+        widths: Vec<u32>,
+        heights: Vec<u32>,
+    },
+    /// Item `i` with size `sizes[i]` is packed into the 1-indexed bin `bins[i]`, and `loads[j]` is
+    /// the total size of the items packed into bin `j + 1`.
+    BinPacking {
+        bins: Vec<IntVariable>,
+        sizes: Vec<u32>,
+        loads: Vec<IntVariable>,
+    },
+    /// `xs` is lexicographically smaller than or equal to `ys`.
+    LexLessEq {
+        xs: Vec<IntVariable>,
+        ys: Vec<IntVariable>,
+    },
+    /// `xs` is lexicographically strictly smaller than `ys`.
+    LexLess {
+        xs: Vec<IntVariable>,
+        ys: Vec<IntVariable>,
+    },
+    /// Every value in `values` is used in `variables` before the next value in `values` is used.
+    ValuePrecedeChain {
+        values: Vec<i32>,
+        variables: Vec<IntVariable>,
+    },
+    /// The constraint holds if and only if `reification` is true. Only the linear constraints and
+    /// clauses can be fully reified; see [`Constraint::reified_by`].
+    Reified {
+        constraint: Box<Constraint>,
+        reification: BoolVariable,
+    },
+    /// The constraint holds if `reification` is true; see [`Constraint::implied_by`].
+    HalfReified {
+        constraint: Box<Constraint>,
+        reification: BoolVariable,
+    },
+}
+
+impl Constraint {
+    /// The constraint `reification <-> self`.
+    ///
+    /// # Errors
+    /// If `self` is not a linear constraint or a clause, then [`Model::into_solver`] returns
+    /// [`ModelError::NotReifiable`] for a [`Model`] containing the resulting constraint, as only
+    /// those constraints have a negation.
+    pub fn reified_by(self, reification: BoolVariable) -> Constraint {
+        Constraint::Reified {
+            constraint: Box::new(self),
+            reification,
+        }
+    }
+
+    /// The constraint `reification -> self`.
+    ///
+    /// # Errors
+    /// Every constraint can be half-reified, except for the ones of which the decomposition does
+    /// not support it yet. [`Model::into_solver`] returns [`ModelError::NotHalfReifiable`] for a
+    /// [`Model`] containing the resulting constraint if `self` is:
+    /// - a [`Constraint::Circuit`];
+    /// - a [`Constraint::Cumulative`], unless it is posted as a disjunctive constraint (see
+    ///   [`Globals::Disjunctive`]);
+    /// - a [`Constraint::Element`] without [`Globals::Element`];
+    /// - a [`Constraint::Maximum`] without [`Globals::Maximum`];
+    /// - a [`Constraint::Diffn`] with [`Globals::DiffnCumulativeProjections`].
+    pub fn implied_by(self, reification: BoolVariable) -> Constraint {
+        Constraint::HalfReified {
+            constraint: Box::new(self),
+            reification,
+        }
+    }
+
+    /// Whether the constraint can be half-reified when it is posted with the given globals; see
+    /// [`Constraint::implied_by`].
+    fn supports_half_reification(&self, use_global_propagator: &impl Fn(Globals) -> bool) -> bool {
+        match self {
+            Constraint::Circuit(_) => false,
+            Constraint::Cumulative {
+                resource_requirements,
+                resource_capacity,
+                ..
+            } => {
+                use_global_propagator(Globals::Disjunctive)
+                    && is_disjunctive(resource_requirements, *resource_capacity)
+            }
+            Constraint::Element { .. } => use_global_propagator(Globals::Element),
+            Constraint::Maximum { .. } => use_global_propagator(Globals::Maximum),
+            Constraint::Diffn { .. } => !use_global_propagator(Globals::DiffnCumulativeProjections),
+            _ => true,
+        }
+    }
+
+    /// Whether the constraint has a negation, which is required to fully reify it.
+    fn is_negatable(&self) -> bool {
+        matches!(
+            self,
+            Constraint::LinearEqual { .. }
+                | Constraint::LinearNotEqual { .. }
+                | Constraint::LinearLessEqual { .. }
+                | Constraint::Clause(_)
+        )
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct IntVariable {
+    /// The model which created the variable.
+    model: ModelId,
     /// The scale w.r.t. the underlying domain.
     scale: i32,
     /// The offset w.r.t. the underyling domain.
@@ -274,6 +1000,7 @@ pub struct IntVariable {
 impl IntVariable {
     pub fn scaled(&self, scale: i32) -> IntVariable {
         IntVariable {
+            model: self.model,
             scale: self.scale * scale,
             offset: self.offset * scale,
             id: self.id,
@@ -282,6 +1009,7 @@ impl IntVariable {
 
     pub fn offset(&self, offset: i32) -> IntVariable {
         IntVariable {
+            model: self.model,
             scale: self.scale,
             offset: self.offset + offset,
             id: self.id,
@@ -289,6 +1017,37 @@ impl IntVariable {
     }
 }
 
+/// A Boolean variable in the [`Model`], or its negation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoolVariable {
+    /// The model which created the variable.
+    model: ModelId,
+    /// The variable id.
+    id: usize,
+    negated: bool,
+}
+
+impl Not for BoolVariable {
+    type Output = BoolVariable;
+
+    fn not(self) -> BoolVariable {
+        BoolVariable {
+            model: self.model,
+            id: self.id,
+            negated: !self.negated,
+        }
+    }
+}
+
+/// The domain of an integer variable in the [`Model`].
+#[derive(Clone, Debug)]
+enum Domain {
+    /// All values between the bounds, inclusive.
+    Interval(i32, i32),
+    /// The given values.
+    Sparse(Vec<i32>),
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct IntVariableArray(usize);
 
@@ -300,6 +1059,7 @@ impl IntVariableArray {
         let (_, range) = &model.arrays[self.0];
 
         (range.start..range.end).map(|id| IntVariable {
+            model: model.id,
             scale: 1,
             offset: 0,
             id,
@@ -311,20 +1071,58 @@ impl IntVariableArray {
 pub enum Output {
     Variable(IntVariable),
     Array(IntVariableArray),
+    Boolean(BoolVariable),
 }
 
 #[derive(Clone, Debug)]
 pub struct VariableMap {
+    /// The model from which the map was created.
+    model: ModelId,
     variables: Vec<AffineView<DomainId>>,
     names: Vec<String>,
     arrays: Vec<(String, Range<usize>)>,
+    literals: Vec<Literal>,
+    boolean_names: Vec<String>,
 }
 
 impl VariableMap {
+    /// # Panics
+    /// Panics if `int_var` was not created by the model from which this map was created.
     pub fn to_solver_variable(&self, int_var: IntVariable) -> AffineView<DomainId> {
-        self.variables[int_var.id]
-            .scaled(int_var.scale)
-            .offset(int_var.offset)
+        self.try_to_solver_variable(int_var)
+            .expect("the variable should be created by the model of this map")
+    }
+
+    fn try_to_solver_variable(
+        &self,
+        int_var: IntVariable,
+    ) -> Result<AffineView<DomainId>, ModelError> {
+        self.variables
+            .get(int_var.id)
+            .filter(|_| int_var.model == self.model)
+            .map(|variable| variable.scaled(int_var.scale).offset(int_var.offset))
+            .ok_or(ModelError::ForeignVariable)
+    }
+
+    fn try_to_solver_literal(&self, bool_var: BoolVariable) -> Result<Literal, ModelError> {
+        let literal = *self
+            .literals
+            .get(bool_var.id)
+            .filter(|_| bool_var.model == self.model)
+            .ok_or(ModelError::ForeignVariable)?;
+
+        if bool_var.negated {
+            Ok(!literal)
+        } else {
+            Ok(literal)
+        }
+    }
+
+    /// # Panics
+    /// Panics if `bool_var` was not created by the model from which this map was created.
+    pub fn to_solver_literal(&self, bool_var: BoolVariable) -> Literal {
+        self.try_to_solver_literal(bool_var)
+            .expect("the variable should be created by the model of this map")
     }
 
     pub fn to_solver_variables<'this, I>(
@@ -358,6 +1156,16 @@ impl VariableMap {
             }
 
             Output::Array(int_variable_array) => self.arrays[int_variable_array.0].0.clone(),
+
+            Output::Boolean(bool_var) => {
+                let name = self.boolean_names[bool_var.id].clone();
+
+                if bool_var.negated {
+                    format!("!{name}")
+                } else {
+                    name
+                }
+            }
         }
     }
 
@@ -380,4 +1188,227 @@ pub enum Globals {
     ForwardCheckingCircuit,
     TimeTableCumulative,
     EnergeticReasoningCumulative,
+    Among,
+    Count,
+    GlobalCardinality,
+    Inverse,
+    Disjunctive,
+    BinPacking,
+    Diffn,
+    DiffnCumulativeProjections,
+    SubCircuit,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn into_solver(model: Model) -> (Solver, VariableMap) {
+        model
+            .into_solver(SolverOptions::default(), |_| false)
+            .expect("the model is consistent")
+    }
+
+    #[test]
+    fn sparse_domains_are_created_in_the_solver() {
+        let mut model = Model::default();
+        let x = model.new_sparse_variable("x", vec![1, 3, 5]);
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x],
+            rhs: 4,
+        });
+
+        let (solver, variables) = into_solver(model);
+        let x = variables.to_solver_variable(x);
+
+        assert_eq!(1, solver.lower_bound(&x));
+        assert_eq!(3, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn reified_constraint_is_enforced_when_reification_is_true() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 5);
+        let r = model.new_boolean_variable("r");
+
+        model.add_constraint(Constraint::Clause(vec![r]));
+        model.add_constraint(
+            Constraint::LinearLessEqual {
+                terms: vec![x],
+                rhs: 2,
+            }
+            .reified_by(r),
+        );
+
+        let (solver, variables) = into_solver(model);
+        let x = variables.to_solver_variable(x);
+
+        assert_eq!(2, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn reification_is_propagated_from_the_constraint() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 3, 5);
+        let r = model.new_boolean_variable("r");
+
+        model.add_constraint(
+            Constraint::LinearLessEqual {
+                terms: vec![x],
+                rhs: 2,
+            }
+            .reified_by(r),
+        );
+
+        let (solver, variables) = into_solver(model);
+
+        assert_eq!(
+            Some(false),
+            solver.get_literal_value(variables.to_solver_literal(r))
+        );
+    }
+
+    #[test]
+    fn half_reified_constraint_with_negated_literal() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 5);
+        let r = model.new_boolean_variable("r");
+
+        model.add_constraint(Constraint::Clause(vec![!r]));
+        model.add_constraint(
+            Constraint::LinearEqual {
+                terms: vec![x],
+                rhs: 1,
+            }
+            .implied_by(!r),
+        );
+
+        let (solver, variables) = into_solver(model);
+        let x = variables.to_solver_variable(x);
+
+        assert_eq!(1, solver.lower_bound(&x));
+        assert_eq!(1, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn empty_domain_is_reported() {
+        let mut model = Model::default();
+        let _ = model.new_interval_variable("x", 5, 3);
+
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+
+        assert!(matches!(result, Err(ModelError::EmptyDomain(name)) if name == "x"));
+    }
+
+    #[test]
+    fn variable_from_another_model_is_reported() {
+        let mut other = Model::default();
+        let _ = other.new_interval_variable("x", 0, 5);
+        let y = other.new_interval_variable("y", 0, 5);
+
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 5);
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x, y],
+            rhs: 4,
+        });
+
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+
+        assert!(matches!(result, Err(ModelError::ForeignVariable)));
+    }
+
+    #[test]
+    fn variable_from_another_model_with_existing_id_is_reported() {
+        let mut other = Model::default();
+        let x_other = other.new_interval_variable("x", 0, 5);
+        let r_other = other.new_boolean_variable("r");
+
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 5);
+        let _ = model.new_boolean_variable("r");
+        let _ = model.new_interval_variable("y", 0, 5);
+
+        let mut int_model = model.clone();
+        int_model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x, x_other],
+            rhs: 4,
+        });
+        let result = int_model.into_solver(SolverOptions::default(), |_| false);
+        assert!(matches!(result, Err(ModelError::ForeignVariable)));
+
+        model.add_constraint(Constraint::Clause(vec![r_other]));
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+        assert!(matches!(result, Err(ModelError::ForeignVariable)));
+    }
+
+    #[test]
+    fn root_infeasibility_is_tied_to_the_constraint() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 3, 5);
+        let infeasible = Constraint::LinearLessEqual {
+            terms: vec![x],
+            rhs: 2,
+        };
+        model.add_constraint(infeasible.clone());
+
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+
+        assert!(
+            matches!(result, Err(ModelError::Infeasible { constraint, .. }) if *constraint == infeasible)
+        );
+    }
+
+    #[test]
+    fn incompatible_globals_are_reported() {
+        let mut model = Model::default();
+        let successors = (0..3)
+            .map(|i| model.new_interval_variable(format!("s{i}"), 1, 3))
+            .collect();
+        model.add_constraint(Constraint::Circuit(successors));
+
+        let result = model.into_solver(SolverOptions::default(), |global| {
+            matches!(
+                global,
+                Globals::DfsCircuit | Globals::ForwardCheckingCircuit
+            )
+        });
+
+        assert!(matches!(
+            result,
+            Err(ModelError::IncompatibleGlobals(
+                Globals::DfsCircuit,
+                Globals::ForwardCheckingCircuit
+            ))
+        ));
+    }
+
+    #[test]
+    fn constraint_without_negation_cannot_be_fully_reified() {
+        let mut model = Model::default();
+        let variables = (0..3)
+            .map(|i| model.new_interval_variable(format!("x{i}"), 1, 3))
+            .collect();
+        let r = model.new_boolean_variable("r");
+        model.add_constraint(Constraint::AllDifferent(variables).reified_by(r));
+
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+
+        assert!(matches!(result, Err(ModelError::NotReifiable(_))));
+    }
+
+    #[test]
+    fn decomposition_without_half_reification_cannot_be_half_reified() {
+        let mut model = Model::default();
+        let terms = (0..3)
+            .map(|i| model.new_interval_variable(format!("x{i}"), 1, 3))
+            .collect();
+        let rhs = model.new_interval_variable("m", 1, 3);
+        let r = model.new_boolean_variable("r");
+        model.add_constraint(Constraint::Maximum { terms, rhs }.implied_by(r));
+
+        let result = model.into_solver(SolverOptions::default(), |_| false);
+
+        assert!(matches!(result, Err(ModelError::NotHalfReifiable(_))));
+    }
 }
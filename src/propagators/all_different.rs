@@ -22,6 +22,10 @@ impl<Var: IntegerVariable + 'static> Propagator for AllDifferentPropagator<Var>
         "AllDifferent"
     }
 
+    fn priority(&self) -> u32 {
+        1
+    }
+
     fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
         todo!()
     }
@@ -0,0 +1,65 @@
+use super::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `\sum x_i = c`, which propagates the bounds of the terms through
+/// the inequalities `\sum x_i <= c` and `\sum -x_i <= -c`.
+///
+/// It is used as the negation of the linear not-equal propagator when that constraint is reified,
+/// such that a single propagator detects the inconsistency and the entailment of both directions.
+#[derive(Debug)]
+pub(crate) struct LinearEqualPropagator<Var: IntegerVariable> {
+    at_most: LinearLessOrEqualPropagator<Var>,
+    at_least: LinearLessOrEqualPropagator<Var::AffineView>,
+}
+
+impl<Var: IntegerVariable> LinearEqualPropagator<Var> {
+    pub(crate) fn new(terms: Box<[Var]>, rhs: i32) -> Self {
+        let negated_terms = terms.iter().map(|term| term.scaled(-1)).collect();
+
+        LinearEqualPropagator {
+            at_most: LinearLessOrEqualPropagator::new(terms, rhs),
+            at_least: LinearLessOrEqualPropagator::new(negated_terms, -rhs),
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for LinearEqualPropagator<Var> {
+    fn name(&self) -> &str {
+        "LinearEq"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.at_most.initialise_at_root(context)?;
+        self.at_least.initialise_at_root(context)
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.at_most
+            .detect_inconsistency(context)
+            .or_else(|| self.at_least.detect_inconsistency(context))
+    }
+
+    fn detect_entailment(&self, context: PropagationContext) -> Option<PropositionalConjunction> {
+        let at_most = self.at_most.detect_entailment(context)?;
+        let at_least = self.at_least.detect_entailment(context)?;
+
+        Some(at_most.iter().chain(at_least.iter()).copied().collect())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.at_most.propagate_upper_bounds(&mut context)?;
+        self.at_least.propagate_upper_bounds(&mut context)
+    }
+}
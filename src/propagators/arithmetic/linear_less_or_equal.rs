@@ -30,6 +30,38 @@ impl<Var: IntegerVariable> LinearLessOrEqualPropagator<Var> {
             .map(|term| context.lower_bound(term))
             .sum()
     }
+
+    /// Tightens the upper bounds of the terms such that the sum of the lower bounds of the other
+    /// terms does not exceed the right-hand side.
+    pub(super) fn propagate_upper_bounds(
+        &self,
+        context: &mut PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let optimistic_lhs = self.get_optimistic_lhs(context.as_readonly());
+
+        for (i, term) in self.terms.iter().enumerate() {
+            let bound = self.rhs - (optimistic_lhs - context.lower_bound(term));
+
+            if context.upper_bound(term) >= bound {
+                let reason: PropositionalConjunction = self
+                    .terms
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(j, x_j)| {
+                        if j != i {
+                            Some(predicate![x_j >= context.lower_bound(x_j)])
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                context.set_upper_bound(term, bound, reason)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<Var: IntegerVariable + 'static> Propagator for LinearLessOrEqualPropagator<Var> {
@@ -37,6 +69,10 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearLessOrEqualPropagator<
         "LinearLeq"
     }
 
+    fn priority(&self) -> u32 {
+        0
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -70,30 +106,30 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearLessOrEqualPropagator<
         }
     }
 
-    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
-        let optimistic_lhs = self.get_optimistic_lhs(context.as_readonly());
-
-        for (i, term) in self.terms.iter().enumerate() {
-            let bound = self.rhs - (optimistic_lhs - context.lower_bound(term));
+    fn detect_entailment(&self, context: PropagationContext) -> Option<PropositionalConjunction> {
+        let pessimistic_lhs: i32 = self
+            .terms
+            .iter()
+            .map(|term| context.upper_bound(term))
+            .sum();
 
-            if context.upper_bound(term) >= bound {
-                let reason: PropositionalConjunction = self
-                    .terms
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        if pessimistic_lhs <= self.rhs {
+            let explanation = self
+                .terms
+                .iter()
+                .map(|term| {
+                    let value = context.upper_bound(term);
+                    predicate![term <= value]
+                })
+                .collect();
 
-                context.set_upper_bound(term, bound, reason)?;
-            }
+            Some(explanation)
+        } else {
+            None
         }
+    }
 
-        Ok(())
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_upper_bounds(&mut context)
     }
 }
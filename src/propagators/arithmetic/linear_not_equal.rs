@@ -7,24 +7,53 @@ use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorInitialisationContext;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::predicate;
+use crate::predicates::Predicate;
 use crate::variables::IntegerVariable;
 
-/// Propagator for the constraint `\sum x_i != rhs`, where `x_i` are
-/// integer variables and `rhs` is an integer constant.
+/// Propagator for the constraint `\sum w_i * x_i != rhs`, where `x_i` are integer variables, `w_i`
+/// are integer weights and `rhs` is an integer constant.
+///
+/// Besides removing the value of the last unfixed term, the propagator detects when the
+/// constraint is entailed (see [`Propagator::detect_entailment`]); this is the case when the
+/// bounds of the terms exclude `rhs`, or when the last unfixed term cannot take the value which
+/// would make the sum equal to `rhs`.
 #[derive(Debug)]
 pub(crate) struct LinearNotEqualPropagator<Var> {
     terms: Box<[Var]>,
+    weights: Box<[i32]>,
     rhs: i32,
-    // TODO: you can add more fields here!
 }
 
 impl<Var> LinearNotEqualPropagator<Var> {
-    pub(crate) fn new(terms: Box<[Var]>, rhs: i32) -> Self {
-        Self { terms, rhs }
+    /// Creates the propagator for `\sum weights_i * terms_i != rhs`. Terms with a weight of zero
+    /// do not contribute to the sum and are dropped.
+    pub(crate) fn new(terms: Box<[Var]>, weights: Box<[i32]>, rhs: i32) -> Self {
+        assert_eq!(
+            terms.len(),
+            weights.len(),
+            "Every term of a linear constraint should have a weight"
+        );
+
+        let (terms, weights) = terms
+            .into_vec()
+            .into_iter()
+            .zip(weights.into_vec())
+            .filter(|&(_, weight)| weight != 0)
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        LinearNotEqualPropagator {
+            terms: terms.into(),
+            weights: weights.into(),
+            rhs,
+        }
     }
 }
 
 impl<Var: IntegerVariable> LinearNotEqualPropagator<Var> {
+    fn weighted_terms(&self) -> impl Iterator<Item = (&Var, i32)> + '_ {
+        self.terms.iter().zip(self.weights.iter().copied())
+    }
+
     fn get_fixed_term_count(&self, context: PropagationContext<'_>) -> usize {
         self.terms
             .iter()
@@ -32,9 +61,19 @@ impl<Var: IntegerVariable> LinearNotEqualPropagator<Var> {
             .count()
     }
 
-    fn get_conflict(&self, context: PropagationContext<'_>) -> PropositionalConjunction {
+    /// The weighted sum of the fixed terms.
+    fn get_fixed_lhs(&self, context: PropagationContext<'_>) -> i32 {
+        self.weighted_terms()
+            .filter(|(term, _)| context.is_fixed(*term))
+            .map(|(term, weight)| weight * context.lower_bound(term))
+            .sum()
+    }
+
+    /// Explains the values of the fixed terms.
+    fn explain_fixed_terms(&self, context: PropagationContext<'_>) -> Vec<Predicate> {
         self.terms
             .iter()
+            .filter(|term| context.is_fixed(*term))
             .map(|term| {
                 let value = context.lower_bound(term);
                 predicate![term == value]
@@ -42,16 +81,74 @@ impl<Var: IntegerVariable> LinearNotEqualPropagator<Var> {
             .collect()
     }
 
-    fn get_fixed_terms<'this, 'context>(
-        &'this self,
-        context: PropagationContext<'context>,
-    ) -> impl Iterator<Item = &'this Var> + 'context
-    where
-        'this: 'context,
-    {
-        self.terms
-            .iter()
-            .filter(move |term| context.is_fixed(*term))
+    /// Returns the only unfixed term with its weight, if all other terms are fixed.
+    fn get_last_unfixed_term(&self, context: PropagationContext<'_>) -> Option<(&Var, i32)> {
+        if self.get_fixed_term_count(context) + 1 != self.terms.len() {
+            return None;
+        }
+
+        self.weighted_terms()
+            .find(|(term, _)| !context.is_fixed(*term))
+    }
+
+    /// Explains that the weighted sum is at least `rhs + 1`, if the lower bounds of the terms
+    /// (for positive weights) and the upper bounds (for negative weights) imply it.
+    fn explain_sum_above_rhs(&self, context: PropagationContext<'_>) -> Option<Vec<Predicate>> {
+        let minimum: i64 = self
+            .weighted_terms()
+            .map(|(term, weight)| {
+                let bound = if weight > 0 {
+                    context.lower_bound(term)
+                } else {
+                    context.upper_bound(term)
+                };
+                weight as i64 * bound as i64
+            })
+            .sum();
+
+        (minimum > self.rhs as i64).then(|| {
+            self.weighted_terms()
+                .map(|(term, weight)| {
+                    if weight > 0 {
+                        let bound = context.lower_bound(term);
+                        predicate![term >= bound]
+                    } else {
+                        let bound = context.upper_bound(term);
+                        predicate![term <= bound]
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Explains that the weighted sum is at most `rhs - 1`, analogous to
+    /// [`LinearNotEqualPropagator::explain_sum_above_rhs`].
+    fn explain_sum_below_rhs(&self, context: PropagationContext<'_>) -> Option<Vec<Predicate>> {
+        let maximum: i64 = self
+            .weighted_terms()
+            .map(|(term, weight)| {
+                let bound = if weight > 0 {
+                    context.upper_bound(term)
+                } else {
+                    context.lower_bound(term)
+                };
+                weight as i64 * bound as i64
+            })
+            .sum();
+
+        (maximum < self.rhs as i64).then(|| {
+            self.weighted_terms()
+                .map(|(term, weight)| {
+                    if weight > 0 {
+                        let bound = context.upper_bound(term);
+                        predicate![term <= bound]
+                    } else {
+                        let bound = context.lower_bound(term);
+                        predicate![term >= bound]
+                    }
+                })
+                .collect()
+        })
     }
 }
 
@@ -60,34 +157,27 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearNotEqualPropagator<Var
         "LinearNe"
     }
 
-    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
-        let fixed_count = self.get_fixed_term_count(context.as_readonly());
-
-        let fixed_lhs: i32 = self
-            .get_fixed_terms(context.as_readonly())
-            .map(|term| context.lower_bound(term))
-            .sum();
+    fn priority(&self) -> u32 {
+        0
+    }
 
-        if fixed_count == self.terms.len() && fixed_lhs == self.rhs {
-            return Err(self.get_conflict(context.as_readonly()).into());
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
         }
 
-        if fixed_count + 1 == self.terms.len() {
-            let unfixed_term = self
-                .terms
-                .iter()
-                .find(|term| !context.is_fixed(*term))
-                .expect("there should be exactly 1 unfixed term");
-
-            let reason: PropositionalConjunction = self
-                .get_fixed_terms(context.as_readonly())
-                .map(|term| {
-                    let value = context.lower_bound(term);
-                    predicate![term == value]
-                })
-                .collect();
-
-            context.remove(unfixed_term, self.rhs - fixed_lhs, reason)?;
+        if let Some((unfixed_term, weight)) = self.get_last_unfixed_term(context.as_readonly()) {
+            let remainder = self.rhs - self.get_fixed_lhs(context.as_readonly());
+
+            // If the weight does not divide the remainder, the constraint is already satisfied.
+            if remainder % weight == 0 {
+                let reason = self.explain_fixed_terms(context.as_readonly());
+                context.remove(
+                    unfixed_term,
+                    remainder / weight,
+                    PropositionalConjunction::from(reason),
+                )?;
+            }
         }
 
         Ok(())
@@ -106,25 +196,44 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearNotEqualPropagator<Var
 
         // All terms are assigned at this point. So the lower bound equals the upper bound of every
         // term.
-        let lhs: i32 = self
-            .terms
-            .iter()
-            .map(|term| context.lower_bound(term))
-            .sum();
-
-        if lhs == self.rhs {
-            Some(self.get_conflict(context))
+        if self.get_fixed_lhs(context) == self.rhs {
+            Some(self.explain_fixed_terms(context).into())
         } else {
             None
         }
     }
 
+    fn detect_entailment(&self, context: PropagationContext) -> Option<PropositionalConjunction> {
+        if let Some(explanation) = self
+            .explain_sum_above_rhs(context)
+            .or_else(|| self.explain_sum_below_rhs(context))
+        {
+            return Some(explanation.into());
+        }
+
+        let (unfixed_term, weight) = self.get_last_unfixed_term(context)?;
+        let remainder = self.rhs - self.get_fixed_lhs(context);
+
+        let mut explanation = self.explain_fixed_terms(context);
+        if remainder % weight != 0 {
+            return Some(explanation.into());
+        }
+
+        let value = remainder / weight;
+        if context.contains(unfixed_term, value) {
+            return None;
+        }
+
+        explanation.push(predicate![unfixed_term != value]);
+        Some(explanation.into())
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
         for term in self.terms.iter() {
-            context.register(term.clone(), DomainEvents::ASSIGN);
+            context.register(term.clone(), DomainEvents::ANY_INT);
         }
 
         Ok(())
@@ -30,6 +30,10 @@ where
         "Maximum"
     }
 
+    fn priority(&self) -> u32 {
+        0
+    }
+
     fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
         todo!()
     }
@@ -1,3 +1,4 @@
+pub(crate) mod linear_equal;
 pub(crate) mod linear_less_or_equal;
 pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
@@ -0,0 +1,336 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the bin-packing constraint, which states that `loads[j]` is the sum of the
+/// sizes of the items `i` with `bins[i] = j`. The bins are 0-indexed, so all `bins[i]` take
+/// values in `0..m` where `m` is the number of loads.
+///
+/// The propagator implements the rules of \[1\]:
+/// - The load of a bin is at least the size of the items which are packed into it, and at most
+///   the size of the items which can be packed into it.
+/// - The loads sum up to the total size of the items.
+/// - An item is eliminated from a bin if it does not fit next to the packed items, and it is
+///   committed to a bin if the bin cannot reach its minimum load without it.
+/// - The bins are normalised to a common capacity and the lower bound L2 of \[2\] on the number
+///   of bins needed for the items which are not yet packed is used to detect failure.
+///
+/// The explanations consist of the item assignment predicates `[bins[i] = j]` and
+/// `[bins[i] != j]`, together with the bounds on the loads.
+///
+/// # Bibliography
+/// \[1\] P. Shaw, ‘A constraint for bin packing’, in Principles and Practice of Constraint
+/// Programming (CP 2004), 2004, pp. 648–662.
+///
+/// \[2\] S. Martello and P. Toth, ‘Lower bounds and reduction procedures for the bin packing
+/// problem’, Discrete Applied Mathematics, vol. 28, no. 1, pp. 59–70, 1990.
+#[derive(Debug)]
+pub(crate) struct BinPackingPropagator<Bin, Load> {
+    bins: Box<[Bin]>,
+    sizes: Box<[u32]>,
+    loads: Box<[Load]>,
+}
+
+impl<Bin, Load> BinPackingPropagator<Bin, Load> {
+    pub(crate) fn new(bins: Box<[Bin]>, sizes: Box<[u32]>, loads: Box<[Load]>) -> Self {
+        assert_eq!(
+            bins.len(),
+            sizes.len(),
+            "Every item of a bin-packing constraint should have a size"
+        );
+
+        BinPackingPropagator { bins, sizes, loads }
+    }
+}
+
+/// The items which are packed into a bin and the items which can be packed into it.
+struct BinContents {
+    /// The items for which `bins[i] = j`.
+    packed: Vec<usize>,
+    /// The items for which `j` is in the domain of `bins[i]`; this includes the packed items.
+    candidates: Vec<usize>,
+    /// The total size of the packed items.
+    packed_size: i32,
+    /// The total size of the candidate items.
+    candidate_size: i32,
+}
+
+impl<Bin: IntegerVariable, Load: IntegerVariable> BinPackingPropagator<Bin, Load> {
+    fn size(&self, item: usize) -> i32 {
+        self.sizes[item] as i32
+    }
+
+    fn contents(&self, context: PropagationContext, bin: usize) -> BinContents {
+        let bin = bin as i32;
+
+        let candidates: Vec<usize> = (0..self.bins.len())
+            .filter(|&item| context.contains(&self.bins[item], bin))
+            .collect();
+        let packed: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&item| context.is_fixed(&self.bins[item]))
+            .collect();
+
+        BinContents {
+            packed_size: packed.iter().map(|&item| self.size(item)).sum(),
+            candidate_size: candidates.iter().map(|&item| self.size(item)).sum(),
+            packed,
+            candidates,
+        }
+    }
+
+    /// Explains that the `packed` items are assigned to `bin`.
+    fn explain_packed(&self, packed: &[usize], bin: usize) -> Vec<Predicate> {
+        let bin = bin as i32;
+
+        packed
+            .iter()
+            .map(|&item| {
+                let variable = &self.bins[item];
+                predicate![variable == bin]
+            })
+            .collect()
+    }
+
+    /// Explains that only the `candidates` can be assigned to `bin`.
+    fn explain_candidates(&self, candidates: &[usize], bin: usize) -> Vec<Predicate> {
+        let bin = bin as i32;
+
+        (0..self.bins.len())
+            .filter(|item| !candidates.contains(item))
+            .map(|item| {
+                let variable = &self.bins[item];
+                predicate![variable != bin]
+            })
+            .collect()
+    }
+
+    /// Bounds every load by the items which are (or can be) packed into its bin.
+    fn propagate_load_maintenance(
+        &self,
+        context: &mut PropagationContextMut,
+    ) -> PropagationStatusCP {
+        for (bin, load) in self.loads.iter().enumerate() {
+            let contents = self.contents(context.as_readonly(), bin);
+
+            context.set_lower_bound(
+                load,
+                contents.packed_size,
+                PropositionalConjunction::from(self.explain_packed(&contents.packed, bin)),
+            )?;
+            context.set_upper_bound(
+                load,
+                contents.candidate_size,
+                PropositionalConjunction::from(self.explain_candidates(&contents.candidates, bin)),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Bounds every load by the bounds of the other loads, as the loads sum up to the total size
+    /// of the items.
+    fn propagate_load_coherence(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let total_size: i32 = (0..self.sizes.len()).map(|item| self.size(item)).sum();
+
+        for (bin, load) in self.loads.iter().enumerate() {
+            let others = || {
+                self.loads
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(other, _)| other != bin)
+                    .map(|(_, other)| other)
+            };
+
+            let reason: PropositionalConjunction = others()
+                .map(|other| {
+                    let upper_bound = context.upper_bound(other);
+                    predicate![other <= upper_bound]
+                })
+                .collect();
+            let others_upper_bound: i32 = others().map(|other| context.upper_bound(other)).sum();
+            context.set_lower_bound(load, total_size - others_upper_bound, reason)?;
+
+            let reason: PropositionalConjunction = others()
+                .map(|other| {
+                    let lower_bound = context.lower_bound(other);
+                    predicate![other >= lower_bound]
+                })
+                .collect();
+            let others_lower_bound: i32 = others().map(|other| context.lower_bound(other)).sum();
+            context.set_upper_bound(load, total_size - others_lower_bound, reason)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes a bin from the domain of an item if the item does not fit into it, and assigns an
+    /// item to a bin if the bin cannot reach its minimum load without it.
+    fn propagate_items(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for (bin, load) in self.loads.iter().enumerate() {
+            let contents = self.contents(context.as_readonly(), bin);
+
+            for &item in contents.candidates.iter() {
+                let variable = &self.bins[item];
+                if context.is_fixed(variable) {
+                    continue;
+                }
+
+                let size = self.size(item);
+
+                if contents.packed_size + size > context.upper_bound(load) {
+                    let maximum_load = contents.packed_size + size - 1;
+                    let mut reason = self.explain_packed(&contents.packed, bin);
+                    reason.push(predicate![load <= maximum_load]);
+
+                    context.remove(variable, bin as i32, PropositionalConjunction::from(reason))?;
+                } else if contents.candidate_size - size < context.lower_bound(load) {
+                    let minimum_load = contents.candidate_size - size + 1;
+                    let mut reason = self.explain_candidates(&contents.candidates, bin);
+                    reason.push(predicate![load >= minimum_load]);
+
+                    let reason = PropositionalConjunction::from(reason);
+                    context.set_lower_bound(variable, bin as i32, reason.clone())?;
+                    context.set_upper_bound(variable, bin as i32, reason)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports a conflict if the items which are not yet packed need more bins than there are,
+    /// when every bin is normalised to the largest maximum load.
+    fn detect_lower_bound_conflict(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let capacity = self
+            .loads
+            .iter()
+            .map(|load| context.upper_bound(load))
+            .max()?;
+        if capacity <= 0 {
+            return None;
+        }
+
+        let mut sizes: Vec<i32> = (0..self.bins.len())
+            .filter(|&item| !context.is_fixed(&self.bins[item]) && self.size(item) > 0)
+            .map(|item| self.size(item))
+            .collect();
+
+        let mut explanation = Vec::new();
+
+        for (bin, load) in self.loads.iter().enumerate() {
+            let contents = self.contents(context, bin);
+            let upper_bound = context.upper_bound(load);
+
+            if contents.packed_size > upper_bound {
+                // This is detected by the load maintenance rule.
+                return None;
+            }
+
+            // The part of the bin which cannot be used by the items which are not yet packed.
+            let unusable = capacity - upper_bound + contents.packed_size;
+            if unusable > 0 {
+                sizes.push(unusable);
+            }
+
+            explanation.extend(self.explain_packed(&contents.packed, bin));
+            explanation.push(predicate![load <= upper_bound]);
+        }
+
+        (lower_bound_l2(&sizes, capacity) > self.loads.len() as i64).then(|| explanation.into())
+    }
+}
+
+/// Computes the lower bound L2 of Martello and Toth on the number of bins with the given capacity
+/// which are needed to pack items with the given sizes. All sizes are at most the capacity.
+fn lower_bound_l2(sizes: &[i32], capacity: i32) -> i64 {
+    let capacity = capacity as i64;
+    let sizes: Vec<i64> = sizes.iter().map(|&size| size as i64).collect();
+
+    std::iter::once(0)
+        .chain(sizes.iter().copied().filter(|&size| 2 * size <= capacity))
+        .map(|k| {
+            // Items which cannot share a bin with any item of size at least `k`.
+            let large = sizes.iter().filter(|&&size| size > capacity - k).count() as i64;
+
+            // Items which each need their own bin, but leave room for items of size at least `k`.
+            let medium: Vec<i64> = sizes
+                .iter()
+                .copied()
+                .filter(|&size| size <= capacity - k && 2 * size > capacity)
+                .collect();
+            let medium_count = medium.len() as i64;
+            let medium_room = medium_count * capacity - medium.iter().sum::<i64>();
+
+            // Items which have to be packed in the room left by the medium items or in new bins.
+            let small_size: i64 = sizes
+                .iter()
+                .copied()
+                .filter(|&size| size >= k && 2 * size <= capacity)
+                .sum();
+
+            let overflow = (small_size - medium_room).max(0);
+            large + medium_count + (overflow + capacity - 1) / capacity
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl<Bin, Load> Propagator for BinPackingPropagator<Bin, Load>
+where
+    Bin: IntegerVariable + 'static,
+    Load: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "BinPacking"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.bins.iter() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+        for load in self.loads.iter() {
+            context.register(load.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let number_of_bins = self.loads.len() as i32;
+        for variable in self.bins.iter() {
+            context.set_lower_bound(variable, 0, conjunction!())?;
+            context.set_upper_bound(variable, number_of_bins - 1, conjunction!())?;
+        }
+
+        self.propagate_load_maintenance(&mut context)?;
+        self.propagate_load_coherence(&mut context)?;
+        self.propagate_items(&mut context)?;
+
+        if let Some(conflict) = self.detect_lower_bound_conflict(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        Ok(())
+    }
+}
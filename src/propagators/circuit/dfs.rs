@@ -8,7 +8,7 @@ use crate::predicates::PropositionalConjunction;
 use crate::variables::IntegerVariable;
 
 //added 3 crates
-use crate::conjunction; 
+use crate::conjunction;
 use crate::engine::cp::domain_events::DomainEvents;
 use crate::engine::cp::propagation::propagation_context::ReadDomains;
 
@@ -27,6 +27,10 @@ impl<Var: IntegerVariable + 'static> Propagator for DfsCircuitPropagator<Var> {
         "DfsCircuit"
     }
 
+    fn priority(&self) -> u32 {
+        3
+    }
+
     fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let n = self.successor.len();
 
@@ -55,7 +59,7 @@ impl<Var: IntegerVariable + 'static> Propagator for DfsCircuitPropagator<Var> {
         // case 2: unfixed variables
         // for all unfixed variable, do a dfs extension for each 'candidate' value and return cyclesize
         // choose lower bound strategy until a cycle is encountered
-        // remove lesser candidate for better performing candidate 
+        // remove lesser candidate for better performing candidate
         // if more candidates have maximum cyclesize, use the candidate with the highest value.
         for i in 0..n {
             if !context.is_fixed(&self.successor[i]) {
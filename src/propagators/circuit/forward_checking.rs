@@ -28,6 +28,10 @@ impl<Var: IntegerVariable + 'static> Propagator for ForwardCheckingCircuitPropag
         //line above was first calles "DfsCircuit"
     }
 
+    fn priority(&self) -> u32 {
+        1
+    }
+
     fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let n = self.successor.len();
 
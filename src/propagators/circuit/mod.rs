@@ -1,5 +1,7 @@
 mod dfs;
 mod forward_checking;
+mod subcircuit;
 
 pub(crate) use dfs::*;
 pub(crate) use forward_checking::*;
+pub(crate) use subcircuit::*;
@@ -0,0 +1,174 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator which prevents the `successors` from forming more than one cycle, where
+/// `successors[i] = i` means that node `i` is not part of the circuit. The successors are
+/// 0-indexed, so all variables take values in `0..n`.
+///
+/// The propagator follows the chains of nodes whose successor is fixed (to a node other than
+/// itself):
+/// - If a chain closes into a cycle, every node outside the cycle is excluded from the circuit,
+///   which is explained by the arcs of the cycle.
+/// - If a chain starts at `s` and ends at `e`, the arc `e -> s` would close a cycle. It is removed
+///   if there is a node outside the chain which cannot be excluded, which is explained by the arcs
+///   of the chain and `[successors[m] != m]` for that node `m`.
+///
+/// The propagator does not ensure that the successors are all different; this is left to a
+/// separate all-different constraint.
+#[derive(Debug)]
+pub(crate) struct SubCircuitPropagator<Var> {
+    successors: Box<[Var]>,
+}
+
+impl<Var> SubCircuitPropagator<Var> {
+    pub(crate) fn new(successors: Box<[Var]>) -> Self {
+        SubCircuitPropagator { successors }
+    }
+}
+
+impl<Var: IntegerVariable> SubCircuitPropagator<Var> {
+    /// Returns the successor of `node` if it is fixed to another node.
+    fn fixed_successor(&self, context: PropagationContext, node: usize) -> Option<usize> {
+        let successor = &self.successors[node];
+
+        (context.is_fixed(successor) && context.lower_bound(successor) != node as i32)
+            .then(|| context.lower_bound(successor) as usize)
+    }
+
+    /// Follows the fixed arcs starting at `start`. Returns the visited nodes, and whether they
+    /// form a cycle back to `start`.
+    fn follow_chain(&self, context: PropagationContext, start: usize) -> (Vec<usize>, bool) {
+        let mut chain = vec![start];
+        let mut current = start;
+
+        while let Some(next) = self.fixed_successor(context, current) {
+            if next == start {
+                return (chain, true);
+            }
+
+            if chain.contains(&next) {
+                // A cycle which does not contain `start`; it is found when starting from one of
+                // its own nodes.
+                break;
+            }
+
+            chain.push(next);
+            current = next;
+        }
+
+        (chain, false)
+    }
+
+    /// Explains the fixed arcs between consecutive nodes of `chain`, and the arc from the last
+    /// node back to the first one if `is_cycle`.
+    fn explain_arcs(&self, chain: &[usize], is_cycle: bool) -> Vec<Predicate> {
+        let number_of_arcs = if is_cycle {
+            chain.len()
+        } else {
+            chain.len() - 1
+        };
+
+        (0..number_of_arcs)
+            .map(|index| {
+                let successor = &self.successors[chain[index]];
+                let next = chain[(index + 1) % chain.len()] as i32;
+
+                predicate![successor == next]
+            })
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for SubCircuitPropagator<Var> {
+    fn name(&self) -> &str {
+        "SubCircuit"
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for successor in self.successors.iter() {
+            context.register(successor.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let n = self.successors.len();
+
+        for successor in self.successors.iter() {
+            context.set_lower_bound(successor, 0, conjunction!())?;
+            context.set_upper_bound(successor, n as i32 - 1, conjunction!())?;
+        }
+
+        let mut has_predecessor = vec![false; n];
+        for node in 0..n {
+            if let Some(next) = self.fixed_successor(context.as_readonly(), node) {
+                has_predecessor[next] = true;
+            }
+        }
+
+        for (start, &has_fixed_predecessor) in has_predecessor.iter().enumerate() {
+            if self.fixed_successor(context.as_readonly(), start).is_none() {
+                continue;
+            }
+
+            let (chain, is_cycle) = self.follow_chain(context.as_readonly(), start);
+
+            if is_cycle {
+                // The circuit is complete; all other nodes are excluded.
+                let reason: PropositionalConjunction = self.explain_arcs(&chain, true).into();
+
+                for node in (0..n).filter(|node| !chain.contains(node)) {
+                    let successor = &self.successors[node];
+                    context.set_lower_bound(successor, node as i32, reason.clone())?;
+                    context.set_upper_bound(successor, node as i32, reason.clone())?;
+                }
+
+                return Ok(());
+            }
+
+            if has_fixed_predecessor {
+                // Only the chains are considered from their first node.
+                continue;
+            }
+
+            let end = *chain.last().expect("a chain contains at least its start");
+            let must_be_included = (0..n).find(|&node| {
+                !chain.contains(&node) && !context.contains(&self.successors[node], node as i32)
+            });
+
+            if let Some(node) = must_be_included {
+                let successor = &self.successors[node];
+                let node = node as i32;
+
+                let mut reason = self.explain_arcs(&chain, false);
+                reason.push(predicate![successor != node]);
+
+                context.remove(
+                    &self.successors[end],
+                    start as i32,
+                    PropositionalConjunction::from(reason),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::propagators::explain_absent_value;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `|{ i | x_i \in S }| = c`, where `x_i` are integer variables,
+/// `S` is a set of values and `c` is an integer variable.
+///
+/// The variables whose domain is contained in `S` have to be counted, and the variables whose
+/// domain does not intersect `S` cannot be counted; these give the bounds of `c`. When `c` is at
+/// its lower bound, every variable which can be counted has to be; when `c` is at its upper
+/// bound, every variable which does not have to be counted cannot take a value in `S`.
+#[derive(Debug)]
+pub(crate) struct AmongPropagator<Var, Count> {
+    variables: Box<[Var]>,
+    /// The values in `S`, sorted and without duplicates.
+    values: Box<[i32]>,
+    count: Count,
+}
+
+impl<Var, Count> AmongPropagator<Var, Count> {
+    pub(crate) fn new(variables: Box<[Var]>, values: Box<[i32]>, count: Count) -> Self {
+        let mut values = values.into_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        Self {
+            variables,
+            values: values.into(),
+            count,
+        }
+    }
+}
+
+impl<Var, Count> Propagator for AmongPropagator<Var, Count>
+where
+    Var: IntegerVariable + 'static,
+    Count: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "Among"
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.variables.iter() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+        context.register(self.count.clone(), DomainEvents::BOUNDS);
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        detect_among_inconsistency(context, &self.variables, &self.values, &self.count)
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        propagate_among(&mut context, &self.variables, &self.values, &self.count)
+    }
+}
+
+/// Reports a conflict if the variables which have to be counted exceed the upper bound of
+/// `count`, or if the variables which can be counted do not reach the lower bound of `count`.
+pub(super) fn detect_among_inconsistency<Var: IntegerVariable, Count: IntegerVariable>(
+    context: PropagationContext,
+    variables: &[Var],
+    values: &[i32],
+    count: &Count,
+) -> Option<PropositionalConjunction> {
+    let must_be_counted: Vec<&Var> = variables
+        .iter()
+        .filter(|variable| is_contained_in(context, *variable, values))
+        .collect();
+
+    let upper_bound = context.upper_bound(count);
+    if must_be_counted.len() as i32 > upper_bound {
+        return Some(
+            must_be_counted
+                .into_iter()
+                .flat_map(|variable| explain_contained_in(context, variable, values))
+                .chain(std::iter::once(predicate![count <= upper_bound]))
+                .collect(),
+        );
+    }
+
+    let cannot_be_counted: Vec<&Var> = variables
+        .iter()
+        .filter(|variable| !intersects(context, *variable, values))
+        .collect();
+
+    let lower_bound = context.lower_bound(count);
+    if ((variables.len() - cannot_be_counted.len()) as i32) < lower_bound {
+        return Some(
+            cannot_be_counted
+                .into_iter()
+                .flat_map(|variable| explain_disjoint(context, variable, values))
+                .chain(std::iter::once(predicate![count >= lower_bound]))
+                .collect(),
+        );
+    }
+
+    None
+}
+
+/// Performs the propagation of the among constraint over `variables`, the (sorted) `values` and
+/// `count`.
+pub(super) fn propagate_among<Var: IntegerVariable, Count: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    values: &[i32],
+    count: &Count,
+) -> PropagationStatusCP {
+    let must_be_counted: Vec<bool> = variables
+        .iter()
+        .map(|variable| is_contained_in(context.as_readonly(), variable, values))
+        .collect();
+    let can_be_counted: Vec<bool> = variables
+        .iter()
+        .map(|variable| intersects(context.as_readonly(), variable, values))
+        .collect();
+
+    let num_must_be_counted = must_be_counted.iter().filter(|&&must| must).count() as i32;
+    let num_can_be_counted = can_be_counted.iter().filter(|&&can| can).count() as i32;
+
+    let must_be_counted_reason: PropositionalConjunction = variables
+        .iter()
+        .zip(must_be_counted.iter())
+        .filter(|(_, &must)| must)
+        .flat_map(|(variable, _)| explain_contained_in(context.as_readonly(), variable, values))
+        .collect();
+    let cannot_be_counted_reason: PropositionalConjunction = variables
+        .iter()
+        .zip(can_be_counted.iter())
+        .filter(|(_, &can)| !can)
+        .flat_map(|(variable, _)| explain_disjoint(context.as_readonly(), variable, values))
+        .collect();
+
+    context.set_lower_bound(count, num_must_be_counted, must_be_counted_reason.clone())?;
+    context.set_upper_bound(count, num_can_be_counted, cannot_be_counted_reason.clone())?;
+
+    let lower_bound = context.lower_bound(count);
+    if num_can_be_counted == lower_bound {
+        // Every variable which can be counted has to take a value in `values`.
+        let reason: PropositionalConjunction = cannot_be_counted_reason
+            .iter()
+            .copied()
+            .chain(std::iter::once(predicate![count >= lower_bound]))
+            .collect();
+
+        for (index, variable) in variables.iter().enumerate() {
+            if !can_be_counted[index] || must_be_counted[index] {
+                continue;
+            }
+
+            for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+                if values.binary_search(&value).is_err() {
+                    context.remove(variable, value, reason.clone())?;
+                }
+            }
+        }
+    }
+
+    let upper_bound = context.upper_bound(count);
+    if num_must_be_counted == upper_bound {
+        // None of the variables which do not have to be counted can take a value in `values`.
+        let reason: PropositionalConjunction = must_be_counted_reason
+            .iter()
+            .copied()
+            .chain(std::iter::once(predicate![count <= upper_bound]))
+            .collect();
+
+        for (index, variable) in variables.iter().enumerate() {
+            if !can_be_counted[index] || must_be_counted[index] {
+                continue;
+            }
+
+            for &value in values {
+                context.remove(variable, value, reason.clone())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether every value in the domain of `variable` is in the (sorted) `values`.
+pub(super) fn is_contained_in<Var: IntegerVariable>(
+    context: PropagationContext,
+    variable: &Var,
+    values: &[i32],
+) -> bool {
+    (context.lower_bound(variable)..=context.upper_bound(variable))
+        .all(|value| !context.contains(variable, value) || values.binary_search(&value).is_ok())
+}
+
+/// Whether the domain of `variable` contains a value in `values`.
+pub(super) fn intersects<Var: IntegerVariable>(
+    context: PropagationContext,
+    variable: &Var,
+    values: &[i32],
+) -> bool {
+    values
+        .iter()
+        .any(|&value| context.contains(variable, value))
+}
+
+/// Explains why the domain of `variable` is contained in the (sorted) `values`, by its bounds and
+/// the holes at the values which are not in `values`.
+pub(super) fn explain_contained_in<Var: IntegerVariable>(
+    context: PropagationContext,
+    variable: &Var,
+    values: &[i32],
+) -> Vec<Predicate> {
+    let lower_bound = context.lower_bound(variable);
+    let upper_bound = context.upper_bound(variable);
+
+    [
+        predicate![variable >= lower_bound],
+        predicate![variable <= upper_bound],
+    ]
+    .into_iter()
+    .chain(
+        (lower_bound..=upper_bound)
+            .filter(|value| values.binary_search(value).is_err())
+            .map(|value| predicate![variable != value]),
+    )
+    .collect()
+}
+
+/// Explains why the domain of `variable` does not contain any value in `values`.
+pub(super) fn explain_disjoint<Var: IntegerVariable>(
+    context: PropagationContext,
+    variable: &Var,
+    values: &[i32],
+) -> Vec<Predicate> {
+    let mut explanation = Vec::new();
+    for &value in values {
+        let predicate = explain_absent_value(context, variable, value);
+        if !explanation.contains(&predicate) {
+            explanation.push(predicate);
+        }
+    }
+
+    explanation
+}
@@ -0,0 +1,175 @@
+use super::among::detect_among_inconsistency;
+use super::among::explain_contained_in;
+use super::among::explain_disjoint;
+use super::among::intersects;
+use super::among::is_contained_in;
+use super::among::propagate_among;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::munchkin_assert_simple;
+use crate::predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `\forall k: |{ i | x_i = v_k }| = c_k`, where `x_i`
+/// are integer variables, `v_k` are distinct values and `c_k` are integer variables.
+///
+/// Every value is first propagated in isolation, as in the among propagator. Afterwards, the
+/// counts are propagated with respect to each other: the variables whose domain is contained in
+/// the values have to be counted by some `c_k`, and only the variables whose domain intersects the
+/// values can be counted at all.
+///
+/// Note that this propagator does not enforce bounds consistency on the global cardinality
+/// constraint; its strength is that of the decomposition into among constraints together with the
+/// reasoning over the sum of the counts.
+#[derive(Debug)]
+pub(crate) struct GlobalCardinalityPropagator<Var, Count> {
+    variables: Box<[Var]>,
+    values: Box<[i32]>,
+    counts: Box<[Count]>,
+}
+
+impl<Var, Count> GlobalCardinalityPropagator<Var, Count> {
+    pub(crate) fn new(variables: Box<[Var]>, values: Box<[i32]>, counts: Box<[Count]>) -> Self {
+        munchkin_assert_simple!(
+            values.len() == counts.len(),
+            "There should be exactly one count for every value"
+        );
+        munchkin_assert_simple!(
+            values
+                .iter()
+                .enumerate()
+                .all(|(i, value)| !values[i + 1..].contains(value)),
+            "The values of a global cardinality propagator should be distinct"
+        );
+
+        Self {
+            variables,
+            values,
+            counts,
+        }
+    }
+}
+
+impl<Var, Count> GlobalCardinalityPropagator<Var, Count>
+where
+    Var: IntegerVariable,
+    Count: IntegerVariable,
+{
+    fn sorted_values(&self) -> Vec<i32> {
+        let mut values = self.values.to_vec();
+        values.sort_unstable();
+        values
+    }
+
+    /// Propagates the bounds of the counts with respect to the total number of variables which
+    /// have to be counted and which can be counted.
+    fn propagate_aggregate(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let values = self.sorted_values();
+
+        let contained_reason: PropositionalConjunction = self
+            .variables
+            .iter()
+            .filter(|variable| is_contained_in(context.as_readonly(), *variable, &values))
+            .flat_map(|variable| explain_contained_in(context.as_readonly(), variable, &values))
+            .collect();
+        let num_must_be_counted = self
+            .variables
+            .iter()
+            .filter(|variable| is_contained_in(context.as_readonly(), *variable, &values))
+            .count() as i32;
+
+        let disjoint_reason: PropositionalConjunction = self
+            .variables
+            .iter()
+            .filter(|variable| !intersects(context.as_readonly(), *variable, &values))
+            .flat_map(|variable| explain_disjoint(context.as_readonly(), variable, &values))
+            .collect();
+        let num_can_be_counted = self
+            .variables
+            .iter()
+            .filter(|variable| intersects(context.as_readonly(), *variable, &values))
+            .count() as i32;
+
+        for (k, count) in self.counts.iter().enumerate() {
+            let others = || {
+                self.counts
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(l, _)| l != k)
+                    .map(|(_, other)| other)
+            };
+
+            let others_upper_bound: i32 = others().map(|other| context.upper_bound(other)).sum();
+            let reason: PropositionalConjunction = contained_reason
+                .iter()
+                .copied()
+                .chain(others().map(|other| predicate![other <= context.upper_bound(other)]))
+                .collect();
+            context.set_lower_bound(count, num_must_be_counted - others_upper_bound, reason)?;
+
+            let others_lower_bound: i32 = others().map(|other| context.lower_bound(other)).sum();
+            let reason: PropositionalConjunction = disjoint_reason
+                .iter()
+                .copied()
+                .chain(others().map(|other| predicate![other >= context.lower_bound(other)]))
+                .collect();
+            context.set_upper_bound(count, num_can_be_counted - others_lower_bound, reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var, Count> Propagator for GlobalCardinalityPropagator<Var, Count>
+where
+    Var: IntegerVariable + 'static,
+    Count: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "GlobalCardinality"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.variables.iter() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+        for count in self.counts.iter() {
+            context.register(count.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.values
+            .iter()
+            .zip(self.counts.iter())
+            .find_map(|(&value, count)| {
+                detect_among_inconsistency(context, &self.variables, &[value], count)
+            })
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        for (&value, count) in self.values.iter().zip(self.counts.iter()) {
+            propagate_among(&mut context, &self.variables, &[value], count)?;
+        }
+
+        self.propagate_aggregate(&mut context)
+    }
+}
@@ -36,6 +36,10 @@ impl<Var: IntegerVariable + 'static> Propagator for EnergeticReasoningPropagator
         "EnergeticReasoning"
     }
 
+    fn priority(&self) -> u32 {
+        4
+    }
+
     fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
         todo!()
     }
@@ -1,5 +1,7 @@
 mod energetic_reasoning;
+mod optional_time_table;
 mod time_table;
 
 pub(crate) use energetic_reasoning::*;
+pub(crate) use optional_time_table::*;
 pub(crate) use time_table::*;
@@ -0,0 +1,332 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntervalVariable;
+
+/// A maximal period of time in which the same set of compulsory parts overlap.
+#[derive(Clone, Debug)]
+struct ProfileSegment {
+    /// The first time point of the segment.
+    start: i32,
+    /// The first time point after the segment.
+    end: i32,
+    /// The intervals which have a compulsory part in the segment.
+    tasks: Vec<usize>,
+    /// The sum of the resource requirements of `tasks`.
+    height: u32,
+}
+
+/// A bound of an interval which is derived from a segment of the profile.
+#[derive(Clone, Debug)]
+struct SweepStep {
+    /// The derived bound.
+    bound: i32,
+    /// The explanation of the use of the resource by the other intervals.
+    profile_reason: Vec<Predicate>,
+    /// The bound of the interval which, together with its minimum duration, implies that the
+    /// interval would overlap with the profile.
+    premise: Predicate,
+}
+
+/// Propagator for the cumulative constraint over (optional) [`IntervalVariable`]s, which states
+/// that at no point in time the resource requirements of the present intervals which are executed
+/// at that point exceed the capacity. Absent intervals do not consume the resource.
+///
+/// The propagator performs time-table reasoning: the compulsory part `[ub(start), lb(end))` of
+/// every present interval is added to a resource profile. The profile is used to detect
+/// overloads, to update the bounds of the present intervals and to make an interval with an
+/// unknown presence absent if it cannot be scheduled anywhere within its time window.
+///
+/// The explanations are pointwise: the use of the resource at time `t` is explained by
+/// `[start <= t]`, `[end >= t + 1]` and the presence literal of the intervals in the profile.
+pub(crate) struct OptionalTimeTablePropagator {
+    intervals: Box<[IntervalVariable]>,
+    resource_requirements: Box<[u32]>,
+    resource_capacity: u32,
+}
+
+impl OptionalTimeTablePropagator {
+    pub(crate) fn new(
+        intervals: Box<[IntervalVariable]>,
+        resource_requirements: Box<[u32]>,
+        resource_capacity: u32,
+    ) -> Self {
+        // Intervals which do not require the resource do not interfere with any other interval.
+        let (intervals, resource_requirements): (Vec<_>, Vec<_>) = intervals
+            .into_vec()
+            .into_iter()
+            .zip(resource_requirements.into_vec())
+            .filter(|&(_, requirement)| requirement > 0)
+            .unzip();
+
+        OptionalTimeTablePropagator {
+            intervals: intervals.into(),
+            resource_requirements: resource_requirements.into(),
+            resource_capacity,
+        }
+    }
+
+    /// Builds the resource profile out of the compulsory parts of the present intervals.
+    fn profile(&self, context: PropagationContext) -> Vec<ProfileSegment> {
+        let compulsory_parts: Vec<(usize, i32, i32)> = self
+            .intervals
+            .iter()
+            .enumerate()
+            .filter(|(_, interval)| context.is_literal_true(interval.presence()))
+            .map(|(task, interval)| {
+                (
+                    task,
+                    context.upper_bound(&interval.start()),
+                    context.lower_bound(&interval.end()),
+                )
+            })
+            .filter(|&(_, start, end)| start < end)
+            .collect();
+
+        let mut time_points: Vec<i32> = compulsory_parts
+            .iter()
+            .flat_map(|&(_, start, end)| [start, end])
+            .collect();
+        time_points.sort_unstable();
+        time_points.dedup();
+
+        time_points
+            .windows(2)
+            .filter_map(|window| {
+                let tasks: Vec<usize> = compulsory_parts
+                    .iter()
+                    .filter(|&&(_, start, end)| start <= window[0] && window[1] <= end)
+                    .map(|&(task, _, _)| task)
+                    .collect();
+
+                (!tasks.is_empty()).then(|| ProfileSegment {
+                    start: window[0],
+                    end: window[1],
+                    height: tasks
+                        .iter()
+                        .map(|&task| self.resource_requirements[task])
+                        .sum(),
+                    tasks,
+                })
+            })
+            .collect()
+    }
+
+    /// Explains that the intervals in `segment` (except `excluded`) use the resource at time `t`.
+    fn explain_profile(
+        &self,
+        segment: &ProfileSegment,
+        excluded: Option<usize>,
+        t: i32,
+    ) -> Vec<Predicate> {
+        segment
+            .tasks
+            .iter()
+            .filter(|&&task| Some(task) != excluded)
+            .flat_map(|&task| {
+                let interval = self.intervals[task];
+                let start = interval.start();
+                let end = interval.end();
+
+                [
+                    predicate![start <= t],
+                    predicate![end >= t + 1],
+                    interval.presence().into(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the height of `segment` without the compulsory part of `task`.
+    fn height_without(&self, segment: &ProfileSegment, task: usize) -> u32 {
+        if segment.tasks.contains(&task) {
+            segment.height - self.resource_requirements[task]
+        } else {
+            segment.height
+        }
+    }
+
+    /// Pushes the earliest start time of `task` past the segments of the profile in which it
+    /// cannot be executed. If the interval started before `t + 1`, then `[start >= t + 1 - p]`
+    /// and `[duration >= p]` imply that it would be executed at `t`.
+    fn sweep_earliest_start(
+        &self,
+        context: PropagationContext,
+        profile: &[ProfileSegment],
+        task: usize,
+    ) -> Vec<SweepStep> {
+        let start = self.intervals[task].start();
+        let min_duration = context.lower_bound(&self.intervals[task].duration());
+        let requirement = self.resource_requirements[task];
+
+        let mut earliest_start = context.lower_bound(&start);
+        let mut steps = Vec::new();
+
+        while let Some(segment) = profile.iter().find(|segment| {
+            segment.start < earliest_start + min_duration
+                && segment.end > earliest_start
+                && self.height_without(segment, task) + requirement > self.resource_capacity
+        }) {
+            let t = (segment.end - 1).min(earliest_start + min_duration - 1);
+
+            earliest_start = t + 1;
+            steps.push(SweepStep {
+                bound: earliest_start,
+                profile_reason: self.explain_profile(segment, Some(task), t),
+                premise: predicate![start >= t + 1 - min_duration],
+            });
+        }
+
+        steps
+    }
+
+    /// Pushes the latest completion time of `task` before the segments of the profile in which it
+    /// cannot be executed.
+    fn sweep_latest_end(
+        &self,
+        context: PropagationContext,
+        profile: &[ProfileSegment],
+        task: usize,
+    ) -> Vec<SweepStep> {
+        let end = self.intervals[task].end();
+        let min_duration = context.lower_bound(&self.intervals[task].duration());
+        let requirement = self.resource_requirements[task];
+
+        let mut latest_end = context.upper_bound(&end);
+        let mut steps = Vec::new();
+
+        while let Some(segment) = profile.iter().rev().find(|segment| {
+            segment.start < latest_end
+                && segment.end > latest_end - min_duration
+                && self.height_without(segment, task) + requirement > self.resource_capacity
+        }) {
+            let t = segment.start.max(latest_end - min_duration);
+
+            latest_end = t;
+            steps.push(SweepStep {
+                bound: latest_end,
+                profile_reason: self.explain_profile(segment, Some(task), t),
+                premise: predicate![end <= t + min_duration],
+            });
+        }
+
+        steps
+    }
+}
+
+impl Propagator for OptionalTimeTablePropagator {
+    fn name(&self) -> &str {
+        "OptionalTimeTable"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for interval in self.intervals.iter() {
+            context.register(interval.start(), DomainEvents::BOUNDS);
+            context.register(interval.end(), DomainEvents::BOUNDS);
+            context.register(interval.duration(), DomainEvents::LOWER_BOUND);
+        }
+
+        for interval in self.intervals.iter() {
+            let local_id = context.get_next_local_id();
+            let _ = context.register_literal(interval.presence(), DomainEvents::ANY_BOOL, local_id);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.profile(context)
+            .iter()
+            .find(|segment| segment.height > self.resource_capacity)
+            .map(|segment| self.explain_profile(segment, None, segment.start).into())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        let profile = self.profile(context.as_readonly());
+
+        for (task, interval) in self.intervals.iter().enumerate() {
+            let presence = interval.presence();
+            let duration = interval.duration();
+            let min_duration = context.lower_bound(&duration);
+
+            if min_duration == 0 || context.is_literal_false(presence) {
+                continue;
+            }
+
+            let steps = self.sweep_earliest_start(context.as_readonly(), &profile, task);
+
+            if context.is_literal_true(presence) {
+                for step in steps {
+                    let reason: PropositionalConjunction = step
+                        .profile_reason
+                        .into_iter()
+                        .chain([
+                            step.premise,
+                            predicate![duration >= min_duration],
+                            presence.into(),
+                        ])
+                        .collect();
+
+                    context.set_lower_bound(&interval.start(), step.bound, reason)?;
+                }
+
+                for step in self.sweep_latest_end(context.as_readonly(), &profile, task) {
+                    let reason: PropositionalConjunction = step
+                        .profile_reason
+                        .into_iter()
+                        .chain([
+                            step.premise,
+                            predicate![duration >= min_duration],
+                            presence.into(),
+                        ])
+                        .collect();
+
+                    context.set_upper_bound(&interval.end(), step.bound, reason)?;
+                }
+            } else {
+                let start = interval.start();
+                let latest_start = context.upper_bound(&start);
+
+                if steps.last().is_some_and(|step| step.bound > latest_start) {
+                    // Only the premise of the first step holds in the current state; the premises
+                    // of the later steps are derived by the earlier steps.
+                    let first_premise = steps[0].premise;
+                    let reason: PropositionalConjunction = steps
+                        .into_iter()
+                        .flat_map(|step| step.profile_reason)
+                        .chain([
+                            first_premise,
+                            predicate![duration >= min_duration],
+                            predicate![start <= latest_start],
+                        ])
+                        .collect();
+
+                    context.assign_literal(presence, false, reason)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -36,6 +36,10 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePropagator<Var> {
         "TimeTable"
     }
 
+    fn priority(&self) -> u32 {
+        2
+    }
+
     fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
         todo!()
     }
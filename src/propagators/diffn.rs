@@ -0,0 +1,434 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// One of the two dimensions in which the rectangles are placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dimension {
+    X,
+    Y,
+}
+
+impl Dimension {
+    fn other(self) -> Dimension {
+        match self {
+            Dimension::X => Dimension::Y,
+            Dimension::Y => Dimension::X,
+        }
+    }
+}
+
+/// A snapshot of the bounds of the origin of a rectangle in one dimension, together with the size
+/// of the rectangle in that dimension.
+#[derive(Clone, Copy, Debug)]
+struct Extent {
+    lower_bound: i32,
+    upper_bound: i32,
+    size: i32,
+}
+
+impl Extent {
+    /// The extent when the dimension runs backwards.
+    fn mirrored(self) -> Extent {
+        Extent {
+            lower_bound: -(self.upper_bound + self.size),
+            upper_bound: -(self.lower_bound + self.size),
+            size: self.size,
+        }
+    }
+
+    /// The part `[ub, lb + size)` which is covered by the rectangle in every placement.
+    fn compulsory_part(self) -> Option<(i32, i32)> {
+        let start = self.upper_bound;
+        let end = self.lower_bound + self.size;
+
+        (start < end).then_some((start, end))
+    }
+}
+
+/// The placements `[x_start, x_end] x [y_start, y_end]` of the origin of a rectangle in which it
+/// would overlap with the compulsory part of `rectangle`.
+#[derive(Clone, Copy, Debug)]
+struct ForbiddenRegion {
+    rectangle: usize,
+    x_start: i32,
+    x_end: i32,
+    y_start: i32,
+    y_end: i32,
+}
+
+/// A bound of a rectangle derived by the sweep. The origin of the rectangle cannot lie in
+/// `[premise, bound)` in the swept dimension, as every such placement overlaps with the
+/// compulsory parts of the `covering` rectangles.
+#[derive(Clone, Debug)]
+struct SweepStep {
+    premise: i32,
+    bound: i32,
+    covering: Vec<usize>,
+}
+
+/// Propagator for the constraint which states that no two rectangles overlap, where rectangle `i`
+/// has its origin at `(xs[i], ys[i])`, and has a fixed width `widths[i]` and height `heights[i]`.
+///
+/// The propagator combines two kinds of reasoning:
+/// - For every pair of rectangles, at least one of them has to be to the left of, to the right
+///   of, above or below the other. If only one of these relations is possible, it is enforced.
+/// - A sweep over every dimension in both directions, based on \[1\], pushes the origin of a
+///   rectangle past the placements in which it would overlap with the compulsory parts of the
+///   other rectangles.
+///
+/// All explanations consist of bound predicates over the origins of the rectangles.
+///
+/// # Bibliography
+/// \[1\] N. Beldiceanu and M. Carlsson, ‘Sweep as a generic pruning technique applied to the
+/// non-overlapping rectangles constraint’, in Principles and Practice of Constraint Programming
+/// (CP 2001), 2001, pp. 377–391.
+#[derive(Debug)]
+pub(crate) struct DiffnPropagator<Var> {
+    xs: Box<[Var]>,
+    ys: Box<[Var]>,
+    widths: Box<[u32]>,
+    heights: Box<[u32]>,
+}
+
+impl<Var> DiffnPropagator<Var> {
+    pub(crate) fn new(
+        xs: Box<[Var]>,
+        ys: Box<[Var]>,
+        widths: Box<[u32]>,
+        heights: Box<[u32]>,
+    ) -> Self {
+        assert!(
+            xs.len() == ys.len() && xs.len() == widths.len() && xs.len() == heights.len(),
+            "Every rectangle of a diffn constraint should have an origin, a width and a height"
+        );
+
+        // Rectangles without an area do not overlap with any other rectangle.
+        let (((xs, ys), widths), heights): (((Vec<_>, Vec<_>), Vec<_>), Vec<_>) = xs
+            .into_vec()
+            .into_iter()
+            .zip(ys.into_vec())
+            .zip(widths.into_vec())
+            .zip(heights.into_vec())
+            .filter(|&((_, width), height)| width > 0 && height > 0)
+            .unzip();
+
+        DiffnPropagator {
+            xs: xs.into(),
+            ys: ys.into(),
+            widths: widths.into(),
+            heights: heights.into(),
+        }
+    }
+}
+
+impl<Var: IntegerVariable> DiffnPropagator<Var> {
+    fn origin(&self, dimension: Dimension, rectangle: usize) -> &Var {
+        match dimension {
+            Dimension::X => &self.xs[rectangle],
+            Dimension::Y => &self.ys[rectangle],
+        }
+    }
+
+    fn size(&self, dimension: Dimension, rectangle: usize) -> i32 {
+        match dimension {
+            Dimension::X => self.widths[rectangle] as i32,
+            Dimension::Y => self.heights[rectangle] as i32,
+        }
+    }
+
+    fn extent(
+        &self,
+        context: PropagationContext,
+        dimension: Dimension,
+        rectangle: usize,
+    ) -> Extent {
+        let origin = self.origin(dimension, rectangle);
+
+        Extent {
+            lower_bound: context.lower_bound(origin),
+            upper_bound: context.upper_bound(origin),
+            size: self.size(dimension, rectangle),
+        }
+    }
+
+    /// Explains the current bounds of the origin of `rectangle` in both dimensions.
+    fn explain_bounds(&self, context: PropagationContext, rectangle: usize) -> Vec<Predicate> {
+        [Dimension::X, Dimension::Y]
+            .into_iter()
+            .flat_map(|dimension| {
+                let origin = self.origin(dimension, rectangle);
+                let lower_bound = context.lower_bound(origin);
+                let upper_bound = context.upper_bound(origin);
+
+                [
+                    predicate![origin >= lower_bound],
+                    predicate![origin <= upper_bound],
+                ]
+            })
+            .collect()
+    }
+
+    /// Whether `before` can be placed entirely before `after` in `dimension`.
+    fn can_precede(
+        &self,
+        context: PropagationContext,
+        dimension: Dimension,
+        before: usize,
+        after: usize,
+    ) -> bool {
+        context.lower_bound(self.origin(dimension, before)) + self.size(dimension, before)
+            <= context.upper_bound(self.origin(dimension, after))
+    }
+
+    /// Explains that `before` cannot be placed entirely before `after` in `dimension`.
+    fn explain_cannot_precede(
+        &self,
+        context: PropagationContext,
+        dimension: Dimension,
+        before: usize,
+        after: usize,
+    ) -> [Predicate; 2] {
+        let before_origin = self.origin(dimension, before);
+        let after_origin = self.origin(dimension, after);
+        let lower_bound = context.lower_bound(before_origin);
+        let size = self.size(dimension, before);
+
+        [
+            predicate![before_origin >= lower_bound],
+            predicate![after_origin <= lower_bound + size - 1],
+        ]
+    }
+
+    /// For every pair of rectangles, enforces the only relative position which is still possible,
+    /// or reports a conflict if none is.
+    fn propagate_pairs(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for i in 0..self.xs.len() {
+            for j in i + 1..self.xs.len() {
+                let relations = [Dimension::X, Dimension::Y]
+                    .into_iter()
+                    .flat_map(|dimension| [(dimension, i, j), (dimension, j, i)]);
+
+                let (possible, impossible): (Vec<_>, Vec<_>) =
+                    relations.partition(|&(dimension, before, after)| {
+                        self.can_precede(context.as_readonly(), dimension, before, after)
+                    });
+
+                if possible.len() > 1 {
+                    continue;
+                }
+
+                let reason: Vec<Predicate> = impossible
+                    .iter()
+                    .flat_map(|&(dimension, before, after)| {
+                        self.explain_cannot_precede(context.as_readonly(), dimension, before, after)
+                    })
+                    .collect();
+
+                let Some(&(dimension, before, after)) = possible.first() else {
+                    return Err(PropositionalConjunction::from(reason).into());
+                };
+
+                let before_origin = self.origin(dimension, before);
+                let after_origin = self.origin(dimension, after);
+                let size = self.size(dimension, before);
+
+                let lower_bound = context.lower_bound(before_origin);
+                let bound_reason: PropositionalConjunction = reason
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(predicate![before_origin >= lower_bound]))
+                    .collect();
+                context.set_lower_bound(after_origin, lower_bound + size, bound_reason)?;
+
+                let upper_bound = context.upper_bound(after_origin);
+                let bound_reason: PropositionalConjunction = reason
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(predicate![after_origin <= upper_bound]))
+                    .collect();
+                context.set_upper_bound(before_origin, upper_bound - size, bound_reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sweeps the origin of `rectangle` forwards in `dimension` (or backwards if `mirrored`) past
+    /// the placements which overlap with the compulsory parts of the other rectangles. The
+    /// returned bounds are in the (possibly mirrored) coordinates of the sweep.
+    fn sweep(
+        &self,
+        context: PropagationContext,
+        dimension: Dimension,
+        mirrored: bool,
+        rectangle: usize,
+    ) -> Vec<SweepStep> {
+        let orient = |extent: Extent| if mirrored { extent.mirrored() } else { extent };
+
+        let primary = orient(self.extent(context, dimension, rectangle));
+        let secondary = self.extent(context, dimension.other(), rectangle);
+
+        let forbidden_regions: Vec<ForbiddenRegion> = (0..self.xs.len())
+            .filter(|&other| other != rectangle)
+            .filter_map(|other| {
+                let (x_start, x_end) =
+                    orient(self.extent(context, dimension, other)).compulsory_part()?;
+                let (y_start, y_end) = self
+                    .extent(context, dimension.other(), other)
+                    .compulsory_part()?;
+
+                Some(ForbiddenRegion {
+                    rectangle: other,
+                    x_start: x_start - primary.size + 1,
+                    x_end: x_end - 1,
+                    y_start: (y_start - secondary.size + 1).max(secondary.lower_bound),
+                    y_end: (y_end - 1).min(secondary.upper_bound),
+                })
+            })
+            .filter(|region| region.y_start <= region.y_end)
+            .collect();
+
+        let mut position = primary.lower_bound;
+        let mut steps = Vec::new();
+
+        while position <= primary.upper_bound {
+            let mut active: Vec<&ForbiddenRegion> = forbidden_regions
+                .iter()
+                .filter(|region| region.x_start <= position && position <= region.x_end)
+                .collect();
+            active.sort_by_key(|region| region.y_start);
+
+            // Greedily cover the possible positions in the other dimension by the active regions.
+            let mut covered_until = secondary.lower_bound - 1;
+            let mut covering: Vec<usize> = Vec::new();
+            let mut next_position = i32::MAX;
+            let mut index = 0;
+
+            while covered_until < secondary.upper_bound {
+                let mut best: Option<&ForbiddenRegion> = None;
+                while index < active.len() && active[index].y_start <= covered_until + 1 {
+                    if best.is_none_or(|region| active[index].y_end > region.y_end) {
+                        best = Some(active[index]);
+                    }
+                    index += 1;
+                }
+
+                let Some(region) = best.filter(|region| region.y_end > covered_until) else {
+                    break;
+                };
+
+                covered_until = region.y_end;
+                covering.push(region.rectangle);
+                next_position = next_position.min(region.x_end + 1);
+            }
+
+            if covered_until < secondary.upper_bound {
+                break;
+            }
+
+            steps.push(SweepStep {
+                premise: position,
+                bound: next_position,
+                covering,
+            });
+            position = next_position;
+        }
+
+        steps
+    }
+
+    fn propagate_sweep(
+        &self,
+        context: &mut PropagationContextMut,
+        dimension: Dimension,
+        mirrored: bool,
+    ) -> PropagationStatusCP {
+        for rectangle in 0..self.xs.len() {
+            let steps = self.sweep(context.as_readonly(), dimension, mirrored, rectangle);
+            if steps.is_empty() {
+                continue;
+            }
+
+            let origin = self.origin(dimension, rectangle);
+            let size = self.size(dimension, rectangle);
+
+            let other_origin = self.origin(dimension.other(), rectangle);
+            let other_lower_bound = context.lower_bound(other_origin);
+            let other_upper_bound = context.upper_bound(other_origin);
+
+            for step in steps {
+                let mut reason: Vec<Predicate> = step
+                    .covering
+                    .iter()
+                    .flat_map(|&other| self.explain_bounds(context.as_readonly(), other))
+                    .collect();
+                reason.push(predicate![other_origin >= other_lower_bound]);
+                reason.push(predicate![other_origin <= other_upper_bound]);
+
+                if mirrored {
+                    let premise = -step.premise - size;
+                    reason.push(predicate![origin <= premise]);
+
+                    context.set_upper_bound(
+                        origin,
+                        -step.bound - size,
+                        PropositionalConjunction::from(reason),
+                    )?;
+                } else {
+                    let premise = step.premise;
+                    reason.push(predicate![origin >= premise]);
+
+                    context.set_lower_bound(
+                        origin,
+                        step.bound,
+                        PropositionalConjunction::from(reason),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DiffnPropagator<Var> {
+    fn name(&self) -> &str {
+        "Diffn"
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.xs.iter().chain(self.ys.iter()) {
+            context.register(variable.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_pairs(&mut context)?;
+
+        for dimension in [Dimension::X, Dimension::Y] {
+            for mirrored in [false, true] {
+                self.propagate_sweep(&mut context, dimension, mirrored)?;
+            }
+        }
+
+        Ok(())
+    }
+}
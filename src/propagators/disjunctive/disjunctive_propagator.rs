@@ -0,0 +1,143 @@
+use super::reasoning::apply_rule;
+use super::reasoning::overload_check;
+use super::reasoning::Bound;
+use super::reasoning::TaskBounds;
+use super::reasoning::Window;
+use super::reasoning::FILTERING_RULES;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the disjunctive (unary resource) constraint, which states that no two tasks
+/// overlap in time.
+///
+/// The propagator implements the Θ-tree based algorithms of \[1\]: overload checking, detectable
+/// precedences, not-first/not-last and edge finding. Every algorithm updates the earliest start
+/// times (or latest completion times for not-last); the other direction is obtained by running
+/// the same algorithm on the mirrored tasks.
+///
+/// The explanations follow the rules which are applied: a set of tasks `Ω` is described by the
+/// bounds `[s_j >= est_Ω]` and `[s_j <= lct_Ω - p_j]` of its tasks.
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+pub(crate) struct DisjunctivePropagator<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[i32]>,
+}
+
+impl<Var: IntegerVariable> DisjunctivePropagator<Var> {
+    pub(crate) fn new(start_times: Box<[Var]>, durations: Box<[u32]>) -> Self {
+        // Tasks without a duration do not interfere with any other task.
+        let (start_times, durations): (Vec<Var>, Vec<i32>) = start_times
+            .into_vec()
+            .into_iter()
+            .zip(durations.into_vec())
+            .filter(|&(_, duration)| duration > 0)
+            .map(|(start_time, duration)| (start_time, duration as i32))
+            .unzip();
+
+        DisjunctivePropagator {
+            start_times: start_times.into(),
+            durations: durations.into(),
+        }
+    }
+
+    fn task_bounds(&self, context: PropagationContext) -> Vec<TaskBounds> {
+        self.start_times
+            .iter()
+            .zip(self.durations.iter())
+            .map(|(start_time, &duration)| TaskBounds {
+                est: context.lower_bound(start_time),
+                lct: context.upper_bound(start_time) + duration,
+                duration,
+            })
+            .collect()
+    }
+
+    fn explain(&self, windows: impl IntoIterator<Item = Window>) -> PropositionalConjunction {
+        windows
+            .into_iter()
+            .flat_map(|window| {
+                let start_time = &self.start_times[window.task];
+                let duration = self.durations[window.task];
+
+                window
+                    .est
+                    .map(|est| predicate![start_time >= est])
+                    .into_iter()
+                    .chain(
+                        window
+                            .lct
+                            .map(|lct| predicate![start_time <= lct - duration]),
+                    )
+            })
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DisjunctivePropagator<Var> {
+    fn name(&self) -> &str {
+        "Disjunctive"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for start_time in self.start_times.iter() {
+            context.register(start_time.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        overload_check(&self.task_bounds(context)).map(|windows| self.explain(windows))
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        for rule in FILTERING_RULES {
+            for mirrored in [false, true] {
+                let bounds = self.task_bounds(context.as_readonly());
+
+                for update in apply_rule(rule, &bounds, mirrored) {
+                    let start_time = &self.start_times[update.task];
+                    let reason = self.explain(update.reason);
+
+                    match update.bound {
+                        Bound::EarliestStart => {
+                            context.set_lower_bound(start_time, update.value, reason)?
+                        }
+                        Bound::LatestCompletion => context.set_upper_bound(
+                            start_time,
+                            update.value - self.durations[update.task],
+                            reason,
+                        )?,
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod disjunctive_propagator;
+mod optional_disjunctive_propagator;
+mod reasoning;
+mod theta_lambda_tree;
+
+pub(crate) use disjunctive_propagator::*;
+pub(crate) use optional_disjunctive_propagator::*;
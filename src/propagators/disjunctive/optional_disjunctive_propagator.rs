@@ -0,0 +1,203 @@
+use super::reasoning::apply_rule;
+use super::reasoning::overload_check;
+use super::reasoning::Bound;
+use super::reasoning::TaskBounds;
+use super::reasoning::Window;
+use super::reasoning::FILTERING_RULES;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntervalVariable;
+
+/// Propagator for the disjunctive constraint over (optional) [`IntervalVariable`]s, which states
+/// that no two present intervals overlap in time.
+///
+/// The filtering rules of [`super::DisjunctivePropagator`] are applied to the intervals which are
+/// known to be present, using the minimum duration of every interval. An interval whose presence
+/// is not yet known is made absent if it cannot be added to the present intervals without
+/// overloading the resource.
+///
+/// A window of an interval in an explanation is described by `[start >= est]`, `[end <= lct]`,
+/// `[duration >= p]` and the presence literal of the interval.
+pub(crate) struct OptionalDisjunctivePropagator {
+    intervals: Box<[IntervalVariable]>,
+}
+
+impl OptionalDisjunctivePropagator {
+    pub(crate) fn new(intervals: Box<[IntervalVariable]>) -> Self {
+        OptionalDisjunctivePropagator { intervals }
+    }
+
+    /// Returns the intervals which are considered by the filtering rules, together with their
+    /// bounds. Intervals without a (minimum) duration do not interfere with any other interval.
+    fn task_bounds(
+        &self,
+        context: PropagationContext,
+        is_considered: impl Fn(&IntervalVariable) -> bool,
+    ) -> (Vec<usize>, Vec<TaskBounds>) {
+        self.intervals
+            .iter()
+            .enumerate()
+            .filter(|(_, interval)| {
+                context.lower_bound(&interval.duration()) > 0 && is_considered(interval)
+            })
+            .map(|(index, interval)| {
+                (
+                    index,
+                    TaskBounds {
+                        est: context.lower_bound(&interval.start()),
+                        lct: context.upper_bound(&interval.end()),
+                        duration: context.lower_bound(&interval.duration()),
+                    },
+                )
+            })
+            .unzip()
+    }
+
+    /// Explains the given windows; the tasks in the windows are indices into `tasks` and
+    /// `bounds`.
+    fn explain(
+        &self,
+        tasks: &[usize],
+        bounds: &[TaskBounds],
+        windows: impl IntoIterator<Item = Window>,
+    ) -> Vec<Predicate> {
+        windows
+            .into_iter()
+            .flat_map(|window| {
+                let interval = self.intervals[tasks[window.task]];
+                let start = interval.start();
+                let end = interval.end();
+                let duration = interval.duration();
+                let min_duration = bounds[window.task].duration;
+
+                window
+                    .est
+                    .map(|est| predicate![start >= est])
+                    .into_iter()
+                    .chain(window.lct.map(|lct| predicate![end <= lct]))
+                    .chain([
+                        predicate![duration >= min_duration],
+                        interval.presence().into(),
+                    ])
+            })
+            .collect()
+    }
+
+    /// Makes absent every interval with an unknown presence which would overload the resource
+    /// together with the present intervals.
+    fn propagate_presence(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let (mut tasks, mut bounds) = self.task_bounds(context.as_readonly(), |interval| {
+            context.is_literal_true(interval.presence())
+        });
+
+        for (index, interval) in self.intervals.iter().enumerate() {
+            let presence = interval.presence();
+            if context.is_literal_fixed(presence) || context.lower_bound(&interval.duration()) == 0
+            {
+                continue;
+            }
+
+            tasks.push(index);
+            bounds.push(TaskBounds {
+                est: context.lower_bound(&interval.start()),
+                lct: context.upper_bound(&interval.end()),
+                duration: context.lower_bound(&interval.duration()),
+            });
+
+            if let Some(windows) = overload_check(&bounds) {
+                // The present intervals are not overloaded by themselves, so the optional interval
+                // is part of the overloaded set; its presence literal is what is propagated.
+                let reason: PropositionalConjunction = self
+                    .explain(&tasks, &bounds, windows)
+                    .into_iter()
+                    .filter(|&predicate| predicate != Predicate::from(presence))
+                    .collect();
+
+                context.assign_literal(presence, false, reason)?;
+            }
+
+            let _ = tasks.pop();
+            let _ = bounds.pop();
+        }
+
+        Ok(())
+    }
+}
+
+impl Propagator for OptionalDisjunctivePropagator {
+    fn name(&self) -> &str {
+        "OptionalDisjunctive"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for interval in self.intervals.iter() {
+            context.register(interval.start(), DomainEvents::BOUNDS);
+            context.register(interval.end(), DomainEvents::BOUNDS);
+            context.register(interval.duration(), DomainEvents::LOWER_BOUND);
+        }
+
+        for interval in self.intervals.iter() {
+            let local_id = context.get_next_local_id();
+            let _ = context.register_literal(interval.presence(), DomainEvents::ANY_BOOL, local_id);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let (tasks, bounds) = self.task_bounds(context, |interval| {
+            context.is_literal_true(interval.presence())
+        });
+
+        overload_check(&bounds).map(|windows| self.explain(&tasks, &bounds, windows).into())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        for rule in FILTERING_RULES {
+            for mirrored in [false, true] {
+                let (tasks, bounds) = self.task_bounds(context.as_readonly(), |interval| {
+                    context.is_literal_true(interval.presence())
+                });
+
+                for update in apply_rule(rule, &bounds, mirrored) {
+                    let interval = self.intervals[tasks[update.task]];
+                    let reason: PropositionalConjunction =
+                        self.explain(&tasks, &bounds, update.reason).into();
+
+                    match update.bound {
+                        Bound::EarliestStart => {
+                            context.set_lower_bound(&interval.start(), update.value, reason)?
+                        }
+                        Bound::LatestCompletion => {
+                            context.set_upper_bound(&interval.end(), update.value, reason)?
+                        }
+                    }
+                }
+            }
+        }
+
+        self.propagate_presence(&mut context)
+    }
+}
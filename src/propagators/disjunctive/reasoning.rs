@@ -0,0 +1,388 @@
+use super::theta_lambda_tree::ThetaLambdaTree;
+
+/// A snapshot of the time window of a task.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct TaskBounds {
+    pub(super) est: i32,
+    pub(super) lct: i32,
+    pub(super) duration: i32,
+}
+
+impl TaskBounds {
+    fn ect(&self) -> i32 {
+        self.est + self.duration
+    }
+
+    fn lst(&self) -> i32 {
+        self.lct - self.duration
+    }
+
+    /// The time window of the task when time runs backwards.
+    fn mirrored(&self) -> TaskBounds {
+        TaskBounds {
+            est: -self.lct,
+            lct: -self.est,
+            duration: self.duration,
+        }
+    }
+}
+
+/// Part of an explanation, stating that a task starts no earlier than `est` and completes no
+/// later than `lct`. The propagators turn windows into predicates over their variables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Window {
+    pub(super) task: usize,
+    pub(super) est: Option<i32>,
+    pub(super) lct: Option<i32>,
+}
+
+impl Window {
+    fn mirrored(self) -> Window {
+        Window {
+            task: self.task,
+            est: self.lct.map(|lct| -lct),
+            lct: self.est.map(|est| -est),
+        }
+    }
+}
+
+/// The bound of a task which is updated by a filtering rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Bound {
+    EarliestStart,
+    LatestCompletion,
+}
+
+/// A tightened bound of a task, together with the windows which explain it.
+#[derive(Clone, Debug)]
+pub(super) struct Update {
+    pub(super) task: usize,
+    pub(super) bound: Bound,
+    pub(super) value: i32,
+    pub(super) reason: Vec<Window>,
+}
+
+impl Update {
+    fn mirrored(self) -> Update {
+        Update {
+            task: self.task,
+            bound: match self.bound {
+                Bound::EarliestStart => Bound::LatestCompletion,
+                Bound::LatestCompletion => Bound::EarliestStart,
+            },
+            value: -self.value,
+            reason: self.reason.into_iter().map(Window::mirrored).collect(),
+        }
+    }
+}
+
+/// A filtering rule which computes the updates of the earliest start times (or latest completion
+/// times for not-last) of the tasks.
+pub(super) type FilteringRule = fn(&[TaskBounds]) -> Vec<Update>;
+
+/// The filtering rules in the order in which they are applied. Every rule is applied both to the
+/// tasks and to the mirrored tasks; not-last on the mirrored tasks corresponds to not-first on the
+/// original tasks.
+pub(super) const FILTERING_RULES: [FilteringRule; 3] =
+    [detectable_precedences, not_last, edge_finding];
+
+/// Applies `rule` to the tasks, or to the mirrored tasks if `mirrored` is set. The updates are
+/// always in terms of the original tasks.
+pub(super) fn apply_rule(
+    rule: FilteringRule,
+    bounds: &[TaskBounds],
+    mirrored: bool,
+) -> Vec<Update> {
+    if mirrored {
+        let mirrored_bounds: Vec<TaskBounds> = bounds.iter().map(TaskBounds::mirrored).collect();
+
+        rule(&mirrored_bounds)
+            .into_iter()
+            .map(Update::mirrored)
+            .collect()
+    } else {
+        rule(bounds)
+    }
+}
+
+fn sorted_by_key(bounds: &[TaskBounds], key: impl Fn(&TaskBounds) -> i32) -> Vec<usize> {
+    let mut tasks: Vec<usize> = (0..bounds.len()).collect();
+    tasks.sort_by_key(|&task| key(&bounds[task]));
+    tasks
+}
+
+/// Given a set of tasks, returns the earliest start time `est*` such that the earliest completion
+/// time of the set equals `est*` plus the durations of the tasks which start no earlier than
+/// `est*`. These tasks are the ones responsible for the earliest completion time.
+fn responsible_est(bounds: &[TaskBounds], tasks: impl IntoIterator<Item = usize>) -> i32 {
+    let mut tasks: Vec<usize> = tasks.into_iter().collect();
+    tasks.sort_by_key(|&task| -bounds[task].est);
+
+    let mut sum_durations = 0;
+    let mut best = (i32::MIN, i32::MIN);
+    for task in tasks {
+        sum_durations += bounds[task].duration;
+        let ect = bounds[task].est + sum_durations;
+
+        if ect >= best.0 {
+            best = (ect, bounds[task].est);
+        }
+    }
+
+    best.1
+}
+
+/// The windows stating that the tasks in `tasks` are executed within `[est, lct]`.
+fn windows(tasks: impl IntoIterator<Item = usize>, est: i32, lct: i32) -> Vec<Window> {
+    tasks
+        .into_iter()
+        .map(|task| Window {
+            task,
+            est: Some(est),
+            lct: Some(lct),
+        })
+        .collect()
+}
+
+/// Reports a conflict if there is a set of tasks `Ω` such that `est_Ω + p_Ω > lct_Ω`.
+pub(super) fn overload_check(bounds: &[TaskBounds]) -> Option<Vec<Window>> {
+    let ests: Vec<i32> = bounds.iter().map(|task| task.est).collect();
+    let mut theta = ThetaLambdaTree::new(&ests);
+
+    let tasks_by_lct = sorted_by_key(bounds, |task| task.lct);
+    for (position, &j) in tasks_by_lct.iter().enumerate() {
+        theta.add_to_theta(j, bounds[j].est, bounds[j].duration);
+
+        if theta.ect() > bounds[j].lct {
+            let theta_tasks = &tasks_by_lct[..=position];
+            let est_omega = responsible_est(bounds, theta_tasks.iter().copied());
+
+            return Some(windows(
+                theta_tasks
+                    .iter()
+                    .copied()
+                    .filter(|&task| bounds[task].est >= est_omega),
+                est_omega,
+                bounds[j].lct,
+            ));
+        }
+    }
+
+    None
+}
+
+/// If `ect_i > lst_j` then `j` has to precede `i`. The earliest start time of `i` is updated to the
+/// earliest completion time of all the tasks which are detected to precede it.
+fn detectable_precedences(bounds: &[TaskBounds]) -> Vec<Update> {
+    let ests: Vec<i32> = bounds.iter().map(|task| task.est).collect();
+    let mut theta = ThetaLambdaTree::new(&ests);
+    let mut in_theta = vec![false; bounds.len()];
+
+    let tasks_by_lst = sorted_by_key(bounds, TaskBounds::lst);
+    let mut next_by_lst = 0;
+
+    let mut updates = Vec::new();
+    for i in sorted_by_key(bounds, TaskBounds::ect) {
+        while next_by_lst < bounds.len()
+            && bounds[i].ect() > bounds[tasks_by_lst[next_by_lst]].lst()
+        {
+            let j = tasks_by_lst[next_by_lst];
+            theta.add_to_theta(j, bounds[j].est, bounds[j].duration);
+            in_theta[j] = true;
+            next_by_lst += 1;
+        }
+
+        if in_theta[i] {
+            theta.remove(i);
+        }
+
+        let ect_theta = theta.ect();
+        if ect_theta > bounds[i].est {
+            let predecessors = (0..bounds.len()).filter(|&j| in_theta[j] && j != i);
+            let est_omega = responsible_est(bounds, predecessors.clone());
+
+            let mut reason = vec![Window {
+                task: i,
+                est: Some(bounds[i].est),
+                lct: None,
+            }];
+            reason.extend(
+                predecessors
+                    .filter(|&j| bounds[j].est >= est_omega)
+                    .map(|j| Window {
+                        task: j,
+                        est: Some(est_omega),
+                        lct: Some(bounds[j].lct),
+                    }),
+            );
+
+            updates.push(Update {
+                task: i,
+                bound: Bound::EarliestStart,
+                value: ect_theta,
+                reason,
+            });
+        }
+
+        if in_theta[i] {
+            theta.add_to_theta(i, bounds[i].est, bounds[i].duration);
+        }
+    }
+
+    updates
+}
+
+/// If the tasks `Ω` cannot all complete before the latest start time of `i`, i.e.
+/// `ect_Ω > lst_i`, then `i` cannot be the last task of `Ω ∪ {i}`; so it has to complete before
+/// the largest latest start time in `Ω`.
+fn not_last(bounds: &[TaskBounds]) -> Vec<Update> {
+    let ests: Vec<i32> = bounds.iter().map(|task| task.est).collect();
+    let mut theta = ThetaLambdaTree::new(&ests);
+    let mut in_theta = vec![false; bounds.len()];
+
+    let tasks_by_lst = sorted_by_key(bounds, TaskBounds::lst);
+    let mut next_by_lst = 0;
+
+    let mut updates = Vec::new();
+    for i in sorted_by_key(bounds, |task| task.lct) {
+        while next_by_lst < bounds.len() && bounds[i].lct > bounds[tasks_by_lst[next_by_lst]].lst()
+        {
+            let j = tasks_by_lst[next_by_lst];
+            theta.add_to_theta(j, bounds[j].est, bounds[j].duration);
+            in_theta[j] = true;
+            next_by_lst += 1;
+        }
+
+        if in_theta[i] {
+            theta.remove(i);
+        }
+
+        if theta.ect() > bounds[i].lst() {
+            let others = (0..bounds.len()).filter(|&j| in_theta[j] && j != i);
+            let est_omega = responsible_est(bounds, others.clone());
+            let omega: Vec<usize> = others.filter(|&j| bounds[j].est >= est_omega).collect();
+
+            let new_lct = omega.iter().map(|&j| bounds[j].lst()).max().unwrap();
+            if new_lct < bounds[i].lct {
+                let mut reason = vec![Window {
+                    task: i,
+                    est: None,
+                    lct: Some(bounds[i].lct),
+                }];
+                reason.extend(omega.iter().map(|&j| Window {
+                    task: j,
+                    est: Some(est_omega),
+                    lct: Some(bounds[j].lct),
+                }));
+
+                updates.push(Update {
+                    task: i,
+                    bound: Bound::LatestCompletion,
+                    value: new_lct,
+                    reason,
+                });
+            }
+        }
+
+        if in_theta[i] {
+            theta.add_to_theta(i, bounds[i].est, bounds[i].duration);
+        }
+    }
+
+    updates
+}
+
+/// If adding a task `i` to the set of tasks `Θ` overloads it, i.e. `est_{Θ ∪ {i}} + p_{Θ ∪ {i}} >
+/// lct_Θ`, then `i` has to be executed after all tasks in `Θ`.
+fn edge_finding(bounds: &[TaskBounds]) -> Vec<Update> {
+    let ests: Vec<i32> = bounds.iter().map(|task| task.est).collect();
+    let mut tree = ThetaLambdaTree::new(&ests);
+    let mut in_theta = vec![true; bounds.len()];
+
+    for (task, task_bounds) in bounds.iter().enumerate() {
+        tree.add_to_theta(task, task_bounds.est, task_bounds.duration);
+    }
+
+    let tasks_by_lct_descending = sorted_by_key(bounds, |task| -task.lct);
+
+    let mut updates = Vec::new();
+    for window in tasks_by_lct_descending.windows(2) {
+        let j = window[0];
+        tree.add_to_lambda(j, bounds[j].est, bounds[j].duration);
+        in_theta[j] = false;
+
+        let lct_theta = bounds[window[1]].lct;
+        while tree.ect_bar() > lct_theta {
+            let Some(i) = tree.responsible_ect_bar() else {
+                // Θ itself is overloaded, which is detected by the overload check.
+                break;
+            };
+
+            let ect_theta = tree.ect();
+            if ect_theta > bounds[i].est {
+                updates.push(Update {
+                    task: i,
+                    bound: Bound::EarliestStart,
+                    value: ect_theta,
+                    reason: explain_edge_finding(bounds, &in_theta, i, lct_theta),
+                });
+            }
+
+            tree.remove(i);
+        }
+    }
+
+    updates
+}
+
+/// Explains that `i` has to be executed after the tasks in `Θ` (given by `in_theta`), and hence
+/// cannot start before the earliest completion time of `Θ`.
+fn explain_edge_finding(
+    bounds: &[TaskBounds],
+    in_theta: &[bool],
+    i: usize,
+    lct_theta: i32,
+) -> Vec<Window> {
+    let theta = || (0..bounds.len()).filter(|&j| in_theta[j]);
+
+    // The tasks responsible for the earliest completion time of Θ start no earlier than `est_b`.
+    let est_b = responsible_est(bounds, theta());
+
+    // The largest `est_a` such that the tasks in Θ which start no earlier than `est_a`, together
+    // with `i`, overload the interval `[est_a, lct_theta]`.
+    let est_a = theta()
+        .chain(std::iter::once(i))
+        .map(|j| bounds[j].est)
+        .filter(|&est| est <= bounds[i].est)
+        .filter(|&est| {
+            let sum_durations: i32 = theta()
+                .filter(|&j| bounds[j].est >= est)
+                .map(|j| bounds[j].duration)
+                .sum();
+
+            est + sum_durations + bounds[i].duration > lct_theta
+        })
+        .max()
+        .expect("edge finding should have detected an overloaded set");
+
+    let est_omega = est_a.min(est_b);
+
+    let mut reason = vec![Window {
+        task: i,
+        est: Some(est_a),
+        lct: None,
+    }];
+    reason.extend(theta().filter(|&j| bounds[j].est >= est_omega).map(|j| {
+        Window {
+            task: j,
+            // A task which is part of both sets needs the tighter of the two bounds.
+            est: [est_a, est_b]
+                .into_iter()
+                .filter(|&est| bounds[j].est >= est)
+                .max(),
+            lct: Some(lct_theta),
+        }
+    }));
+
+    reason
+}
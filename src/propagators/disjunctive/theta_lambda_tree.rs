@@ -0,0 +1,207 @@
+use std::cmp::max;
+
+/// Used as the earliest completion time of an empty set of tasks.
+const NEGATIVE_INFINITY: i32 = i32::MIN / 4;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    /// The sum of the durations of the tasks in Θ.
+    sum_durations: i32,
+    /// The earliest completion time of the tasks in Θ.
+    ect: i32,
+    /// The sum of the durations of the tasks in Θ and at most one task in Λ.
+    sum_durations_bar: i32,
+    /// The earliest completion time of the tasks in Θ and at most one task in Λ.
+    ect_bar: i32,
+    /// The task in Λ which is used to obtain `sum_durations_bar`, if any.
+    responsible_sum_durations_bar: Option<usize>,
+    /// The task in Λ which is used to obtain `ect_bar`, if any.
+    responsible_ect_bar: Option<usize>,
+}
+
+impl Node {
+    const EMPTY: Node = Node {
+        sum_durations: 0,
+        ect: NEGATIVE_INFINITY,
+        sum_durations_bar: 0,
+        ect_bar: NEGATIVE_INFINITY,
+        responsible_sum_durations_bar: None,
+        responsible_ect_bar: None,
+    };
+
+    fn combine(left: &Node, right: &Node) -> Node {
+        let (sum_durations_bar, responsible_sum_durations_bar) = if left.sum_durations_bar
+            + right.sum_durations
+            >= left.sum_durations + right.sum_durations_bar
+        {
+            (
+                left.sum_durations_bar + right.sum_durations,
+                left.responsible_sum_durations_bar,
+            )
+        } else {
+            (
+                left.sum_durations + right.sum_durations_bar,
+                right.responsible_sum_durations_bar,
+            )
+        };
+
+        let (ect_bar, responsible_ect_bar) = [
+            (right.ect_bar, right.responsible_ect_bar),
+            (
+                left.ect + right.sum_durations_bar,
+                right.responsible_sum_durations_bar,
+            ),
+            (left.ect_bar + right.sum_durations, left.responsible_ect_bar),
+        ]
+        .into_iter()
+        .max_by_key(|&(ect_bar, _)| ect_bar)
+        .unwrap();
+
+        Node {
+            sum_durations: left.sum_durations + right.sum_durations,
+            ect: max(right.ect, left.ect + right.sum_durations),
+            sum_durations_bar,
+            ect_bar,
+            responsible_sum_durations_bar,
+            responsible_ect_bar,
+        }
+    }
+}
+
+/// The Θ-Λ-tree of \[1\], a balanced binary tree over a set of tasks sorted by their earliest
+/// start times. The tasks can be in Θ (white), in Λ (gray) or not in the tree at all. The tree
+/// maintains the earliest completion time of Θ, and the earliest completion time of Θ when at most
+/// one of the tasks in Λ is added to it, in logarithmic time per update.
+///
+/// Tasks are identified by their index in the slice of earliest start times given to
+/// [`ThetaLambdaTree::new`].
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+#[derive(Clone, Debug)]
+pub(super) struct ThetaLambdaTree {
+    /// The nodes in the tree, where the children of node `i` are `2i + 1` and `2i + 2`.
+    nodes: Vec<Node>,
+    /// For every task the index of its leaf in `nodes`.
+    leaf_of_task: Vec<usize>,
+}
+
+impl ThetaLambdaTree {
+    /// Creates an empty tree over the tasks with the given earliest start times.
+    pub(super) fn new(earliest_start_times: &[i32]) -> ThetaLambdaTree {
+        let num_leaves = earliest_start_times.len().next_power_of_two();
+        let first_leaf = num_leaves - 1;
+
+        let mut tasks_by_est: Vec<usize> = (0..earliest_start_times.len()).collect();
+        tasks_by_est.sort_by_key(|&task| earliest_start_times[task]);
+
+        let mut leaf_of_task = vec![0; earliest_start_times.len()];
+        for (position, task) in tasks_by_est.into_iter().enumerate() {
+            leaf_of_task[task] = first_leaf + position;
+        }
+
+        ThetaLambdaTree {
+            nodes: vec![Node::EMPTY; 2 * num_leaves - 1],
+            leaf_of_task,
+        }
+    }
+
+    /// The earliest completion time of the tasks in Θ.
+    pub(super) fn ect(&self) -> i32 {
+        self.nodes[0].ect
+    }
+
+    /// The earliest completion time of the tasks in Θ together with at most one task in Λ.
+    pub(super) fn ect_bar(&self) -> i32 {
+        self.nodes[0].ect_bar
+    }
+
+    /// The task in Λ which is responsible for [`ThetaLambdaTree::ect_bar`], if any.
+    pub(super) fn responsible_ect_bar(&self) -> Option<usize> {
+        self.nodes[0].responsible_ect_bar
+    }
+
+    /// Adds `task` to Θ (removing it from Λ if necessary).
+    pub(super) fn add_to_theta(&mut self, task: usize, est: i32, duration: i32) {
+        self.update_leaf(
+            task,
+            Node {
+                sum_durations: duration,
+                ect: est + duration,
+                sum_durations_bar: duration,
+                ect_bar: est + duration,
+                responsible_sum_durations_bar: None,
+                responsible_ect_bar: None,
+            },
+        );
+    }
+
+    /// Adds `task` to Λ (removing it from Θ if necessary).
+    pub(super) fn add_to_lambda(&mut self, task: usize, est: i32, duration: i32) {
+        self.update_leaf(
+            task,
+            Node {
+                sum_durations: 0,
+                ect: NEGATIVE_INFINITY,
+                sum_durations_bar: duration,
+                ect_bar: est + duration,
+                responsible_sum_durations_bar: Some(task),
+                responsible_ect_bar: Some(task),
+            },
+        );
+    }
+
+    /// Removes `task` from the tree.
+    pub(super) fn remove(&mut self, task: usize) {
+        self.update_leaf(task, Node::EMPTY);
+    }
+
+    fn update_leaf(&mut self, task: usize, leaf: Node) {
+        let mut index = self.leaf_of_task[task];
+        self.nodes[index] = leaf;
+
+        while index > 0 {
+            index = (index - 1) / 2;
+            self.nodes[index] =
+                Node::combine(&self.nodes[2 * index + 1], &self.nodes[2 * index + 2]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThetaLambdaTree;
+
+    #[test]
+    fn ect_of_theta() {
+        let ests = [0, 2, 3];
+        let durations = [3, 2, 1];
+        let mut tree = ThetaLambdaTree::new(&ests);
+
+        tree.add_to_theta(1, ests[1], durations[1]);
+        tree.add_to_theta(2, ests[2], durations[2]);
+        assert_eq!(5, tree.ect());
+
+        tree.add_to_theta(0, ests[0], durations[0]);
+        assert_eq!(6, tree.ect());
+
+        tree.remove(0);
+        assert_eq!(5, tree.ect());
+    }
+
+    #[test]
+    fn ect_bar_with_responsible_task() {
+        let ests = [0, 2, 3];
+        let durations = [3, 2, 4];
+        let mut tree = ThetaLambdaTree::new(&ests);
+
+        tree.add_to_theta(0, ests[0], durations[0]);
+        tree.add_to_theta(1, ests[1], durations[1]);
+        tree.add_to_lambda(2, ests[2], durations[2]);
+
+        assert_eq!(5, tree.ect());
+        assert_eq!(9, tree.ect_bar());
+        assert_eq!(Some(2), tree.responsible_ect_bar());
+    }
+}
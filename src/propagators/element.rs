@@ -34,6 +34,10 @@ where
         "Element"
     }
 
+    fn priority(&self) -> u32 {
+        0
+    }
+
     fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
         todo!()
     }
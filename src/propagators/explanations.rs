@@ -0,0 +1,25 @@
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Returns a predicate which explains that `value` is not in the domain of `variable`. If the
+/// value lies outside of the bounds, the corresponding bound predicate is used instead, so that
+/// all values on the same side of the domain share the same explanation.
+pub(crate) fn explain_absent_value<Var: IntegerVariable>(
+    context: PropagationContext<'_>,
+    variable: &Var,
+    value: i32,
+) -> Predicate {
+    let lower_bound = context.lower_bound(variable);
+    let upper_bound = context.upper_bound(variable);
+
+    if value < lower_bound {
+        predicate![variable >= lower_bound]
+    } else if value > upper_bound {
+        predicate![variable <= upper_bound]
+    } else {
+        predicate![variable != value]
+    }
+}
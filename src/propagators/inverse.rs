@@ -0,0 +1,98 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `f[i] = j <-> g[j] = i`, where `f` and `g` are arrays of integer
+/// variables of the same length `n`. The arrays are 0-indexed, so all variables take values in
+/// `0..n`.
+///
+/// The propagator removes the value `j` from `f[i]` if `i` is not in the domain of `g[j]`, which
+/// is explained by `[g[j] != i]`, and fixes `g[j]` to `i` when `f[i]` is fixed to `j`, which is
+/// explained by `[f[i] = j]` (and vice versa). At the fixed point the propagator is domain
+/// consistent.
+#[derive(Debug)]
+pub(crate) struct InversePropagator<Var> {
+    f: Box<[Var]>,
+    g: Box<[Var]>,
+}
+
+impl<Var> InversePropagator<Var> {
+    pub(crate) fn new(f: Box<[Var]>, g: Box<[Var]>) -> Self {
+        assert_eq!(
+            f.len(),
+            g.len(),
+            "The arrays of an inverse constraint should have the same length"
+        );
+
+        Self { f, g }
+    }
+}
+
+/// Removes every value `j` from `lhs[i]` for which `i` is not in the domain of `rhs[j]`.
+fn propagate_one_direction<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    lhs: &[Var],
+    rhs: &[Var],
+) -> PropagationStatusCP {
+    let n = lhs.len() as i32;
+
+    for (i, lhs_i) in lhs.iter().enumerate() {
+        let i = i as i32;
+
+        context.set_lower_bound(lhs_i, 0, conjunction!())?;
+        context.set_upper_bound(lhs_i, n - 1, conjunction!())?;
+
+        for j in context.lower_bound(lhs_i)..=context.upper_bound(lhs_i) {
+            let rhs_j = &rhs[j as usize];
+
+            if context.contains(lhs_i, j) && !context.contains(rhs_j, i) {
+                context.remove(lhs_i, j, conjunction!([rhs_j != i]))?;
+            }
+        }
+
+        if context.is_fixed(lhs_i) {
+            // `lhs[i] = j` implies `rhs[j] = i`.
+            let j = context.lower_bound(lhs_i);
+            let rhs_j = &rhs[j as usize];
+
+            context.set_lower_bound(rhs_j, i, conjunction!([lhs_i == j]))?;
+            context.set_upper_bound(rhs_j, i, conjunction!([lhs_i == j]))?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for InversePropagator<Var> {
+    fn name(&self) -> &str {
+        "Inverse"
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.f.iter().chain(self.g.iter()) {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        propagate_one_direction(&mut context, &self.f, &self.g)?;
+        propagate_one_direction(&mut context, &self.g, &self.f)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `xs <=_lex ys` (or `xs <_lex ys` if `strict` is set), where `xs`
+/// and `ys` are arrays of integer variables of the same length.
+///
+/// The propagator walks over the positions from the front. At every position `i` for which all
+/// previous positions are forced to be equal, `x_i <= y_i` is enforced; it is made strict when
+/// the remainder of the arrays forces `xs[i + 1..] >_lex ys[i + 1..]` (or `>=_lex` for the strict
+/// constraint). The walk continues as long as `x_i >= y_i` is forced, in which case the two
+/// variables have to be equal. This achieves domain consistency \[1\].
+///
+/// A position `i` is explained to be forced equal (or greater) by `[x_i >= v]` and `[y_i <= v]`
+/// with `v = ub(y_i)` (or `v + 1` for `x_i`).
+///
+/// # Bibliography
+/// \[1\] A. M. Frisch, B. Hnich, Z. Kiziltan, I. Miguel, and T. Walsh, ‘Global constraints for
+/// lexicographic orderings’, in Principles and Practice of Constraint Programming (CP 2002), 2002,
+/// pp. 93–108.
+#[derive(Debug)]
+pub(crate) struct LexicographicPropagator<Var> {
+    xs: Box<[Var]>,
+    ys: Box<[Var]>,
+    strict: bool,
+}
+
+impl<Var> LexicographicPropagator<Var> {
+    pub(crate) fn new(xs: Box<[Var]>, ys: Box<[Var]>, strict: bool) -> Self {
+        assert_eq!(
+            xs.len(),
+            ys.len(),
+            "The arrays of a lexicographic ordering should have the same length"
+        );
+
+        LexicographicPropagator { xs, ys, strict }
+    }
+}
+
+impl<Var: IntegerVariable> LexicographicPropagator<Var> {
+    /// Whether `x_i >= y_i` is forced by the bounds.
+    fn is_at_least(&self, context: PropagationContext, i: usize) -> bool {
+        context.lower_bound(&self.xs[i]) >= context.upper_bound(&self.ys[i])
+    }
+
+    /// Explains that `x_i >= y_i + offset`, with `offset` either 0 or 1.
+    fn explain_at_least(
+        &self,
+        context: PropagationContext,
+        i: usize,
+        offset: i32,
+    ) -> [Predicate; 2] {
+        let x = &self.xs[i];
+        let y = &self.ys[i];
+        let value = context.upper_bound(y);
+
+        [predicate![x >= value + offset], predicate![y <= value]]
+    }
+
+    /// If the positions after `i` force `xs[i + 1..] >_lex ys[i + 1..]` (or `>=_lex` when the
+    /// constraint is strict), returns the explanation of that.
+    fn explain_suffix_forces_strict(
+        &self,
+        context: PropagationContext,
+        i: usize,
+    ) -> Option<Vec<Predicate>> {
+        let mut explanation = Vec::new();
+
+        for j in i + 1..self.xs.len() {
+            if context.lower_bound(&self.xs[j]) > context.upper_bound(&self.ys[j]) {
+                explanation.extend(self.explain_at_least(context, j, 1));
+                return Some(explanation);
+            }
+
+            if !self.is_at_least(context, j) {
+                return None;
+            }
+
+            explanation.extend(self.explain_at_least(context, j, 0));
+        }
+
+        self.strict.then_some(explanation)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for LexicographicPropagator<Var> {
+    fn name(&self) -> &str {
+        "Lexicographic"
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.xs.iter().chain(self.ys.iter()) {
+            context.register(variable.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let mut explanation = Vec::new();
+
+        for i in 0..self.xs.len() {
+            if context.lower_bound(&self.xs[i]) > context.upper_bound(&self.ys[i]) {
+                explanation.extend(self.explain_at_least(context, i, 1));
+                return Some(explanation.into());
+            }
+
+            if !self.is_at_least(context, i) {
+                return None;
+            }
+
+            explanation.extend(self.explain_at_least(context, i, 0));
+        }
+
+        // All positions are forced to be equal.
+        self.strict.then(|| explanation.into())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        // The explanation of why all positions before `i` are forced to be equal.
+        let mut prefix: Vec<Predicate> = Vec::new();
+
+        for i in 0..self.xs.len() {
+            let x = &self.xs[i];
+            let y = &self.ys[i];
+
+            let strict = self.explain_suffix_forces_strict(context.as_readonly(), i);
+            let offset = if strict.is_some() { 1 } else { 0 };
+            let suffix = strict.unwrap_or_default();
+
+            let upper_bound_y = context.upper_bound(y);
+            let reason: PropositionalConjunction = prefix
+                .iter()
+                .chain(suffix.iter())
+                .copied()
+                .chain(std::iter::once(predicate![y <= upper_bound_y]))
+                .collect();
+            context.set_upper_bound(x, upper_bound_y - offset, reason)?;
+
+            let lower_bound_x = context.lower_bound(x);
+            let reason: PropositionalConjunction = prefix
+                .iter()
+                .chain(suffix.iter())
+                .copied()
+                .chain(std::iter::once(predicate![x >= lower_bound_x]))
+                .collect();
+            context.set_lower_bound(y, lower_bound_x + offset, reason)?;
+
+            if !self.is_at_least(context.as_readonly(), i) {
+                return Ok(());
+            }
+
+            prefix.extend(self.explain_at_least(context.as_readonly(), i, 0));
+        }
+
+        if self.strict {
+            // All positions are forced to be equal.
+            return Err(PropositionalConjunction::from(prefix).into());
+        }
+
+        Ok(())
+    }
+}
@@ -4,9 +4,20 @@
 
 pub(crate) mod all_different;
 pub(crate) mod arithmetic;
+pub(crate) mod bin_packing;
 pub(crate) mod circuit;
+pub(crate) mod counting;
 pub(crate) mod cumulative;
+pub(crate) mod diffn;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+mod explanations;
+pub(crate) mod inverse;
+pub(crate) mod lexicographic;
+pub(crate) mod regular;
 mod reified_propagator;
+pub(crate) mod value_precede_chain;
+pub(crate) mod xor;
 
+pub(crate) use explanations::*;
 pub(crate) use reified_propagator::*;
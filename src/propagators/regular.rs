@@ -0,0 +1,251 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::constraints::Automaton;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicates::Predicate;
+use crate::propagators::explain_absent_value;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `regular(x, A)`, which states that the sequence of values
+/// `x_1, ..., x_n` is accepted by the deterministic finite automaton `A`.
+///
+/// The propagator is based on the layered graph of \[1\]. Layer `i` of the graph contains the
+/// states of the automaton, and there is an edge from state `q` in layer `i` to state `q'` in
+/// layer `i + 1` labelled with `v` if the automaton moves from `q` to `q'` when reading `v` and
+/// `v` is in the domain of `x_i`. A value is removed from the domain of `x_i` if it does not label
+/// an edge on a path from the initial state in the first layer to an accepting state in the last
+/// layer.
+///
+/// A removal is explained by the values which cut the states that are unreachable from the
+/// initial state (for the layers before `x_i`) or that cannot reach an accepting state (for the
+/// layers after `x_i`) from the rest of the graph.
+///
+/// # Bibliography
+/// \[1\] G. Pesant, ‘A regular language membership constraint for finite sequences of
+/// variables’, in International Conference on Principles and Practice of Constraint Programming,
+/// 2004, pp. 482–495.
+#[derive(Debug)]
+pub(crate) struct RegularPropagator<Var> {
+    variables: Box<[Var]>,
+    automaton: Automaton,
+}
+
+impl<Var> RegularPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, automaton: Automaton) -> Self {
+        Self {
+            variables,
+            automaton,
+        }
+    }
+}
+
+impl<Var: IntegerVariable> RegularPropagator<Var> {
+    /// Computes for every layer `0..=n` which states can be reached from the initial state.
+    fn forward_reachable(&self, context: PropagationContext<'_>) -> Vec<Vec<bool>> {
+        let num_states = self.automaton.num_states();
+
+        let mut reachable = vec![vec![false; num_states]; self.variables.len() + 1];
+        reachable[0][self.automaton.initial_state()] = true;
+
+        for (layer, variable) in self.variables.iter().enumerate() {
+            for state in 0..num_states {
+                if !reachable[layer][state] {
+                    continue;
+                }
+
+                for &(value, next_state) in self.automaton.transitions_from(state) {
+                    if context.contains(variable, value) {
+                        reachable[layer + 1][next_state] = true;
+                    }
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Computes for every layer `0..=n` from which states an accepting state in the last layer
+    /// can be reached.
+    fn backward_reachable(&self, context: PropagationContext<'_>) -> Vec<Vec<bool>> {
+        let num_states = self.automaton.num_states();
+
+        let mut reachable = vec![vec![false; num_states]; self.variables.len() + 1];
+        for (state, is_reachable) in reachable[self.variables.len()].iter_mut().enumerate() {
+            *is_reachable = self.automaton.is_accepting(state);
+        }
+
+        for (layer, variable) in self.variables.iter().enumerate().rev() {
+            for state in 0..num_states {
+                reachable[layer][state] =
+                    self.automaton
+                        .transitions_from(state)
+                        .iter()
+                        .any(|&(value, next_state)| {
+                            reachable[layer + 1][next_state] && context.contains(variable, value)
+                        });
+            }
+        }
+
+        reachable
+    }
+
+    /// For every layer, the predicates which explain why the states that are not forward
+    /// reachable in the next layer cannot be reached from the reachable states in that layer.
+    fn forward_cuts(
+        &self,
+        context: PropagationContext<'_>,
+        forward: &[Vec<bool>],
+    ) -> Vec<Vec<Predicate>> {
+        self.variables
+            .iter()
+            .enumerate()
+            .map(|(layer, variable)| {
+                let mut cut = Vec::new();
+
+                for state in (0..self.automaton.num_states()).filter(|&q| forward[layer][q]) {
+                    for &(value, next_state) in self.automaton.transitions_from(state) {
+                        if !forward[layer + 1][next_state] {
+                            add_absent_value(context, variable, value, &mut cut);
+                        }
+                    }
+                }
+
+                cut
+            })
+            .collect()
+    }
+
+    /// For every layer, the predicates which explain why the states that cannot reach an
+    /// accepting state do not have an edge to a state in the next layer which can.
+    fn backward_cuts(
+        &self,
+        context: PropagationContext<'_>,
+        backward: &[Vec<bool>],
+    ) -> Vec<Vec<Predicate>> {
+        self.variables
+            .iter()
+            .enumerate()
+            .map(|(layer, variable)| {
+                let mut cut = Vec::new();
+
+                for state in (0..self.automaton.num_states()).filter(|&q| !backward[layer][q]) {
+                    for &(value, next_state) in self.automaton.transitions_from(state) {
+                        if backward[layer + 1][next_state] {
+                            add_absent_value(context, variable, value, &mut cut);
+                        }
+                    }
+                }
+
+                cut
+            })
+            .collect()
+    }
+}
+
+/// Adds a predicate to `cut` which states that `value` is not in the domain of `variable`, unless
+/// the cut already contains it.
+fn add_absent_value<Var: IntegerVariable>(
+    context: PropagationContext<'_>,
+    variable: &Var,
+    value: i32,
+    cut: &mut Vec<Predicate>,
+) {
+    let predicate = explain_absent_value(context, variable, value);
+
+    if !cut.contains(&predicate) {
+        cut.push(predicate);
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for RegularPropagator<Var> {
+    fn name(&self) -> &str {
+        "Regular"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.variables.iter() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let forward = self.forward_reachable(context);
+        let num_layers = self.variables.len();
+
+        if (0..self.automaton.num_states())
+            .any(|state| forward[num_layers][state] && self.automaton.is_accepting(state))
+        {
+            return None;
+        }
+
+        Some(
+            self.forward_cuts(context, &forward)
+                .into_iter()
+                .flatten()
+                .collect(),
+        )
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        let forward = self.forward_reachable(context.as_readonly());
+        let backward = self.backward_reachable(context.as_readonly());
+
+        let forward_cuts = self.forward_cuts(context.as_readonly(), &forward);
+        let backward_cuts = self.backward_cuts(context.as_readonly(), &backward);
+
+        for (layer, variable) in self.variables.iter().enumerate() {
+            let is_supported = |value: i32| {
+                (0..self.automaton.num_states())
+                    .filter(|&state| forward[layer][state])
+                    .any(|state| {
+                        self.automaton
+                            .next_state(state, value)
+                            .is_some_and(|next_state| backward[layer + 1][next_state])
+                    })
+            };
+
+            let unsupported_values: Vec<i32> = (context.lower_bound(variable)
+                ..=context.upper_bound(variable))
+                .filter(|&value| context.contains(variable, value) && !is_supported(value))
+                .collect();
+
+            if unsupported_values.is_empty() {
+                continue;
+            }
+
+            let reason: PropositionalConjunction = forward_cuts[..layer]
+                .iter()
+                .chain(backward_cuts[layer + 1..].iter())
+                .flatten()
+                .copied()
+                .collect();
+
+            for value in unsupported_values {
+                context.remove(variable, value, reason.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -17,16 +17,26 @@ use crate::variables::Literal;
 /// When a propagator is reified, it will only propagate whenever `r` is set to true. However, if
 /// the propagator implements [`Propagator::detect_inconsistency`], the result of that method may
 /// be used to propagate `r` to false. If that method is not implemented, `r` will never be
-/// propagated to false.
+/// propagated to false. Note that an entailed `p` does not imply anything about `r`.
+///
+/// If the propagator is created with a negation (see [`ReifiedPropagator::with_negation`]), it
+/// propagates the full reification `r <-> p`; the negation is propagated whenever `r` is set to
+/// false. In that case, the entailment of `p` (see [`Propagator::detect_entailment`]) propagates
+/// `r` to true, and the inconsistency and entailment of the negation propagate `r` to true and
+/// false respectively.
 pub(crate) struct ReifiedPropagator<WrappedPropagator> {
     propagator: WrappedPropagator,
+    /// The propagator for the negation of `propagator`, if `r <-> p` is propagated.
+    negation: Option<Box<dyn Propagator>>,
     reification_literal: Literal,
     /// The inconsistency that is identified by `propagator` during initialisation.
     root_level_inconsistency: Option<PropositionalConjunction>,
+    /// The inconsistency that is identified by `negation` during initialisation.
+    negation_root_level_inconsistency: Option<PropositionalConjunction>,
     /// The formatted name of the propagator.
     name: String,
     /// The `LocalId` of the reification literal. Is guaranteed to be a larger ID than any of the
-    /// registered ids of the wrapped propagator.
+    /// registered ids of the wrapped propagators.
     reification_literal_id: LocalId,
 }
 
@@ -36,12 +46,28 @@ impl<WrappedPropagator: Propagator> ReifiedPropagator<WrappedPropagator> {
         ReifiedPropagator {
             reification_literal,
             propagator,
+            negation: None,
             root_level_inconsistency: None,
+            negation_root_level_inconsistency: None,
             name,
             reification_literal_id: LocalId::from(0), /* Place-holder, will be set in
                                                        * `initialise_at_root` */
         }
     }
+
+    /// Creates the propagator for `r <-> p`, where `negation` propagates the negation of `p`.
+    pub(crate) fn with_negation(
+        propagator: WrappedPropagator,
+        negation: impl Propagator + 'static,
+        reification_literal: Literal,
+    ) -> Self {
+        let name = format!("Reified({}, {})", propagator.name(), negation.name());
+        ReifiedPropagator {
+            negation: Some(Box::new(negation)),
+            name,
+            ..ReifiedPropagator::new(propagator, reification_literal)
+        }
+    }
 }
 
 impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedPropagator> {
@@ -50,17 +76,27 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
         // Since we cannot propagate here, we store a conflict which the wrapped propagator
-        // identifies at the root, and propagate the reification literal to false in the
-        // `propagate` method.
+        // identifies at the root, and propagate the reification literal in the `propagate`
+        // method.
         if let Err(conjunction) = self.propagator.initialise_at_root(context) {
             self.root_level_inconsistency = Some(conjunction);
         }
 
+        let mut events = BooleanDomainEvent::AssignedTrue.into();
+
+        if let Some(negation) = self.negation.as_mut() {
+            if let Err(conjunction) = negation.initialise_at_root(context) {
+                self.negation_root_level_inconsistency = Some(conjunction);
+            }
+
+            events |= BooleanDomainEvent::AssignedFalse;
+        }
+
         self.reification_literal_id = context.get_next_local_id();
 
         let _ = context.register_literal(
             self.reification_literal,
-            DomainEvents::create_with_bool_events(BooleanDomainEvent::AssignedTrue.into()),
+            DomainEvents::create_with_bool_events(events),
             self.reification_literal_id,
         );
 
@@ -71,6 +107,8 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         if !context.is_literal_fixed(self.reification_literal) {
             if let Some(conjunction) = &self.root_level_inconsistency {
                 context.assign_literal(self.reification_literal, false, conjunction.clone())?;
+            } else if let Some(conjunction) = &self.negation_root_level_inconsistency {
+                context.assign_literal(self.reification_literal, true, conjunction.clone())?;
             }
         }
 
@@ -81,7 +119,17 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
 
             let result = self.propagator.propagate(context);
 
-            self.map_propagation_status(result)?;
+            self.map_propagation_status(result, self.reification_literal)?;
+        } else if let Some(negation) = self
+            .negation
+            .as_ref()
+            .filter(|_| context.is_literal_false(self.reification_literal))
+        {
+            context.with_reification(!self.reification_literal);
+
+            let result = negation.propagate(context);
+
+            self.map_propagation_status(result, !self.reification_literal)?;
         }
 
         Ok(())
@@ -90,12 +138,20 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn priority(&self) -> u32 {
+        self.propagator.priority()
+    }
 }
 
 impl<Prop> ReifiedPropagator<Prop> {
-    fn map_propagation_status(&self, mut status: PropagationStatusCP) -> PropagationStatusCP {
+    fn map_propagation_status(
+        &self,
+        mut status: PropagationStatusCP,
+        literal: Literal,
+    ) -> PropagationStatusCP {
         if let Err(Inconsistency::Other(ConflictInfo::Explanation(ref mut conjunction))) = status {
-            conjunction.add(self.reification_literal.into());
+            conjunction.add(literal.into());
         }
         status
     }
@@ -104,13 +160,31 @@ impl<Prop> ReifiedPropagator<Prop> {
     where
         Prop: Propagator,
     {
-        if !context.is_literal_fixed(self.reification_literal) {
-            if let Some(conjunction) = self.propagator.detect_inconsistency(context.as_readonly()) {
-                context.assign_literal(self.reification_literal, false, conjunction)?;
-            }
+        if context.is_literal_fixed(self.reification_literal) {
+            return Ok(());
         }
 
-        Ok(())
+        let readonly = context.as_readonly();
+
+        let (conjunction, value) =
+            if let Some(conjunction) = self.propagator.detect_inconsistency(readonly) {
+                (conjunction, false)
+            } else if let Some(negation) = self.negation.as_ref() {
+                // Only under full reification does an entailed constraint imply the literal.
+                if let Some(conjunction) = self.propagator.detect_entailment(readonly) {
+                    (conjunction, true)
+                } else if let Some(conjunction) = negation.detect_inconsistency(readonly) {
+                    (conjunction, true)
+                } else if let Some(conjunction) = negation.detect_entailment(readonly) {
+                    (conjunction, false)
+                } else {
+                    return Ok(());
+                }
+            } else {
+                return Ok(());
+            };
+
+        context.assign_literal(self.reification_literal, value, conjunction)
     }
 }
 
@@ -125,6 +199,8 @@ mod tests {
     use crate::predicate;
     use crate::predicates::Predicate;
     use crate::predicates::PropositionalConjunction;
+    use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+    use crate::variables::TransformableVariable;
 
     #[test]
     fn a_detected_inconsistency_is_given_as_reason_for_propagating_reification_literal_to_false() {
@@ -245,6 +321,105 @@ mod tests {
         assert!(solver.is_literal_false(reification_literal));
     }
 
+    #[test]
+    fn a_detected_entailment_propagates_reification_literal_to_true() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(1, 3);
+
+        // r <-> [a + b <= 5], of which the negation is [-a - b <= -6].
+        let _ = solver
+            .new_propagator(ReifiedPropagator::with_negation(
+                LinearLessOrEqualPropagator::new([a, b].into(), 5),
+                LinearLessOrEqualPropagator::new([a.scaled(-1), b.scaled(-1)].into(), -6),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        assert!(solver.is_literal_true(reification_literal));
+
+        let reason = solver.get_reason_bool(reification_literal, true);
+        assert_eq!(reason, &conjunction!([a <= 2] & [b <= 3]));
+    }
+
+    #[test]
+    fn a_detected_entailment_does_not_propagate_half_reification_literal() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearLessOrEqualPropagator::new([a, b].into(), 5),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        assert!(!solver.is_literal_true(reification_literal));
+        assert!(!solver.is_literal_false(reification_literal));
+    }
+
+    #[test]
+    fn the_negation_is_propagated_when_reification_literal_is_false() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let a = solver.new_variable(0, 5);
+
+        // r <-> [a <= 2], of which the negation is [-a <= -3].
+        let mut propagator = solver
+            .new_propagator(ReifiedPropagator::with_negation(
+                LinearLessOrEqualPropagator::new([a].into(), 2),
+                LinearLessOrEqualPropagator::new([a.scaled(-1)].into(), -3),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        solver.assert_bounds(a, 0, 5);
+
+        solver.set_literal(reification_literal, false);
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        solver.assert_bounds(a, 3, 5);
+        let reason = solver.get_reason_int(predicate![a >= 3].try_into().unwrap());
+        assert_eq!(
+            reason,
+            &PropositionalConjunction::from(Predicate::from(!reification_literal))
+        );
+    }
+
+    #[test]
+    fn an_inconsistent_negation_propagates_reification_literal_to_true() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let a = solver.new_variable(0, 5);
+
+        // r <-> [a <= 2], of which the negation is [-a <= -3].
+        let mut propagator = solver
+            .new_propagator(ReifiedPropagator::with_negation(
+                LinearLessOrEqualPropagator::new([a].into(), 2),
+                LinearLessOrEqualPropagator::new([a.scaled(-1)].into(), -3),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        assert!(!solver.is_literal_true(reification_literal));
+
+        for value in 3..=5 {
+            solver.remove(a, value).expect("non-empty domain");
+        }
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        assert!(solver.is_literal_true(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, true);
+        assert_eq!(reason, &conjunction!([a <= 2]));
+    }
+
     struct GenericPropagator<Propagation, ConsistencyCheck, Init> {
         propagation: Propagation,
         consistency_check: ConsistencyCheck,
@@ -0,0 +1,119 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::propagators::explain_absent_value;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint which states that for every pair of consecutive values `s` and
+/// `t` in `values`, if some `x_i = t` then there is an `h < i` such that `x_h = s`.
+///
+/// Every pair `(s, t)` is propagated with the algorithm of \[1\]. Let `α` be the first index for
+/// which `s` is in the domain of `x_α`, and `β` the next one. Then `t` is removed from the domains
+/// of `x_0, ..., x_α`, which is explained by `s` not being in the domains of the earlier
+/// variables. If there is a `γ < β` such that `x_γ = t`, then `x_α` has to be `s`.
+///
+/// # Bibliography
+/// \[1\] Y. C. Law and J. H. M. Lee, ‘Global constraints for integer and set value precedence’, in
+/// Principles and Practice of Constraint Programming (CP 2004), 2004, pp. 362–376.
+#[derive(Debug)]
+pub(crate) struct ValuePrecedeChainPropagator<Var> {
+    values: Box<[i32]>,
+    variables: Box<[Var]>,
+}
+
+impl<Var> ValuePrecedeChainPropagator<Var> {
+    pub(crate) fn new(values: Box<[i32]>, variables: Box<[Var]>) -> Self {
+        ValuePrecedeChainPropagator { values, variables }
+    }
+}
+
+/// Propagates that `s` precedes `t` in `variables`.
+fn propagate_precedence<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    s: i32,
+    t: i32,
+) -> PropagationStatusCP {
+    // The explanation of why `s` is not in the domain of any of the variables considered so far.
+    let mut absent_s: Vec<Predicate> = Vec::new();
+
+    let mut alpha = None;
+    for (i, variable) in variables.iter().enumerate() {
+        context.remove(
+            variable,
+            t,
+            PropositionalConjunction::from(absent_s.clone()),
+        )?;
+
+        if context.contains(variable, s) {
+            alpha = Some(i);
+            break;
+        }
+
+        absent_s.push(explain_absent_value(context.as_readonly(), variable, s));
+    }
+
+    let Some(alpha) = alpha else {
+        return Ok(());
+    };
+
+    for variable in variables.iter().skip(alpha + 1) {
+        if context.contains(variable, s) {
+            // This is `β`; there is a second variable which can take `s`.
+            return Ok(());
+        }
+
+        if context.is_fixed(variable) && context.lower_bound(variable) == t {
+            // This is `γ`; only `x_α` can take `s` before it.
+            let reason: PropositionalConjunction = absent_s
+                .into_iter()
+                .chain(std::iter::once(predicate![variable == t]))
+                .collect();
+
+            let alpha_variable = &variables[alpha];
+            context.set_lower_bound(alpha_variable, s, reason.clone())?;
+            context.set_upper_bound(alpha_variable, s, reason)?;
+
+            return Ok(());
+        }
+
+        absent_s.push(explain_absent_value(context.as_readonly(), variable, s));
+    }
+
+    Ok(())
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for ValuePrecedeChainPropagator<Var> {
+    fn name(&self) -> &str {
+        "ValuePrecedeChain"
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for variable in self.variables.iter() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        for pair in self.values.windows(2) {
+            propagate_precedence(&mut context, &self.variables, pair[0], pair[1])?;
+        }
+
+        Ok(())
+    }
+}
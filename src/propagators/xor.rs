@@ -0,0 +1,196 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicates::Predicate;
+use crate::variables::Literal;
+
+/// A linear equation over GF(2); the variables in the equation sum up to `parity` modulo 2.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Equation {
+    /// A bitset over the variables of the system, with bit `v` set if variable `v` occurs in the
+    /// equation.
+    coefficients: Box<[u64]>,
+    parity: bool,
+}
+
+impl Equation {
+    fn new(number_of_variables: usize) -> Self {
+        Equation {
+            coefficients: vec![0; number_of_variables.div_ceil(64)].into(),
+            parity: false,
+        }
+    }
+
+    fn contains(&self, variable: usize) -> bool {
+        self.coefficients[variable / 64] & (1 << (variable % 64)) != 0
+    }
+
+    fn flip(&mut self, variable: usize) {
+        self.coefficients[variable / 64] ^= 1 << (variable % 64);
+    }
+
+    fn add(&mut self, other: &Equation) {
+        for (word, other_word) in self.coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *word ^= other_word;
+        }
+        self.parity ^= other.parity;
+    }
+}
+
+/// Propagator for a system of XOR constraints, where every constraint states that an odd (or
+/// even) number of its literals is true.
+///
+/// The system is solved by Gaussian elimination over GF(2) on the unassigned variables \[1\].
+/// After the elimination, an equation over a single unassigned variable determines its value, and
+/// an equation without unassigned variables which is violated is a conflict. Such an equation is
+/// the sum of some of the original equations; its explanation consists of the values of the
+/// assigned variables which occur in it.
+///
+/// # Bibliography
+/// \[1\] M. Soos, K. Nohl, and C. Castelluccia, ‘Extending SAT solvers to cryptographic
+/// problems’, in Theory and Applications of Satisfiability Testing (SAT 2009), 2009, pp. 244–257.
+#[derive(Debug)]
+pub(crate) struct XorPropagator {
+    /// The (positive) literals of the distinct propositional variables in the system.
+    variables: Box<[Literal]>,
+    equations: Box<[Equation]>,
+}
+
+impl XorPropagator {
+    /// Creates the propagator for the system in which, for every `(literals, parity)`, the number
+    /// of true literals is odd if `parity` is true and even otherwise.
+    pub(crate) fn new(system: impl IntoIterator<Item = (Vec<Literal>, bool)>) -> Self {
+        let system: Vec<(Vec<Literal>, bool)> = system.into_iter().collect();
+
+        let mut variables: Vec<Literal> = system
+            .iter()
+            .flat_map(|(literals, _)| literals.iter())
+            .map(|&literal| positive(literal))
+            .collect();
+        variables.sort_by_key(|literal| literal.to_u32());
+        variables.dedup();
+
+        let equations = system
+            .into_iter()
+            .map(|(literals, parity)| {
+                let mut equation = Equation::new(variables.len());
+                equation.parity = parity;
+
+                for literal in literals {
+                    let variable = variables
+                        .binary_search_by_key(&positive(literal).to_u32(), |v| v.to_u32())
+                        .expect("every literal has a variable");
+
+                    // A variable which occurs twice cancels out.
+                    equation.flip(variable);
+                    // A negative literal is true when its variable is false.
+                    equation.parity ^= literal.is_negative();
+                }
+
+                equation
+            })
+            .collect();
+
+        XorPropagator {
+            variables: variables.into(),
+            equations,
+        }
+    }
+
+    /// Reduces the equations on the unassigned variables. Returns the reduced equations.
+    fn eliminate(&self, context: PropagationContext) -> Vec<Equation> {
+        let mut equations = self.equations.to_vec();
+        let mut next_pivot_row = 0;
+
+        for variable in 0..self.variables.len() {
+            if context.is_literal_fixed(self.variables[variable]) {
+                continue;
+            }
+
+            let Some(pivot) =
+                (next_pivot_row..equations.len()).find(|&row| equations[row].contains(variable))
+            else {
+                continue;
+            };
+            equations.swap(next_pivot_row, pivot);
+
+            let pivot_equation = equations[next_pivot_row].clone();
+            for (row, equation) in equations.iter_mut().enumerate() {
+                if row != next_pivot_row && equation.contains(variable) {
+                    equation.add(&pivot_equation);
+                }
+            }
+
+            next_pivot_row += 1;
+        }
+
+        equations
+    }
+}
+
+/// The positive literal over the propositional variable of `literal`.
+fn positive(literal: Literal) -> Literal {
+    Literal::new(literal.get_propositional_variable(), true)
+}
+
+impl Propagator for XorPropagator {
+    fn name(&self) -> &str {
+        "Xor"
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for &variable in self.variables.iter() {
+            let local_id = context.get_next_local_id();
+            let _ = context.register_literal(variable, DomainEvents::ANY_BOOL, local_id);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        for equation in self.eliminate(context.as_readonly()) {
+            let mut parity = equation.parity;
+            let mut unassigned = Vec::new();
+            let mut reason: Vec<Predicate> = Vec::new();
+
+            for (index, &variable) in self.variables.iter().enumerate() {
+                if !equation.contains(index) {
+                    continue;
+                }
+
+                if context.is_literal_true(variable) {
+                    parity ^= true;
+                    reason.push(variable.into());
+                } else if context.is_literal_false(variable) {
+                    reason.push((!variable).into());
+                } else {
+                    unassigned.push(variable);
+                }
+            }
+
+            match unassigned.as_slice() {
+                [] if parity => return Err(PropositionalConjunction::from(reason).into()),
+                &[variable] => context.assign_literal(
+                    variable,
+                    parity,
+                    PropositionalConjunction::from(reason),
+                )?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -8,6 +9,7 @@ use crate::branching::Brancher;
 use crate::model::Globals;
 use crate::model::IntVariable;
 use crate::model::Model;
+use crate::model::ModelError;
 use crate::model::Output;
 use crate::model::VariableMap;
 use crate::options::SolverOptions;
@@ -15,6 +17,11 @@ use crate::results::OptimisationResult;
 use crate::results::ProblemSolution;
 use crate::results::Solution;
 use crate::statistics::configure;
+use crate::termination::Combinator;
+use crate::termination::ConflictBudget;
+use crate::termination::DecisionBudget;
+use crate::termination::PropagationBudget;
+use crate::termination::TerminationCondition;
 use crate::termination::TimeBudget;
 use crate::Solver;
 
@@ -50,6 +57,36 @@ pub enum Action<SearchStrategies: OptionEnum> {
         #[arg(short = 'S', long = "search", value_enum, default_value_t)]
         search_strategy: SearchStrategies,
 
+        /// A DZN file containing a (partial) assignment to the output variables, which is used as
+        /// a hint to start the search from.
+        ///
+        /// The file uses the same format as a solution which is printed by the solver, without the
+        /// `----------` separator. An error is reported if the search strategy does not use the
+        /// hint, i.e. if it is not based on the default brancher.
+        #[arg(short = 'H', long = "hint")]
+        hint_path: Option<PathBuf>,
+
+        /// Log the statistics of the propagators as a single JSON object.
+        #[arg(long = "json-propagator-statistics")]
+        json_propagator_statistics: bool,
+
+        /// Measure and log the time spent in every propagator.
+        #[arg(long = "profile-propagators")]
+        profile_propagators: bool,
+
+        /// The maximum number of conflicts the solver is allowed to encounter.
+        #[arg(long = "conflict-budget")]
+        conflict_budget: Option<u64>,
+
+        /// The maximum number of decisions the solver is allowed to make.
+        #[arg(long = "decision-budget")]
+        decision_budget: Option<u64>,
+
+        /// The maximum number of propagations (changes to the domains of integer variables) the
+        /// solver is allowed to make.
+        #[arg(long = "propagation-budget")]
+        propagation_budget: Option<u64>,
+
         /// The number of seconds the solver is allowed to run.
         time_out: u64,
     },
@@ -114,35 +151,86 @@ where
             globals,
             proof_path,
             search_strategy,
+            hint_path,
+            json_propagator_statistics,
+            profile_propagators,
+            conflict_budget,
+            decision_budget,
+            propagation_budget,
             time_out,
-        } => solve(
-            model,
-            instance,
-            search_strategy,
-            globals,
-            proof_path,
-            Duration::from_secs(time_out),
-        ),
+        } => {
+            let mut termination = Combinator::<Box<dyn TerminationCondition>>::new(vec![]);
+            if let Some(budget) = conflict_budget {
+                termination.add(Box::new(ConflictBudget::new(budget)));
+            }
+            if let Some(budget) = decision_budget {
+                termination.add(Box::new(DecisionBudget::new(budget)));
+            }
+            if let Some(budget) = propagation_budget {
+                termination.add(Box::new(PropagationBudget::new(budget)));
+            }
+
+            solve(
+                model,
+                instance,
+                search_strategy,
+                globals,
+                proof_path,
+                hint_path,
+                json_propagator_statistics,
+                profile_propagators,
+                termination,
+                Duration::from_secs(time_out),
+            )
+        }
         Action::Verify { proof_path } => verify(model, proof_path),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn solve<SearchStrategies>(
     model: Model,
     instance: impl Problem<SearchStrategies>,
     search_strategy: SearchStrategies,
     globals: Vec<Globals>,
     _proof_path: Option<PathBuf>,
+    hint_path: Option<PathBuf>,
+    json_propagator_statistics: bool,
+    profile_propagators: bool,
+    mut termination: Combinator<Box<dyn TerminationCondition>>,
     time_out: Duration,
 ) -> anyhow::Result<()> {
-    let (mut solver, solver_variables) = model.into_solver(
+    use anyhow::Context;
+
+    let (mut solver, solver_variables) = match model.into_solver(
         SolverOptions {
+            log_propagator_statistics_as_json: json_propagator_statistics,
+            measure_propagator_time: profile_propagators,
             ..Default::default()
         },
         |global| globals.contains(&global),
-    );
+    ) {
+        Ok(solver) => solver,
+        Err(error @ ModelError::Infeasible { .. }) => {
+            println!("%% {error}");
+            println!("UNSATISFIABLE");
+            return Ok(());
+        }
+        Err(error) => return Err(error).context("Failed to create a solver from the model"),
+    };
 
     let output_variables: Vec<_> = instance.get_output_variables().collect();
+
+    let is_hinted = hint_path.is_some();
+    if let Some(hint_path) = hint_path {
+        add_hints(
+            &mut solver,
+            &solver_variables,
+            &output_variables,
+            &hint_path,
+        )?;
+    }
+
     let callback_solver_variables = solver_variables.clone();
 
     solver.with_solution_callback(move |solution| {
@@ -154,10 +242,15 @@ pub fn solve<SearchStrategies>(
     });
 
     let mut brancher = instance.get_search(search_strategy, &solver, &solver_variables);
-    let mut time_budget = TimeBudget::starting_now(time_out);
+    if is_hinted && !brancher.uses_hints() {
+        anyhow::bail!(
+            "The search strategy does not use hints; use a strategy based on the default brancher"
+        );
+    }
+    termination.add(Box::new(TimeBudget::starting_now(time_out)));
     let objective_variable = solver_variables.to_solver_variable(instance.objective());
 
-    match solver.minimise(&mut brancher, &mut time_budget, objective_variable) {
+    match solver.minimise(&mut brancher, &mut termination, objective_variable) {
         // Printing of the solution is handled in the callback.
         OptimisationResult::Optimal(_) => println!("=========="),
         OptimisationResult::Satisfiable(_) => {}
@@ -175,6 +268,51 @@ pub fn solve<SearchStrategies>(
     Ok(())
 }
 
+/// Adds the values of the output variables in the DZN file at `hint_path` as hints to the solver
+/// (see [`Solver::add_hint`]); output variables which are not in the file are not hinted.
+fn add_hints(
+    solver: &mut Solver,
+    solver_variables: &VariableMap,
+    output_variables: &[Output],
+    hint_path: &Path,
+) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    let hint = std::fs::read_to_string(hint_path)
+        .with_context(|| format!("Error reading {}", hint_path.display()))?;
+
+    let hint = dzn_rs::parse::<i32>(hint.as_bytes())
+        .with_context(|| format!("Failed to parse DZN from {}", hint_path.display()))?;
+
+    for output in output_variables {
+        let name = solver_variables.get_name(output);
+
+        match output {
+            Output::Variable(variable) => {
+                if let Some(&value) = hint.get::<i32>(&name) {
+                    solver.add_hint(solver_variables.to_solver_variable(*variable), value);
+                }
+            }
+
+            Output::Array(int_variable_array) => {
+                let variables = solver_variables.get_array(*int_variable_array);
+
+                if let Some(values) = hint.array_1d::<i32>(&name, variables.len()) {
+                    for (index, variable) in variables.into_iter().enumerate() {
+                        let value = *values.get([index]).expect("index is within range");
+                        solver.add_hint(variable, value);
+                    }
+                }
+            }
+
+            // Only integer variables can be hinted.
+            Output::Boolean(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
 fn print_output(output: &Output, solver_variables: &VariableMap, solution: &Solution) {
     let name = solver_variables.get_name(output);
 
@@ -202,6 +340,12 @@ fn print_output(output: &Output, solver_variables: &VariableMap, solution: &Solu
             }
             println!("];");
         }
+
+        Output::Boolean(bool_variable) => {
+            let literal = solver_variables.to_solver_literal(*bool_variable);
+
+            println!("{name} = {};", solution.get_literal_value(literal));
+        }
     }
 }
 
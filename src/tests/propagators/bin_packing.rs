@@ -0,0 +1,128 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::bin_packing::BinPackingPropagator;
+
+#[test]
+fn loads_are_bounded_by_items() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(0, 1);
+    let x2 = solver.new_variable(1, 1);
+    let l0 = solver.new_variable(0, 20);
+    let l1 = solver.new_variable(0, 20);
+
+    let _ = solver
+        .new_propagator(BinPackingPropagator::new(
+            [x0, x1, x2].into(),
+            [3, 4, 5].into(),
+            [l0, l1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(l0, 3, 7);
+    solver.assert_bounds(l1, 5, 9);
+
+    let reason = solver.get_reason_int(predicate![l0 >= 3].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![x0 == 0]])
+    );
+
+    let reason = solver.get_reason_int(predicate![l0 <= 7].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![x2 != 0]])
+    );
+}
+
+#[test]
+fn item_which_does_not_fit_is_eliminated() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(0, 1);
+    let l0 = solver.new_variable(0, 6);
+    let l1 = solver.new_variable(0, 6);
+
+    let _ = solver
+        .new_propagator(BinPackingPropagator::new(
+            [x0, x1].into(),
+            [4, 3].into(),
+            [l0, l1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x1, 1, 1);
+
+    let reason = solver.get_reason_int(predicate![x1 != 0].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![x0 == 0], predicate![l0 <= 6]])
+    );
+}
+
+#[test]
+fn item_needed_for_minimum_load_is_committed() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 1);
+    let x1 = solver.new_variable(0, 1);
+    let l0 = solver.new_variable(5, 10);
+    let l1 = solver.new_variable(0, 10);
+
+    let _ = solver
+        .new_propagator(BinPackingPropagator::new(
+            [x0, x1].into(),
+            [4, 2].into(),
+            [l0, l1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x0, 0, 0);
+    solver.assert_bounds(x1, 0, 0);
+}
+
+#[test]
+fn loads_are_coherent_with_total_size() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 1);
+    let x1 = solver.new_variable(0, 1);
+    let l0 = solver.new_variable(0, 10);
+    let l1 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(BinPackingPropagator::new(
+            [x0, x1].into(),
+            [3, 4].into(),
+            [l0, l1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(l0, 5, 7);
+}
+
+#[test]
+fn lower_bound_detects_too_few_bins() {
+    let mut solver = TestSolver::default();
+
+    // Three items of size 6 cannot be packed into two bins of capacity 10, although their total
+    // size fits.
+    let x0 = solver.new_variable(0, 1);
+    let x1 = solver.new_variable(0, 1);
+    let x2 = solver.new_variable(0, 1);
+    let l0 = solver.new_variable(0, 10);
+    let l1 = solver.new_variable(0, 10);
+    let l2 = solver.new_variable(0, 0);
+
+    let _ = solver
+        .new_propagator(BinPackingPropagator::new(
+            [x0, x1, x2].into(),
+            [6, 6, 6].into(),
+            [l0, l1, l2].into(),
+        ))
+        .expect_err("Expected conflict");
+}
@@ -1,2 +1,3 @@
 pub(crate) mod dfs;
 pub(crate) mod forward_checking;
+pub(crate) mod subcircuit;
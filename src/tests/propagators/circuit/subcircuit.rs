@@ -0,0 +1,80 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::circuit::SubCircuitPropagator;
+
+#[test]
+fn closed_cycle_excludes_other_nodes() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(1, 1);
+    let b = solver.new_variable(0, 0);
+    let c = solver.new_variable(0, 3);
+    let d = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(SubCircuitPropagator::new([a, b, c, d].into()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(c, 2, 2);
+    solver.assert_bounds(d, 3, 3);
+
+    let reason = solver.get_reason_int(predicate![c >= 2].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![a == 1], predicate![b == 0]])
+    );
+}
+
+#[test]
+fn second_cycle_is_a_conflict() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(1, 1);
+    let b = solver.new_variable(0, 0);
+    let c = solver.new_variable(3, 3);
+    let d = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(SubCircuitPropagator::new([a, b, c, d].into()))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn premature_closure_is_removed_when_a_node_is_included() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(1, 1);
+    let b = solver.new_variable(0, 3);
+    let c = solver.new_sparse_variable(&[0, 1, 3]);
+    let d = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(SubCircuitPropagator::new([a, b, c, d].into()))
+        .expect("Expected no conflict");
+
+    assert!(!solver.contains(b, 0));
+
+    let reason = solver.get_reason_int(predicate![b != 0].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![a == 1], predicate![c != 2]])
+    );
+}
+
+#[test]
+fn closure_is_allowed_when_all_other_nodes_can_be_excluded() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(1, 1);
+    let b = solver.new_variable(0, 3);
+    let c = solver.new_variable(0, 3);
+    let d = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(SubCircuitPropagator::new([a, b, c, d].into()))
+        .expect("Expected no conflict");
+
+    assert!(solver.contains(b, 0));
+}
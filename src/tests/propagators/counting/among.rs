@@ -0,0 +1,86 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::counting::among::AmongPropagator;
+
+#[test]
+fn count_bounds_are_propagated() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_sparse_variable(&[2, 3]);
+    let z = solver.new_variable(4, 5);
+    let count = solver.new_variable(0, 10);
+
+    let _ = solver
+        .new_propagator(AmongPropagator::new(
+            [x, y, z].into(),
+            [3, 2, 1].into(),
+            count,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(count, 2, 2);
+
+    let reason = solver.get_reason_int(predicate![count <= 2].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![z >= 4]])
+    );
+}
+
+#[test]
+fn variables_are_forced_in_when_count_is_at_lower_bound() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 2);
+    let y = solver.new_variable(0, 2);
+    let count = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(AmongPropagator::new([x, y].into(), [1, 2].into(), count))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x, 1, 2);
+    solver.assert_bounds(y, 1, 2);
+}
+
+#[test]
+fn variables_are_forced_out_when_count_is_at_upper_bound() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(0, 2);
+    let count = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(AmongPropagator::new([x, y].into(), [1].into(), count))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(count, 1, 1);
+    solver.assert_domain(y, vec![0, 2]);
+
+    let reason = solver.get_reason_int(predicate![y != 1].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![x >= 1],
+            predicate![x <= 1],
+            predicate![count <= 1]
+        ])
+    );
+}
+
+#[test]
+fn detects_conflict() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(1, 1);
+    let count = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(AmongPropagator::new([x, y].into(), [1].into(), count))
+        .expect_err("Expected conflict");
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+use crate::engine::test_helper::TestSolver;
+use crate::propagators::counting::global_cardinality::GlobalCardinalityPropagator;
+
+#[test]
+fn counts_are_propagated_per_value() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(1, 2);
+    let z = solver.new_variable(3, 3);
+    let count_1 = solver.new_variable(0, 3);
+    let count_2 = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(GlobalCardinalityPropagator::new(
+            [x, y, z].into(),
+            [1, 2].into(),
+            [count_1, count_2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(count_1, 1, 2);
+    solver.assert_bounds(count_2, 0, 1);
+}
+
+#[test]
+fn counts_are_propagated_with_respect_to_each_other() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 2);
+    let y = solver.new_variable(1, 2);
+    let z = solver.new_variable(1, 2);
+    let count_1 = solver.new_variable(0, 1);
+    let count_2 = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(GlobalCardinalityPropagator::new(
+            [x, y, z].into(),
+            [1, 2].into(),
+            [count_1, count_2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(count_2, 2, 3);
+}
+
+#[test]
+fn values_are_removed_when_counts_are_fixed() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(1, 3);
+    let count_1 = solver.new_variable(1, 1);
+    let count_2 = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(GlobalCardinalityPropagator::new(
+            [x, y].into(),
+            [1, 2].into(),
+            [count_1, count_2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(y, 2, 2);
+}
+
+#[test]
+fn detects_conflict() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 2);
+    let y = solver.new_variable(1, 2);
+    let count_1 = solver.new_variable(0, 0);
+    let count_2 = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(GlobalCardinalityPropagator::new(
+            [x, y].into(),
+            [1, 2].into(),
+            [count_1, count_2].into(),
+        ))
+        .expect_err("Expected conflict");
+}
@@ -0,0 +1,2 @@
+pub(crate) mod among;
+pub(crate) mod global_cardinality;
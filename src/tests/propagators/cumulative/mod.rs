@@ -1,2 +1,3 @@
 pub(crate) mod energetic_reasoning;
+pub(crate) mod optional_time_table;
 pub(crate) mod time_table;
@@ -0,0 +1,99 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::cumulative::OptionalTimeTablePropagator;
+
+#[test]
+fn overload_of_present_intervals_is_detected() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 3, 3, 3);
+    let b = solver.new_interval_variable(1, 4, 3, 3);
+    solver.set_literal(a.presence(), true);
+    solver.set_literal(b.presence(), true);
+
+    let _ = solver
+        .new_propagator(OptionalTimeTablePropagator::new(
+            [a, b].into(),
+            [1, 1].into(),
+            1,
+        ))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn earliest_start_is_pushed_past_profile() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 4, 3, 3);
+    let b = solver.new_interval_variable(0, 12, 2, 2);
+    solver.set_literal(a.presence(), true);
+    solver.set_literal(b.presence(), true);
+
+    let _ = solver
+        .new_propagator(OptionalTimeTablePropagator::new(
+            [a, b].into(),
+            [1, 1].into(),
+            1,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(b.start(), 3, 10);
+
+    let start = b.start();
+    let duration = b.duration();
+    let a_start = a.start();
+    let a_end = a.end();
+    let reason = solver.get_reason_int(predicate![start >= 3].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![a_start <= 2],
+            predicate![a_end >= 3],
+            a.presence().into(),
+            predicate![start >= 1],
+            predicate![duration >= 2],
+            b.presence().into(),
+        ])
+    );
+}
+
+#[test]
+fn interval_which_does_not_fit_is_absent() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 5, 5, 5);
+    let b = solver.new_interval_variable(0, 4, 2, 2);
+    solver.set_literal(a.presence(), true);
+
+    let _ = solver
+        .new_propagator(OptionalTimeTablePropagator::new(
+            [a, b].into(),
+            [2, 1].into(),
+            2,
+        ))
+        .expect("Expected no conflict");
+
+    assert!(solver.is_literal_false(b.presence()));
+}
+
+#[test]
+fn absent_interval_does_not_use_resource() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 5, 5, 5);
+    let b = solver.new_interval_variable(0, 10, 2, 2);
+    solver.set_literal(a.presence(), false);
+    solver.set_literal(b.presence(), true);
+
+    let _ = solver
+        .new_propagator(OptionalTimeTablePropagator::new(
+            [a, b].into(),
+            [1, 1].into(),
+            1,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(b.start(), 0, 8);
+}
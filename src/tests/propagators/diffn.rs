@@ -0,0 +1,124 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::diffn::DiffnPropagator;
+
+#[test]
+fn overlapping_fixed_rectangles_are_a_conflict() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 0);
+    let y0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(1, 1);
+    let y1 = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(DiffnPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            [2, 2].into(),
+            [2, 2].into(),
+        ))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn rectangles_without_area_do_not_overlap() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 0);
+    let y0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(1, 1);
+    let y1 = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(DiffnPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            [2, 0].into(),
+            [2, 2].into(),
+        ))
+        .expect("Expected no conflict");
+}
+
+#[test]
+fn only_possible_relative_position_is_enforced() {
+    let mut solver = TestSolver::default();
+
+    // The rectangles are stacked in the y-dimension, so the second has to be right of the first.
+    let x0 = solver.new_variable(0, 1);
+    let y0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(0, 10);
+    let y1 = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(DiffnPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            [3, 2].into(),
+            [2, 2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x1, 3, 10);
+    solver.assert_bounds(x0, 0, 1);
+
+    let reason = solver.get_reason_int(predicate![x1 >= 3].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![x1 >= 0],
+            predicate![x0 <= 1],
+            predicate![y0 >= 0],
+            predicate![y1 <= 1],
+            predicate![y1 >= 1],
+            predicate![y0 <= 2],
+            predicate![x0 >= 0],
+        ])
+    );
+}
+
+#[test]
+fn sweep_pushes_past_compulsory_parts() {
+    let mut solver = TestSolver::default();
+
+    // Two fixed rectangles cover `[0, 2) x [0, 2)` and `[0, 4) x [2, 4)`. A 1x2 rectangle which
+    // can be placed anywhere in the y-dimension does not fit before x = 2, and placements with
+    // x in [2, 4) can still be reached in the y-dimension.
+    let x0 = solver.new_variable(0, 0);
+    let y0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(0, 0);
+    let y1 = solver.new_variable(2, 2);
+    let x2 = solver.new_variable(0, 5);
+    let y2 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(DiffnPropagator::new(
+            [x0, x1, x2].into(),
+            [y0, y1, y2].into(),
+            [2, 4, 1].into(),
+            [2, 2, 2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x2, 2, 5);
+
+    let reason = solver.get_reason_int(predicate![x2 >= 2].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![x0 >= 0],
+            predicate![x0 <= 0],
+            predicate![y0 >= 0],
+            predicate![y0 <= 0],
+            predicate![x1 >= 0],
+            predicate![x1 <= 0],
+            predicate![y1 >= 2],
+            predicate![y1 <= 2],
+            predicate![y2 >= 0],
+            predicate![y2 <= 2],
+            predicate![x2 >= 0],
+        ])
+    );
+}
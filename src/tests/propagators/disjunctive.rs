@@ -0,0 +1,131 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::propagators::disjunctive::OptionalDisjunctivePropagator;
+
+#[test]
+fn overload_is_detected() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 2);
+    let s2 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(DisjunctivePropagator::new([s1, s2].into(), [3, 3].into()))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn detectable_precedence_updates_earliest_start_time() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 1);
+    let s2 = solver.new_variable(0, 10);
+
+    let _ = solver
+        .new_propagator(DisjunctivePropagator::new([s1, s2].into(), [4, 2].into()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(s1, 0, 1);
+    solver.assert_bounds(s2, 4, 10);
+
+    let reason = solver.get_reason_int(predicate![s2 >= 4].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![s2 >= 0],
+            predicate![s1 >= 0],
+            predicate![s1 <= 1],
+        ])
+    );
+}
+
+#[test]
+fn edge_finding_updates_earliest_start_time() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 3);
+    let s2 = solver.new_variable(0, 3);
+    let s3 = solver.new_variable(0, 20);
+
+    let mut propagator = solver
+        .new_propagator(DisjunctivePropagator::new(
+            [s1, s2, s3].into(),
+            [3, 3, 2].into(),
+        ))
+        .expect("Expected no conflict");
+    let result = solver.propagate_until_fixed_point(&mut propagator);
+    assert!(result.is_ok());
+
+    solver.assert_bounds(s3, 6, 20);
+}
+
+#[test]
+fn not_last_updates_latest_start_time() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 4);
+    let s2 = solver.new_variable(0, 4);
+    let s3 = solver.new_variable(0, 5);
+
+    let mut propagator = solver
+        .new_propagator(DisjunctivePropagator::new(
+            [s1, s2, s3].into(),
+            [3, 3, 1].into(),
+        ))
+        .expect("Expected no conflict");
+    let result = solver.propagate_until_fixed_point(&mut propagator);
+    assert!(result.is_ok());
+
+    solver.assert_bounds(s3, 0, 3);
+}
+
+#[test]
+fn tasks_without_duration_are_ignored() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 0);
+    let s2 = solver.new_variable(0, 5);
+
+    let _ = solver
+        .new_propagator(DisjunctivePropagator::new([s1, s2].into(), [0, 3].into()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(s2, 0, 5);
+}
+
+#[test]
+fn optional_interval_which_overloads_is_absent() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 5, 4, 4);
+    let b = solver.new_interval_variable(0, 5, 2, 3);
+    solver.set_literal(a.presence(), true);
+
+    let _ = solver
+        .new_propagator(OptionalDisjunctivePropagator::new([a, b].into()))
+        .expect("Expected no conflict");
+
+    assert!(solver.is_literal_false(b.presence()));
+}
+
+#[test]
+fn present_intervals_with_variable_durations_are_ordered() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_interval_variable(0, 5, 4, 6);
+    let b = solver.new_interval_variable(0, 12, 2, 3);
+    let c = solver.new_interval_variable(0, 12, 2, 3);
+    solver.set_literal(a.presence(), true);
+    solver.set_literal(b.presence(), true);
+    solver.set_literal(c.presence(), false);
+
+    let _ = solver
+        .new_propagator(OptionalDisjunctivePropagator::new([a, b, c].into()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(b.start(), 4, 10);
+    solver.assert_bounds(c.start(), 0, 10);
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::inverse::InversePropagator;
+
+#[test]
+fn values_are_restricted_to_index_range() {
+    let mut solver = TestSolver::default();
+
+    let f = [solver.new_variable(-5, 5), solver.new_variable(0, 1)];
+    let g = [solver.new_variable(0, 1), solver.new_variable(0, 10)];
+
+    let _ = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(f[0], 0, 1);
+    solver.assert_bounds(g[1], 0, 1);
+}
+
+#[test]
+fn removals_are_channelled() {
+    let mut solver = TestSolver::default();
+
+    let f = [
+        solver.new_variable(0, 2),
+        solver.new_variable(0, 2),
+        solver.new_variable(0, 2),
+    ];
+    let g = [
+        solver.new_sparse_variable(&[0, 2]),
+        solver.new_variable(0, 2),
+        solver.new_variable(0, 2),
+    ];
+
+    let _ = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("Expected no conflict");
+
+    solver.assert_domain(f[1], vec![1, 2]);
+
+    let reason = solver.get_reason_int(predicate![f[1] != 0].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![g[0] != 1]])
+    );
+}
+
+#[test]
+fn assignment_is_channelled() {
+    let mut solver = TestSolver::default();
+
+    let f = [solver.new_variable(1, 1), solver.new_variable(0, 1)];
+    let g = [solver.new_variable(0, 1), solver.new_variable(0, 1)];
+
+    let mut propagator = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("Expected no conflict");
+    let result = solver.propagate_until_fixed_point(&mut propagator);
+    assert!(result.is_ok());
+
+    solver.assert_bounds(g[1], 0, 0);
+    solver.assert_bounds(g[0], 1, 1);
+    solver.assert_bounds(f[1], 0, 0);
+}
+
+#[test]
+fn detects_conflict() {
+    let mut solver = TestSolver::default();
+
+    let f = [solver.new_variable(1, 1), solver.new_variable(1, 1)];
+    let g = [solver.new_variable(0, 1), solver.new_variable(0, 1)];
+
+    let _ = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect_err("Expected conflict");
+}
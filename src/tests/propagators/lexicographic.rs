@@ -0,0 +1,130 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::lexicographic::LexicographicPropagator;
+
+#[test]
+fn first_position_is_bounded() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 5);
+    let x1 = solver.new_variable(0, 5);
+    let y0 = solver.new_variable(0, 3);
+    let y1 = solver.new_variable(0, 5);
+
+    let _ = solver
+        .new_propagator(LexicographicPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            false,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x0, 0, 3);
+    solver.assert_bounds(x1, 0, 5);
+
+    let reason = solver.get_reason_int(predicate![x0 <= 3].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![y0 <= 3]])
+    );
+}
+
+#[test]
+fn suffix_makes_first_position_strict() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 5);
+    let x1 = solver.new_variable(4, 5);
+    let y0 = solver.new_variable(0, 3);
+    let y1 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(LexicographicPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            false,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x0, 0, 2);
+    solver.assert_bounds(y0, 1, 3);
+
+    let reason = solver.get_reason_int(predicate![x0 <= 2].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![x1 >= 3],
+            predicate![y1 <= 2],
+            predicate![y0 <= 3],
+        ])
+    );
+}
+
+#[test]
+fn tied_prefix_bounds_next_position() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(3, 3);
+    let x1 = solver.new_variable(0, 9);
+    let y0 = solver.new_variable(3, 3);
+    let y1 = solver.new_variable(0, 4);
+
+    let _ = solver
+        .new_propagator(LexicographicPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            true,
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x1, 0, 3);
+    solver.assert_bounds(y1, 1, 4);
+
+    let reason = solver.get_reason_int(predicate![x1 <= 3].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![
+            predicate![x0 >= 3],
+            predicate![y0 <= 3],
+            predicate![y1 <= 4],
+        ])
+    );
+}
+
+#[test]
+fn equal_arrays_violate_strict_ordering() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(1, 1);
+    let x1 = solver.new_variable(2, 2);
+    let y0 = solver.new_variable(1, 1);
+    let y1 = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(LexicographicPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            true,
+        ))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn equal_arrays_satisfy_non_strict_ordering() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(1, 1);
+    let x1 = solver.new_variable(2, 2);
+    let y0 = solver.new_variable(1, 1);
+    let y1 = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(LexicographicPropagator::new(
+            [x0, x1].into(),
+            [y0, y1].into(),
+            false,
+        ))
+        .expect("Expected no conflict");
+}
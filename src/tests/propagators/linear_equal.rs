@@ -0,0 +1,43 @@
+#![cfg(test)]
+use crate::engine::test_helper::TestSolver;
+use crate::propagators::arithmetic::linear_equal::LinearEqualPropagator;
+
+#[test]
+fn bounds_are_propagated_in_both_directions() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 5);
+    let b = solver.new_variable(3, 4);
+
+    // a + b = 5
+    let _ = solver
+        .new_propagator(LinearEqualPropagator::new([a, b].into(), 5))
+        .expect("no conflict");
+
+    solver.assert_bounds(a, 1, 2);
+    solver.assert_bounds(b, 3, 4);
+}
+
+#[test]
+fn detects_entailment_when_the_sum_is_fixed() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(2, 2);
+    let b = solver.new_variable(3, 3);
+
+    let propagator = LinearEqualPropagator::new([a, b].into(), 5);
+
+    assert!(solver.detect_entailment(&propagator).is_some());
+}
+
+#[test]
+fn detects_conflict_when_the_sum_cannot_reach_the_rhs() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 1);
+    let b = solver.new_variable(0, 2);
+
+    let result = solver.new_propagator(LinearEqualPropagator::new([a, b].into(), 5));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,167 @@
+#![cfg(test)]
+use crate::conjunction;
+use crate::constraints;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+use crate::propagators::ReifiedPropagator;
+use crate::Solver;
+
+#[test]
+fn value_of_last_unfixed_term_is_removed() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(2, 2);
+    let b = solver.new_variable(0, 5);
+
+    // 2a + 3b != 10
+    let _ = solver
+        .new_propagator(LinearNotEqualPropagator::new(
+            [a, b].into(),
+            [2, 3].into(),
+            10,
+        ))
+        .expect("no empty domain");
+
+    solver.assert_domain(b, vec![0, 1, 3, 4, 5]);
+    let reason = solver.get_reason_int(predicate![b != 2].try_into().unwrap());
+    assert_eq!(reason, &conjunction!([a == 2]));
+}
+
+#[test]
+fn value_which_is_not_a_multiple_of_the_weight_is_not_removed() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(1, 1);
+    let b = solver.new_variable(0, 5);
+
+    // a + 2b != 4
+    let _ = solver
+        .new_propagator(LinearNotEqualPropagator::new(
+            [a, b].into(),
+            [1, 2].into(),
+            4,
+        ))
+        .expect("no empty domain");
+
+    solver.assert_bounds(b, 0, 5);
+    solver.assert_domain(b, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn fixed_terms_summing_to_rhs_are_a_conflict() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(3, 3);
+    let b = solver.new_variable(1, 1);
+
+    // a - 3b != 0
+    let _ = solver
+        .new_propagator(LinearNotEqualPropagator::new(
+            [a, b].into(),
+            [1, -3].into(),
+            0,
+        ))
+        .expect_err("a - 3b = 0");
+}
+
+#[test]
+fn entailment_is_detected_when_bounds_exclude_rhs() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(3, 5);
+    let b = solver.new_variable(0, 1);
+
+    // a - b != 1 is entailed, since a - b >= 2.
+    let propagator = LinearNotEqualPropagator::new([a, b].into(), [1, -1].into(), 1);
+
+    let reason = solver
+        .detect_entailment(&propagator)
+        .expect("a - b != 1 is entailed");
+    assert_eq!(reason, conjunction!([a >= 3] & [b <= 1]));
+}
+
+#[test]
+fn entailment_is_detected_when_last_term_cannot_take_value() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(2, 2);
+    let b = solver.new_sparse_variable(&[1, 3, 5]);
+
+    // a + b != 4 is entailed, since b != 2.
+    let propagator = LinearNotEqualPropagator::new([a, b].into(), [1, 1].into(), 4);
+
+    let reason = solver
+        .detect_entailment(&propagator)
+        .expect("a + b != 4 is entailed");
+    assert_eq!(reason, conjunction!([a == 2] & [b != 2]));
+}
+
+#[test]
+fn entailment_is_not_detected_when_equality_is_possible() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 5);
+    let b = solver.new_variable(0, 5);
+
+    let propagator = LinearNotEqualPropagator::new([a, b].into(), [1, 1].into(), 4);
+
+    assert_eq!(None, solver.detect_entailment(&propagator));
+}
+
+#[test]
+fn half_reification_literal_is_not_propagated_when_entailed() {
+    let mut solver = TestSolver::default();
+
+    let reification_literal = solver.new_literal();
+    let a = solver.new_variable(3, 5);
+    let b = solver.new_variable(0, 1);
+
+    // a - b != 1 is entailed, but that does not force the half reification literal.
+    let _ = solver
+        .new_propagator(ReifiedPropagator::new(
+            LinearNotEqualPropagator::new([a, b].into(), [1, -1].into(), 1),
+            reification_literal,
+        ))
+        .expect("no conflict");
+
+    assert!(!solver.is_literal_true(reification_literal));
+    assert!(!solver.is_literal_false(reification_literal));
+}
+
+#[test]
+fn reification_literal_is_set_when_value_is_removed() {
+    let mut solver = Solver::default();
+
+    let x = solver.new_bounded_integer(0, 5);
+    let reification_literal = solver.new_literal();
+
+    solver
+        .add_constraint(constraints::not_equals([x], 3))
+        .reify(reification_literal)
+        .expect("no conflict");
+    assert_eq!(None, solver.get_literal_value(reification_literal));
+
+    let x_is_not_3 = solver.get_literal(predicate![x != 3]);
+    solver.add_clause([x_is_not_3]).expect("no conflict");
+
+    assert_eq!(Some(true), solver.get_literal_value(reification_literal));
+}
+
+#[test]
+fn reification_literal_is_unset_when_value_is_assigned() {
+    let mut solver = Solver::default();
+
+    let x = solver.new_bounded_integer(0, 5);
+    let reification_literal = solver.new_literal();
+
+    solver
+        .add_constraint(constraints::not_equals([x], 3))
+        .reify(reification_literal)
+        .expect("no conflict");
+
+    let x_is_3 = solver.get_literal(predicate![x == 3]);
+    solver.add_clause([x_is_3]).expect("no conflict");
+
+    assert_eq!(Some(false), solver.get_literal_value(reification_literal));
+}
@@ -1,5 +1,16 @@
 pub(crate) mod all_different;
+pub(crate) mod bin_packing;
 pub(crate) mod circuit;
+pub(crate) mod counting;
 pub(crate) mod cumulative;
+pub(crate) mod diffn;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+pub(crate) mod inverse;
+pub(crate) mod lexicographic;
+pub(crate) mod linear_equal;
+pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
+pub(crate) mod regular;
+pub(crate) mod value_precede_chain;
+pub(crate) mod xor;
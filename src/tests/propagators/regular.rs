@@ -0,0 +1,124 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::constraints::Automaton;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::regular::RegularPropagator;
+use crate::variables::TransformableVariable;
+
+/// An automaton over {0, 1} which accepts the sequences that do not contain two consecutive 1s.
+fn no_consecutive_ones() -> Automaton {
+    Automaton::new(2, 0, [0, 1], [(0, 0, 0), (0, 1, 1), (1, 0, 0)])
+}
+
+#[test]
+fn values_without_transition_are_removed() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 3);
+    let y = solver.new_variable(-1, 1);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new([x, y].into(), no_consecutive_ones()))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x, 0, 1);
+    solver.assert_bounds(y, 0, 1);
+}
+
+#[test]
+fn assignment_propagates_to_neighbours() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 1);
+    let y = solver.new_variable(1, 1);
+    let z = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new(
+            [x, y, z].into(),
+            no_consecutive_ones(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x, 0, 0);
+    solver.assert_bounds(z, 0, 0);
+
+    let reason = solver.get_reason_int(predicate![x != 1].try_into().unwrap());
+    assert_eq!(reason, &PropositionalConjunction::from(predicate![y >= 1]));
+}
+
+#[test]
+fn accepting_states_restrict_the_last_variable() {
+    let mut solver = TestSolver::default();
+
+    // Accepts the sequences with an odd number of 1s.
+    let automaton = Automaton::new(2, 0, [1], [(0, 0, 0), (0, 1, 1), (1, 0, 1), (1, 1, 0)]);
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new([x, y].into(), automaton))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(y, 0, 0);
+}
+
+#[test]
+fn holes_are_created_in_domains() {
+    let mut solver = TestSolver::default();
+
+    // Accepts the sequences where every value is 0 or 2.
+    let automaton = Automaton::new(1, 0, [0], [(0, 0, 0), (0, 2, 0)]);
+
+    let x = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new([x].into(), automaton))
+        .expect("Expected no conflict");
+
+    solver.assert_domain(x, vec![0, 2]);
+}
+
+#[test]
+fn works_with_views() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(-1, 0);
+    let y = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new(
+            [x.scaled(-1), y.scaled(1)].into(),
+            no_consecutive_ones(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x, -1, 0);
+    solver.assert_bounds(y, 0, 1);
+
+    solver.remove(x, 0).expect("Expected non-empty domain");
+    let mut propagator = solver
+        .new_propagator(RegularPropagator::new(
+            [x.scaled(-1), y.scaled(1)].into(),
+            no_consecutive_ones(),
+        ))
+        .expect("Expected no conflict");
+    let result = solver.propagate(&mut propagator);
+    assert!(result.is_ok());
+
+    solver.assert_bounds(y, 0, 0);
+}
+
+#[test]
+fn detects_conflict() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(1, 1);
+    let y = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(RegularPropagator::new([x, y].into(), no_consecutive_ones()))
+        .expect_err("Expected conflict");
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::value_precede_chain::ValuePrecedeChainPropagator;
+
+#[test]
+fn later_value_is_removed_from_first_variable() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 2);
+    let x1 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(ValuePrecedeChainPropagator::new(
+            [1, 2].into(),
+            [x0, x1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_domain(x0, vec![0, 1]);
+    solver.assert_bounds(x1, 0, 2);
+}
+
+#[test]
+fn later_value_is_removed_until_earlier_value_can_occur() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 3);
+    let x1 = solver.new_variable(0, 3);
+    let x2 = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(ValuePrecedeChainPropagator::new(
+            [1, 2].into(),
+            [x0, x1, x2].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x0, 3, 3);
+    solver.assert_domain(x1, vec![0, 1, 3]);
+    solver.assert_bounds(x2, 0, 3);
+
+    let reason = solver.get_reason_int(predicate![x1 != 2].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![x0 >= 3]])
+    );
+}
+
+#[test]
+fn only_candidate_for_earlier_value_is_fixed() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 2);
+    let x1 = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(ValuePrecedeChainPropagator::new(
+            [1, 2].into(),
+            [x0, x1].into(),
+        ))
+        .expect("Expected no conflict");
+
+    solver.assert_bounds(x0, 1, 1);
+
+    let reason = solver.get_reason_int(predicate![x0 >= 1].try_into().unwrap());
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![predicate![x1 == 2]])
+    );
+}
+
+#[test]
+fn later_value_without_earlier_value_is_a_conflict() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 0);
+    let x1 = solver.new_variable(2, 2);
+
+    let _ = solver
+        .new_propagator(ValuePrecedeChainPropagator::new(
+            [1, 2].into(),
+            [x0, x1].into(),
+        ))
+        .expect_err("Expected conflict");
+}
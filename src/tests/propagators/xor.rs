@@ -0,0 +1,98 @@
+#![cfg(test)]
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicates::Predicate;
+use crate::propagators::xor::XorPropagator;
+
+#[test]
+fn last_literal_is_assigned() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    solver.set_literal(a, true);
+    solver.set_literal(b, false);
+
+    let _ = solver
+        .new_propagator(XorPropagator::new([(vec![a, b, c], true)]))
+        .expect("Expected no conflict");
+
+    assert!(solver.is_literal_false(c));
+
+    let reason = solver.get_reason_bool(c, false);
+    assert_eq!(
+        reason,
+        &PropositionalConjunction::from(vec![Predicate::from(a), Predicate::from(!b)])
+    );
+}
+
+#[test]
+fn negative_literals_flip_the_parity() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+
+    solver.set_literal(a, true);
+
+    let _ = solver
+        .new_propagator(XorPropagator::new([(vec![!a, b], true)]))
+        .expect("Expected no conflict");
+
+    assert!(solver.is_literal_false(!b));
+}
+
+#[test]
+fn violated_parity_is_a_conflict() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+
+    solver.set_literal(a, true);
+    solver.set_literal(b, true);
+
+    let _ = solver
+        .new_propagator(XorPropagator::new([(vec![a, b], true)]))
+        .expect_err("Expected conflict");
+}
+
+#[test]
+fn elimination_combines_equations() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    // a xor b = 1 and a xor b xor c = 1 together imply that c is false, although neither
+    // equation implies anything on its own.
+    let _ = solver
+        .new_propagator(XorPropagator::new([
+            (vec![a, b], true),
+            (vec![a, b, c], true),
+        ]))
+        .expect("Expected no conflict");
+
+    assert!(solver.is_literal_false(c));
+    assert!(!solver.is_literal_false(a) && !solver.is_literal_false(!a));
+}
+
+#[test]
+fn elimination_detects_inconsistent_system() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    let _ = solver
+        .new_propagator(XorPropagator::new([
+            (vec![a, b], true),
+            (vec![b, c], true),
+            (vec![a, c], true),
+        ]))
+        .expect_err("Expected conflict");
+}